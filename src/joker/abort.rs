@@ -5,9 +5,10 @@
 //!     - Argument
 //!
 //! - ControlFlowAbort
-//!     - Break
-//!     - Continue
+//!     - Break(label)
+//!     - Continue(label)
 //!     - Return(Object)
+//!     - Throw(Object)
 //!
 //! - ArgumentAbort
 //!     - ArgLimitAbort
@@ -50,20 +51,28 @@ impl ReportError for Error {
 
 #[derive(Debug)]
 pub enum ControlFlowAbort {
-    Break,
-    Continue,
+    Break(Option<String>),
+    Continue(Option<String>),
     Return(Option<Object>),
+    Throw(Object),
 }
 
 impl Display for ControlFlowAbort {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ControlFlowAbort::Break => write!(f, "Break"),
-            ControlFlowAbort::Continue => write!(f, "Continue"),
+            ControlFlowAbort::Break(label) => match label {
+                Some(label) => write!(f, "Break({})", label),
+                None => write!(f, "Break"),
+            },
+            ControlFlowAbort::Continue(label) => match label {
+                Some(label) => write!(f, "Continue({})", label),
+                None => write!(f, "Continue"),
+            },
             ControlFlowAbort::Return(return_) => match return_ {
                 Some(value) => Display::fmt(value, f),
                 None => write!(f, "Return(None)"),
             },
+            ControlFlowAbort::Throw(value) => write!(f, "Uncaught throw: {}", value),
         }
     }
 }
@@ -29,10 +29,19 @@
 //!     var_decl       → "var" varStmt ;
 //!     fn_decl       → "fn" FnStmt ;
 //!     class_decl     → "class" classStmt ;
+//!     struct_decl    → "struct" structStmt ;
+//!     enum_decl      → "enum" enumStmt ;
+//!     import_decl    → "import" STRING "as" IDENTIFIER ";" ;
+//!     export_decl    → "export" ( fn_decl | class_decl | struct_decl | enum_decl ) ;
+//!     type_decl      → "type" IDENTIFIER "=" type ";" ;
+//!     type           → base_type "?"? ( "|" base_type "?"? )* ;
+//!     base_type      → IDENTIFIER | "[" type "]" | "{" type ":" type "}"
+//!                     | "(" type ( "," type )* ")" ;
 //!     
 //!
 //!     statement      → exprStmt
 //!                     | returnStmt
+//!                     | yieldStmt
 //!                     | breakStmt
 //!                     | continueStmt
 //!                     | FnStmt
@@ -40,71 +49,122 @@
 //!                     | ifStmt               
 //!                     | printStmt
 //!                     | whileStmt
+//!                     | loopStmt
+//!                     | doWhileStmt
+//!                     | labeledStmt
+//!                     | throwStmt
+//!                     | tryStmt
 //!                     | BlockStmt             
 //!                     | varStmt ;             
 //!
 //!     exprStmt       → expression ";" ;
 //!     printStmt      → "print" expression ";" ;
 //!     BlockStmt      → "{" statement "}"
-//!     varStmt        → "var" IDENTIFIER  (":" IDENTIFIER)? ("=" expression )? ";" ;
+//!     varStmt        → "var" IDENTIFIER  (":" IDENTIFIER)? ("=" expression )? ";"
+//!                     | "var" "mut"? destruct (":" type)? "=" expression ";" ;
+//!     destruct       → "(" ( IDENTIFIER | "_" ) ( "," ( IDENTIFIER | "_" ) )+ ")" ;
 //!     ifStmt         → "if" "(" expression ")" statement ( "else" statement )? ;
 //!     whileStmt      → "while" "(" expression ")" statement ;          
+//!     loopStmt       → "loop" statement ;
+//!     doWhileStmt    → "do" statement "while" "(" expression ")" ";" ;
+//!     labeledStmt    → IDENTIFIER ":" ( whileStmt | forStmt | loopStmt | doWhileStmt ) ;
+//!     throwStmt      → "throw" expression ";" ;
+//!     tryStmt        → "try" BlockStmt
+//!                     ( "catch" "(" IDENTIFIER ( ":" type )? ")" BlockStmt )?
+//!                     ( "finally" BlockStmt )? ;
 //!     forStmt        → "for" "(" ( varDecl | exprStmt | ";" )
 //!                      expression? ";"
-//!                      expression? ")" statement ;
+//!                      expression? ")" statement
+//!                     | "for" "(" ( IDENTIFIER | destruct ) "in" expression ")" statement ;
 //!     
-//!     FnStmt        → "fn" IDENTIFIER  "("
-//!                         (IDENTIFIER ":" IDENTIFIER (, IDENTIFIER ":" IDENTIFIER )*? )?
-//!                     ")" ("->" IDENTIFIER)? statement ;
+//!     FnStmt        → "fn" "*"? IDENTIFIER type_params? "("
+//!                         (fnParam (, fnParam )*? )?
+//!                     ")" ("->" IDENTIFIER)? statement ;       ("*" is generator, "->" yield type)
+//!     fnParam        → IDENTIFIER ":" type ( "=" expression )?
+//!                     | "..." IDENTIFIER ":" "[" type "]" ;
 //!     methodStmt   → "fn" IDENTIFIER  "("
 //!                         "this" (, IDENTIFIER ":" IDENTIFIER )*?
 //!                     ")" statement ;
 //!
-//!     classStmt      → "class" IDENTIFIER (":" IDENTIFIER)?  "{"
+//!     classStmt      → "class" IDENTIFIER type_params? (":" IDENTIFIER)?  "{"
 //!                             var_decl*
 //!                             | fn_decl*
 //!                             | method_decl*
 //!                     "}" ;
+//!     type_params    → "<" IDENTIFIER ( "," IDENTIFIER )* ">" ;
 //!
 //!
-//!      breakStmt      → "break" ";"
-//!     continueStmt   → "continue" ";"
+//!     structStmt     → "struct" IDENTIFIER "{"
+//!                             ( IDENTIFIER ":" type ( "," IDENTIFIER ":" type )* ","? )?
+//!                     "}" ;
+//!     enumStmt       → "enum" IDENTIFIER "{"
+//!                             ( variant ( "," variant )* ","? )?
+//!                     "}" ;
+//!     variant        → IDENTIFIER ( "(" type ( "," type )* ")" )? ;
+//!
+//!      breakStmt      → "break" IDENTIFIER? ";"
+//!     continueStmt   → "continue" IDENTIFIER? ";"
 //!     returnStmt     → "return" expression? ";" ;
+//!     yieldStmt      → "yield" expression ";" ;
 //!  
 //!     expression     → assignment ;
 //!
 //!     assignment     → ( call "." )? IDENTIFIER "=" assignment
+//!                     | call "[" expression "]" "=" assignment
+//!                     | call ( "+=" | "-=" | "*=" | "/=" | "%=" ) assignment
+//!                     | call ( "++" | "--" )
 //!                     | Lambda ;
 //!
 //!     Lambda         → "|" parameters? "|" ("->" IDENTIFIER)?  statement ( "(" parameters? ")" ";" )?
+//!     parameter      → ( IDENTIFIER | destruct ) ":" type ;
 //!                     | Trinomial ;
 //!
 //!
 //!     Trinomial      → expression "?" Trinomial ":" Trinomial ";" ;    
-//!                     | logic_or ;
+//!                     | coalesce ;
 //!
+//!     coalesce       → logic_or ( "??" coalesce )? ;
 //!     logic_or       → logic_and ( "or" logic_and )* ;
 //!     logic_and      → equality ( "and" equality )* ;
 //!
 //!     equality       → comparison ( ( "!=" | "==" ) comparison )* ;
-//!     comparison     → term ( ( ">" | ">=" | "<" | "<=" ) term )* ;
+//!     comparison     → range ( ( ">" | ">=" | "<" | "<=" ) range )* ;
+//!     range          → term ( ( ".." | "..=" ) term )? ;
 //!     term           → factor ( ( "-" | "+" ) factor )* ;
-//!     factor         → unary ( ( "/" | "*" ) unary )* ;
+//!     factor         → cast ( ( "/" | "*" ) cast )* ;
+//!     cast           → unary ( ( "as" | "is" ) type )* ;
 //!     unary          → ( "!" | "-" ) unary
 //!                     | call ;
 //!
-//!     call           → grouping ( "(" arguments? ")" | "." IDENTIFIER  )* ;
-//!     arguments      → expression ( "," expression )* ;
+//!     call           → grouping ( "(" arguments? ")" | ( "." | "?." ) IDENTIFIER | "[" expression "]" )* ;
+//!     arguments      → ( expression ( "," expression )* ( "," named )* ) | named ( "," named )* ;
+//!     named          → IDENTIFIER ":" expression ;
 //!
 //!     grouping       → "(" expression ")" ;
+//!                     | "(" expression ( "," expression )+ ")"
 //!                     | “super” "." IDENTIFIER
 //!                     | primary ;
 //!
-//!     primary        → I32| F64 | STRING | "true" | "false" | "null"
+//!     primary        → match
+//!                     | I32| F64 | STRING | "true" | "false" | "null"
+//!                     | "\"" ( STRING | "${" expression "}" )* "\""
+//!                     | "[" arguments? "]"
+//!                     | "{" ( expression ":" expression ( "," expression ":" expression )* )? "}"
+//!                     | IDENTIFIER "{" ( IDENTIFIER ":" expression ( "," IDENTIFIER ":" expression )* ","? )? "}"
 //!                     | IDENTIFIER ;
 //!
 //!     getter      -> expr.ident
+//!     safe_getter -> expr?.ident
 //!     setter      -> expr.ident = expr
+//!     index       -> expr[expr]
+//!     index_set   -> expr[expr] = expr
+//!
+//!     match          → "match" expression "{" ( arm ( "," arm )* ","? )? "}" ;
+//!     arm            → pattern "=>" expression ;
+//!     pattern        → "_"
+//!                     | "-"? literal ( ( ".." | "..=" ) "-"? literal )?
+//!                     | IDENTIFIER "." IDENTIFIER ( "(" IDENTIFIER ( "," IDENTIFIER )* ")" )?
+//!                     | IDENTIFIER ( "(" IDENTIFIER ")" )? ;
 //!
 //!
 //!
@@ -118,6 +178,11 @@ use super::{
     types::{ParamPair, Type},
 };
 
+// loop label display name, no label is None.
+fn label_name(label: &Option<Token>) -> &str {
+    label.as_ref().map_or("None", |label| label.lexeme.as_str())
+}
+
 macro_rules! define_ast {
     (
         $ast_name:ident {
@@ -143,6 +208,8 @@ macro_rules! define_ast {
             $(pub $field: $field_type),*
         }
 
+        // constructor take every field, class node have many.
+        #[allow(clippy::too_many_arguments)]
         impl $struct_name {
             pub fn new($($field: $field_type),*) -> $struct_name {
                 $struct_name { $($field),* }
@@ -196,10 +263,11 @@ macro_rules! define_ast {
     (@impl_display Call, $($field:ident: $field_type: ty),*) => {
         impl Display for Call {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "Call(callee: {}, paren: {}, arguments: {:?})",
+                write!(f, "Call(callee: {}, paren: {}, arguments: {:?}, named: {:?})",
                     self.callee,
                     self.paren,
                     self.arguments,
+                    self.named,
                 )
             }
         }
@@ -207,8 +275,58 @@ macro_rules! define_ast {
     (@impl_display Lambda, $($field:ident: $field_type: ty),*) => {
         impl Display for Lambda {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "Lambda(params: {:?}, return_type: {:?}, body: {:?})",
-                    self.params, self.return_type, self.body)
+                write!(f, "Lambda(params: {:?}, return_type: {:?}, body: {:?}, destructs: {:?})",
+                    self.params, self.return_type, self.body, self.destructs)
+            }
+        }
+    };
+    (@impl_display List, $($field:ident: $field_type: ty),*) => {
+        impl Display for List {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "List(bracket: {}, elements: {:?})", self.bracket, self.elements)
+            }
+        }
+    };
+    (@impl_display Tuple, $($field:ident: $field_type: ty),*) => {
+        impl Display for Tuple {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Tuple(paren: {}, elements: {:?})", self.paren, self.elements)
+            }
+        }
+    };
+    (@impl_display Map, $($field:ident: $field_type: ty),*) => {
+        impl Display for Map {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Map(brace: {}, pairs: {:?})", self.brace, self.pairs)
+            }
+        }
+    };
+    (@impl_display Match, $($field:ident: $field_type: ty),*) => {
+        impl Display for Match {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Match(keyword: {}, value: {}, arms: [{}])",
+                    self.keyword,
+                    self.value,
+                    self.arms
+                        .iter()
+                        .map(|arm| arm.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                )
+            }
+        }
+    };
+    (@impl_display Template, $($field:ident: $field_type: ty),*) => {
+        impl Display for Template {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Template(token: {}, parts: {:?})", self.token, self.parts)
+            }
+        }
+    };
+    (@impl_display StructInit, $($field:ident: $field_type: ty),*) => {
+        impl Display for StructInit {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "StructInit(name: {}, fields: {:?})", self.name, self.fields)
             }
         }
     };
@@ -233,7 +351,7 @@ macro_rules! define_ast {
     (@impl_display ForStmt, $($field:ident: $field_type: ty),*) => {
         impl Display for ForStmt {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "ForStmt(initializer: {}, condition: {}, increment: {} , body: {})",
+                write!(f, "ForStmt(initializer: {}, condition: {}, increment: {} , body: {}, label: {})",
                     match &self.initializer {
                         Some(initializer) => format!("Some({})", initializer),
                         None => String::from("None"),
@@ -244,6 +362,7 @@ macro_rules! define_ast {
                         None => String::from("None"),
                     },
                     self.body,
+                    label_name(&self.label),
                 )
             }
         }
@@ -251,14 +370,14 @@ macro_rules! define_ast {
     (@impl_display BreakStmt, $($field:ident: $field_type: ty),*) => {
         impl Display for BreakStmt {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "BreakStmt")
+                write!(f, "BreakStmt(label: {})", label_name(&self.label))
             }
         }
     };
     (@impl_display ContinueStmt, $($field:ident: $field_type: ty),*) => {
         impl Display for ContinueStmt {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "ContinueStmt")
+                write!(f, "ContinueStmt(label: {})", label_name(&self.label))
             }
         }
     };
@@ -273,8 +392,9 @@ macro_rules! define_ast {
     (@impl_display VarStmt, $($field:ident: $field_type: ty),*) => {
         impl Display for VarStmt {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "VarStmt(name: {}, type: {}, value: {})",
+                write!(f, "VarStmt(name: {}, mutable: {}, type: {}, value: {})",
                     self.name,
+                    self.mutable,
                     match &self.type_ {
                         Some(expr) => format!("Some({})", expr),
                         None => String::from("None"),
@@ -287,6 +407,38 @@ macro_rules! define_ast {
             }
         }
     };
+    (@impl_display DestructStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for DestructStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "DestructStmt(names: {:?}, mutable: {}, type: {:?}, value: {})",
+                    self.names, self.mutable, self.type_, self.value)
+            }
+        }
+    };
+    (@impl_display ForInStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for ForInStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "ForInStmt(names: {:?}, destruct: {}, iterable: {}, body: {}, label: {})",
+                    self.names, self.destruct, self.iterable, self.body, label_name(&self.label))
+            }
+        }
+    };
+    (@impl_display WhileStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for WhileStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "WhileStmt(condition: {}, body: {}, label: {})",
+                    self.condition, self.body, label_name(&self.label))
+            }
+        }
+    };
+    (@impl_display DoWhileStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for DoWhileStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "DoWhileStmt(body: {}, condition: {}, label: {})",
+                    self.body, self.condition, label_name(&self.label))
+            }
+        }
+    };
     (@impl_display ReturnStmt, $($field:ident: $field_type: ty),*) => {
         impl Display for ReturnStmt {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -303,16 +455,70 @@ macro_rules! define_ast {
     (@impl_display ClassStmt, $($field:ident: $field_type: ty),*) => {
         impl Display for ClassStmt {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "ClassStmt(name: {}, super_class: {:?}, fields: {:?}, methods: {:?}, functions: {:?})",
+                write!(f, "ClassStmt(name: {}, super_class: {:?}, fields: {:?}, methods: {:?}, functions: {:?}, statics: {:?}, interfaces: {:?}, generics: {:?})",
                     self.name,
                     self.super_class,
                     self.fields,
                     self.methods,
                     self.functions,
+                    self.statics,
+                    self.interfaces,
+                    self.generics,
                 )
             }
         }
     };
+    (@impl_display StructStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for StructStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "StructStmt(name: {}, fields: {:?})", self.name, self.fields)
+            }
+        }
+    };
+    (@impl_display InterfaceStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for InterfaceStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "InterfaceStmt(name: {}, methods: {:?})", self.name, self.methods)
+            }
+        }
+    };
+    (@impl_display EnumStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for EnumStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "EnumStmt(name: {}, variants: {:?})", self.name, self.variants)
+            }
+        }
+    };
+    (@impl_display TryStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for TryStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "TryStmt(keyword: {}, try_branch: {}, catch_name: {:?}, catch_type: {:?}, catch_branch: {:?}, finally_branch: {:?})",
+                    self.keyword,
+                    self.try_branch,
+                    self.catch_name,
+                    self.catch_type,
+                    self.catch_branch,
+                    self.finally_branch,
+                )
+            }
+        }
+    };
+    (@impl_display ConstStmt, $($field:ident: $field_type: ty),*) => {
+        impl Display for ConstStmt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "ConstStmt(name: {}, type: {:?}, value: {})",
+                    self.name, self.type_, self.value)
+            }
+        }
+    };
+    (@impl_display AssignOp, $($field:ident: $field_type: ty),*) => {
+        impl Display for AssignOp {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "AssignOp(target: {}, opera: {}, value: {:?})",
+                    self.target, self.opera, self.value)
+            }
+        }
+    };
     (@impl_display $struct_name:ident, $($field:ident : $field_type:ty),* $(,)?) => {
         impl Display for $struct_name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -335,16 +541,33 @@ define_ast! {
         Assign      { name: Token, value: Box<Expr>},
         Logical     { l_expr: Box<Expr>, m_opera: Token, r_expr: Box<Expr> },
         Trinomial   { condition: Box<Expr>, l_expr: Box<Expr>, r_expr: Box<Expr> },
-        Call        { callee: Box<Expr>, paren: Token, arguments: Vec<Expr> },
-        Lambda      { pipe: Token, params: Option<Vec<ParamPair>>, return_type: Option<Box<Type>>, body: Box<Stmt> },
+        Call        { callee: Box<Expr>, paren: Token, arguments: Vec<Expr>, named: Vec<(Token, Expr)> },
+        Lambda      { pipe: Token, params: Option<Vec<ParamPair>>, return_type: Option<Box<Type>>, body: Box<Stmt>,
+                        destructs: Vec<Stmt> },
         Getter      { expr: Box<Expr>, name: Token },
         Setter      { l_expr: Box<Expr>, name: Token, r_expr: Box<Expr> },
         This        { keyword: Token },
         Super       { keyword: Token, method: Token },
+        List        { bracket: Token, elements: Vec<Expr> },
+        Index       { l_expr: Box<Expr>, bracket: Token, index: Box<Expr> },
+        IndexSet    { l_expr: Box<Expr>, bracket: Token, index: Box<Expr>, r_expr: Box<Expr> },
+        Map         { brace: Token, pairs: Vec<(Expr, Expr)> },
+        Match       { keyword: Token, value: Box<Expr>, arms: Vec<MatchArm> },
+        StructInit  { name: Token, fields: Vec<(Token, Expr)> },
+        AssignOp    { target: Box<Expr>, opera: Token, value: Option<Box<Expr>> },  // value None: postfix `++` `--`
+        Template    { token: Token, parts: Vec<Expr> },
+        Cast        { expr: Box<Expr>, keyword: Token, target: Box<Type> },
+        SafeGetter  { expr: Box<Expr>, name: Token },
+        Is          { expr: Box<Expr>, keyword: Token, target: Box<Type> },
+        Tuple       { paren: Token, elements: Vec<Expr> },
+        Range       { start: Box<Expr>, opera: Token, end: Box<Expr> },
     },
     ExprVisitor,    expr, { visit_literal, visit_unary, visit_binary, visit_grouping ,visit_variable,
                             visit_assign, visit_logical, visit_trinomial, visit_call, visit_lambda,
-                            visit_getter, visit_setter, visit_this, visit_super },
+                            visit_getter, visit_setter, visit_this, visit_super, visit_list,
+                            visit_index, visit_index_set, visit_map, visit_match, visit_struct_init,
+                            visit_assign_op, visit_template, visit_cast, visit_safe_getter,
+                            visit_is, visit_tuple, visit_range },
     ExprAcceptor,
 }
 
@@ -352,19 +575,195 @@ define_ast! {
     Stmt {
         ExprStmt    { expr: Expr },
         PrintStmt   { expr: Expr },
-        VarStmt     { name: Token, type_: Option<Type>, value: Option<Expr> },   // left value
+        VarStmt     { name: Token, mutable: bool, type_: Option<Type>, value: Option<Expr> },   // left value
         BlockStmt   { stmts: Vec<Stmt> },           // space
         IfStmt      { condition: Expr, then_branch: Box<Stmt>, else_branch: Option<Box<Stmt>> },
-        WhileStmt   { condition: Expr, body: Box<Stmt>},
-        ForStmt     { initializer: Option<Box<Stmt>>, condition: Expr, increment: Option<Expr> , body: Box<Stmt> },
-        BreakStmt   { name: Token },
-        ContinueStmt{ name: Token },
-        FnStmt      { name: Token, params: Option<Vec<ParamPair>>, return_type: Option<Box<Type>>, body: Vec<Stmt> },
+        WhileStmt   { condition: Expr, body: Box<Stmt>, label: Option<Token> },
+        ForStmt     { initializer: Option<Box<Stmt>>, condition: Expr, increment: Option<Expr> , body: Box<Stmt>, label: Option<Token> },
+        BreakStmt   { name: Token, label: Option<Token> },
+        ContinueStmt{ name: Token, label: Option<Token> },
+        FnStmt      { name: Token, params: Option<Vec<ParamPair>>, return_type: Option<Box<Type>>, body: Vec<Stmt>, generator: bool },
         ReturnStmt  { keyword: Token, value: Option<Expr> },
         ClassStmt   { name: Token, super_class: Option<Expr>, fields: Option<Vec<Stmt>>,
-                        methods: Option<Vec<Stmt>>, functions: Option<Vec<Stmt>> },
+                        methods: Option<Vec<Stmt>>, functions: Option<Vec<Stmt>>, statics: Option<Vec<Stmt>>,
+                        interfaces: Vec<Token>, generics: Vec<Token> },
+        StructStmt  { name: Token, fields: Vec<(Token, Type)> },
+        EnumStmt    { name: Token, variants: Vec<(Token, Vec<Type>)> },
+        ThrowStmt   { keyword: Token, value: Expr },
+        TryStmt     { keyword: Token, try_branch: Box<Stmt>, catch_name: Option<Token>, catch_type: Option<Type>,
+                        catch_branch: Option<Box<Stmt>>, finally_branch: Option<Box<Stmt>> },
+        ImportStmt  { keyword: Token, path: Token, name: Token },
+        ExportStmt  { keyword: Token, stmt: Box<Stmt> },
+        ConstStmt   { name: Token, type_: Option<Type>, value: Expr },
+        InterfaceStmt { name: Token, methods: Vec<(Token, Type)> },
+        TypeStmt    { name: Token, type_: Type },
+        DestructStmt{ paren: Token, names: Vec<Token>, mutable: bool, type_: Option<Type>, value: Expr },
+        ForInStmt   { keyword: Token, names: Vec<Token>, destruct: bool, iterable: Expr, body: Box<Stmt>, label: Option<Token> },
+        DoWhileStmt { keyword: Token, body: Box<Stmt>, condition: Expr, label: Option<Token> },
+        YieldStmt   { keyword: Token, value: Expr },
     },
     StmtVisitor,    stmt, {visit_expr, visit_print, visit_var, visit_block, visit_if, visit_while ,
-                            visit_for, visit_break, visit_continue, visit_fn, visit_return, visit_class },
+                            visit_for, visit_break, visit_continue, visit_fn, visit_return, visit_class,
+                            visit_struct, visit_enum, visit_throw, visit_try, visit_import,
+                            visit_export, visit_const, visit_interface, visit_type, visit_destruct,
+                            visit_for_in, visit_do_while, visit_yield },
     StmtAcceptor,
 }
+
+impl Stmt {
+    // yield inside this statement, not look into nested fn and lambda.
+    pub fn has_yield(&self) -> bool {
+        match self {
+            Stmt::YieldStmt(_) => true,
+            Stmt::BlockStmt(BlockStmt { stmts }) => stmts.iter().any(Stmt::has_yield),
+            Stmt::IfStmt(IfStmt {
+                then_branch,
+                else_branch,
+                ..
+            }) => then_branch.has_yield() || else_branch.as_ref().is_some_and(|e| e.has_yield()),
+            Stmt::WhileStmt(WhileStmt { body, .. })
+            | Stmt::ForStmt(ForStmt { body, .. })
+            | Stmt::ForInStmt(ForInStmt { body, .. })
+            | Stmt::DoWhileStmt(DoWhileStmt { body, .. }) => body.has_yield(),
+            Stmt::TryStmt(TryStmt {
+                try_branch,
+                catch_branch,
+                finally_branch,
+                ..
+            }) => [
+                Some(try_branch),
+                catch_branch.as_ref(),
+                finally_branch.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|branch| branch.has_yield()),
+            _ => false,
+        }
+    }
+}
+
+impl ExportStmt {
+    // exported declaration name.
+    pub fn name(&self) -> &Token {
+        match self.stmt.as_ref() {
+            Stmt::FnStmt(FnStmt { name, .. })
+            | Stmt::ClassStmt(ClassStmt { name, .. })
+            | Stmt::StructStmt(StructStmt { name, .. })
+            | Stmt::EnumStmt(EnumStmt { name, .. })
+            | Stmt::ConstStmt(ConstStmt { name, .. }) => name,
+            _ => unreachable!("[ExportStmt::name] export only declaration."),
+        }
+    }
+}
+
+// match arm pattern:
+//      - Literal:  1, "x", true, null
+//      - Range:    1..5, 1..=5
+//      - Class:    Point, Point(p), i32(n) (union member type)
+//      - Variant:  Shape.Empty, Shape.Rect(w, h)
+//      - Wildcard: _
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    Literal {
+        token: Token,
+        value: OEnum,
+    },
+    Range {
+        token: Token,
+        start: OEnum,
+        end: OEnum,
+        inclusive: bool,
+    },
+    Class {
+        name: Token,
+        binding: Option<Token>,
+    },
+    Variant {
+        name: Token,
+        variant: Token,
+        bindings: Vec<Token>,
+    },
+    Wildcard {
+        token: Token,
+    },
+}
+
+impl Pattern {
+    pub fn token(&self) -> &Token {
+        match self {
+            Pattern::Literal { token, value: _ } => token,
+            Pattern::Range { token, .. } => token,
+            Pattern::Class { name, binding: _ } => name,
+            Pattern::Variant { name, .. } => name,
+            Pattern::Wildcard { token } => token,
+        }
+    }
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self, Pattern::Wildcard { token: _ })
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pattern::Literal { token: _, value } => write!(f, "{}", value),
+            Pattern::Range {
+                token: _,
+                start,
+                end,
+                inclusive,
+            } => write!(
+                f,
+                "{}{}{}",
+                start,
+                if *inclusive { "..=" } else { ".." },
+                end
+            ),
+            Pattern::Class { name, binding } => match binding {
+                Some(binding) => write!(f, "{}({})", name.lexeme, binding.lexeme),
+                None => write!(f, "{}", name.lexeme),
+            },
+            Pattern::Variant {
+                name,
+                variant,
+                bindings,
+            } => {
+                if bindings.is_empty() {
+                    write!(f, "{}.{}", name.lexeme, variant.lexeme)
+                } else {
+                    write!(
+                        f,
+                        "{}.{}({})",
+                        name.lexeme,
+                        variant.lexeme,
+                        bindings
+                            .iter()
+                            .map(|binding| binding.lexeme.clone())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                }
+            }
+            Pattern::Wildcard { token: _ } => write!(f, "_"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub body: Expr,
+}
+
+impl MatchArm {
+    pub fn new(pattern: Pattern, body: Expr) -> MatchArm {
+        MatchArm { pattern, body }
+    }
+}
+
+impl Display for MatchArm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.body)
+    }
+}
@@ -4,10 +4,13 @@
 
 use super::{
     ast::{
-        Assign, Binary, BlockStmt, BreakStmt, Call, ClassStmt, Expr, ExprAcceptor, ExprStmt,
-        ExprVisitor, FnStmt, ForStmt, Getter, Grouping, IfStmt, Lambda, Literal, Logical,
-        PrintStmt, ReturnStmt, Setter, Stmt, StmtAcceptor, StmtVisitor, Super, This, Trinomial,
-        Unary, VarStmt, Variable, WhileStmt,
+        Assign, AssignOp, Binary, BlockStmt, BreakStmt, Call, Cast, ClassStmt, ConstStmt,
+        DestructStmt, DoWhileStmt, EnumStmt, ExportStmt, Expr, ExprAcceptor, ExprStmt, ExprVisitor,
+        FnStmt, ForInStmt, ForStmt, Getter, Grouping, IfStmt, ImportStmt, Index, IndexSet,
+        InterfaceStmt, Is, Lambda, List, Literal, Logical, Map, Match, PrintStmt, Range,
+        ReturnStmt, SafeGetter, Setter, Stmt, StmtAcceptor, StmtVisitor, StructInit, StructStmt,
+        Super, Template, This, ThrowStmt, Trinomial, TryStmt, Tuple, TypeStmt, Unary, VarStmt,
+        Variable, WhileStmt, YieldStmt,
     },
     error::{JokerError, ReportError},
     object::Object,
@@ -55,8 +58,9 @@ impl StmtVisitor<String> for AstPrinter {
     }
     fn visit_var(&self, stmt: &VarStmt) -> Result<String, JokerError> {
         Ok(format!(
-            "VarStmt(name: {}, type: {}, value: {})",
+            "VarStmt(name: {}, mutable: {}, type: {}, value: {})",
             stmt.name.lexeme,
+            stmt.mutable,
             match &stmt.type_ {
                 Some(type_) => format!("Some({})", type_),
                 None => String::from("None"),
@@ -97,6 +101,16 @@ impl StmtVisitor<String> for AstPrinter {
             stmt.body.accept(self)?,
         ))
     }
+    fn visit_do_while(&self, stmt: &DoWhileStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "DoWhileStmt(body: {}, cond: {})",
+            stmt.body.accept(self)?,
+            stmt.condition.accept(self)?,
+        ))
+    }
+    fn visit_yield(&self, stmt: &YieldStmt) -> Result<String, JokerError> {
+        Ok(format!("YieldStmt({})", stmt.value.accept(self)?))
+    }
     fn visit_break(&self, _stmt: &BreakStmt) -> Result<String, JokerError> {
         Ok(String::from("BreakStmt"))
     }
@@ -141,7 +155,7 @@ impl StmtVisitor<String> for AstPrinter {
     }
     fn visit_class(&self, stmt: &ClassStmt) -> Result<String, JokerError> {
         Ok(format!(
-            "ClassStmt(name: {}, super_class: {:?}, fields: {:?}, methods: {:?}, functions: {:?})",
+            "ClassStmt(name: {}, super_class: {:?}, fields: {:?}, methods: {:?}, functions: {:?}, statics: {:?}, interfaces: {:?})",
             stmt.name.lexeme,
             match &stmt.super_class {
                 Some(super_class) => format!("Some({})", super_class.accept(self)?),
@@ -171,6 +185,127 @@ impl StmtVisitor<String> for AstPrinter {
                     .join("\n"),
                 None => String::from("None"),
             },
+            match &stmt.statics {
+                Some(statics) => statics
+                    .iter()
+                    .map(|st| -> String { st.accept(self).unwrap() })
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                None => String::from("None"),
+            },
+            stmt.interfaces
+                .iter()
+                .map(|interface| interface.lexeme.clone())
+                .collect::<Vec<String>>(),
+        ))
+    }
+    fn visit_struct(&self, stmt: &StructStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "StructStmt(name: {}, fields: {:?})",
+            stmt.name.lexeme,
+            stmt.fields
+                .iter()
+                .map(|(field, type_)| format!("{}: {}", field.lexeme, type_))
+                .collect::<Vec<String>>()
+        ))
+    }
+    fn visit_enum(&self, stmt: &EnumStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "EnumStmt(name: {}, variants: {:?})",
+            stmt.name.lexeme,
+            stmt.variants
+                .iter()
+                .map(|(variant, payload)| format!(
+                    "{}({})",
+                    variant.lexeme,
+                    payload
+                        .iter()
+                        .map(|type_| type_.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ))
+                .collect::<Vec<String>>()
+        ))
+    }
+    fn visit_interface(&self, stmt: &InterfaceStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "InterfaceStmt(name: {}, methods: {:?})",
+            stmt.name.lexeme,
+            stmt.methods
+                .iter()
+                .map(|(method, type_)| format!("{}: {}", method.lexeme, type_))
+                .collect::<Vec<String>>()
+        ))
+    }
+    fn visit_type(&self, stmt: &TypeStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "TypeStmt(name: {}, type: {})",
+            stmt.name.lexeme, stmt.type_
+        ))
+    }
+    fn visit_destruct(&self, stmt: &DestructStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "DestructStmt(names: {:?}, mutable: {}, value: {})",
+            stmt.names
+                .iter()
+                .map(|name| name.lexeme.clone())
+                .collect::<Vec<String>>(),
+            stmt.mutable,
+            stmt.value.accept(self)?
+        ))
+    }
+    fn visit_for_in(&self, stmt: &ForInStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "ForInStmt(names: {:?}, destruct: {}, iterable: {}, body: {})",
+            stmt.names
+                .iter()
+                .map(|name| name.lexeme.clone())
+                .collect::<Vec<String>>(),
+            stmt.destruct,
+            stmt.iterable.accept(self)?,
+            stmt.body.accept(self)?,
+        ))
+    }
+    fn visit_throw(&self, stmt: &ThrowStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "ThrowStmt(keyword: {}, value: {})",
+            stmt.keyword.lexeme,
+            stmt.value.accept(self)?
+        ))
+    }
+    fn visit_try(&self, stmt: &TryStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "TryStmt(try: {}, catch: {}, finally: {})",
+            stmt.try_branch.accept(self)?,
+            match (&stmt.catch_name, &stmt.catch_branch) {
+                (Some(name), Some(branch)) =>
+                    format!("Some({}, {})", name.lexeme, branch.accept(self)?),
+                _ => String::from("None"),
+            },
+            match &stmt.finally_branch {
+                Some(branch) => format!("Some({})", branch.accept(self)?),
+                None => String::from("None"),
+            },
+        ))
+    }
+    fn visit_import(&self, stmt: &ImportStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "ImportStmt(path: {}, name: {})",
+            stmt.path.lexeme, stmt.name.lexeme
+        ))
+    }
+    fn visit_export(&self, stmt: &ExportStmt) -> Result<String, JokerError> {
+        Ok(format!("ExportStmt(stmt: {})", stmt.stmt.accept(self)?))
+    }
+    fn visit_const(&self, stmt: &ConstStmt) -> Result<String, JokerError> {
+        Ok(format!(
+            "ConstStmt(name: {}, type: {}, value: {})",
+            stmt.name.lexeme,
+            match &stmt.type_ {
+                Some(type_) => format!("Some({})", type_),
+                None => String::from("None"),
+            },
+            stmt.value.accept(self)?
         ))
     }
 }
@@ -181,6 +316,16 @@ impl ExprVisitor<String> for AstPrinter {
             Object::Literal(literal) => Ok(literal.to_string()),
             Object::Caller(call) => Ok(call.to_string()),
             Object::Instance(instance) => Ok(instance.to_string()),
+            Object::List(list) => Ok(list.to_string()),
+            Object::Map(map) => Ok(map.to_string()),
+            Object::StructDecl(struct_decl) => Ok(struct_decl.to_string()),
+            Object::Struct(struct_) => Ok(struct_.to_string()),
+            Object::EnumDecl(enum_decl) => Ok(enum_decl.to_string()),
+            Object::Enum(enum_) => Ok(enum_.to_string()),
+            Object::Module(module) => Ok(module.to_string()),
+            Object::Tuple(tuple) => Ok(tuple.to_string()),
+            Object::Range(range) => Ok(range.to_string()),
+            Object::Generator(generator) => Ok(generator.to_string()),
         }
     }
     fn visit_unary(&self, expr: &Unary) -> Result<String, JokerError> {
@@ -262,6 +407,110 @@ impl ExprVisitor<String> for AstPrinter {
             expr.keyword.lexeme, expr.method.lexeme
         ))
     }
+    fn visit_list(&self, expr: &List) -> Result<String, JokerError> {
+        let mut elements: Vec<String> = Vec::new();
+        for element in &expr.elements {
+            elements.push(element.accept(self)?);
+        }
+        Ok(format!("List(elements: {:?})", elements))
+    }
+    fn visit_index(&self, expr: &Index) -> Result<String, JokerError> {
+        Ok(format!(
+            "Index(l_expr: {}, index: {})",
+            expr.l_expr.accept(self)?,
+            expr.index.accept(self)?,
+        ))
+    }
+    fn visit_index_set(&self, expr: &IndexSet) -> Result<String, JokerError> {
+        Ok(format!(
+            "IndexSet(l_expr: {}, index: {}, r_expr: {})",
+            expr.l_expr.accept(self)?,
+            expr.index.accept(self)?,
+            expr.r_expr.accept(self)?,
+        ))
+    }
+    fn visit_match(&self, expr: &Match) -> Result<String, JokerError> {
+        let mut arms: Vec<String> = Vec::new();
+        for arm in &expr.arms {
+            arms.push(format!("{} => {}", arm.pattern, arm.body.accept(self)?));
+        }
+        Ok(format!(
+            "Match(value: {}, arms: {:?})",
+            expr.value.accept(self)?,
+            arms
+        ))
+    }
+    fn visit_map(&self, expr: &Map) -> Result<String, JokerError> {
+        let mut pairs: Vec<String> = Vec::new();
+        for (key, value) in &expr.pairs {
+            pairs.push(format!("{}: {}", key.accept(self)?, value.accept(self)?));
+        }
+        Ok(format!("Map(pairs: {:?})", pairs))
+    }
+    fn visit_struct_init(&self, expr: &StructInit) -> Result<String, JokerError> {
+        let mut fields: Vec<String> = Vec::new();
+        for (field, value) in &expr.fields {
+            fields.push(format!("{}: {}", field.lexeme, value.accept(self)?));
+        }
+        Ok(format!(
+            "StructInit(name: {}, fields: {:?})",
+            expr.name.lexeme, fields
+        ))
+    }
+    fn visit_assign_op(&self, expr: &AssignOp) -> Result<String, JokerError> {
+        Ok(format!(
+            "AssignOp(target: {}, opera: {}, value: {})",
+            expr.target.accept(self)?,
+            expr.opera.lexeme,
+            match &expr.value {
+                Some(value) => value.accept(self)?,
+                None => String::from("step"),
+            },
+        ))
+    }
+    fn visit_template(&self, expr: &Template) -> Result<String, JokerError> {
+        let mut parts: Vec<String> = Vec::new();
+        for part in &expr.parts {
+            parts.push(part.accept(self)?);
+        }
+        Ok(format!("Template(parts: {:?})", parts))
+    }
+    fn visit_cast(&self, expr: &Cast) -> Result<String, JokerError> {
+        Ok(format!(
+            "Cast(expr: {}, target: {})",
+            expr.expr.accept(self)?,
+            expr.target
+        ))
+    }
+    fn visit_tuple(&self, expr: &Tuple) -> Result<String, JokerError> {
+        let mut elements: Vec<String> = Vec::new();
+        for element in &expr.elements {
+            elements.push(element.accept(self)?);
+        }
+        Ok(format!("Tuple(elements: {:?})", elements))
+    }
+    fn visit_range(&self, expr: &Range) -> Result<String, JokerError> {
+        Ok(format!(
+            "Range(start: {}, opera: {}, end: {})",
+            expr.start.accept(self)?,
+            expr.opera.lexeme,
+            expr.end.accept(self)?,
+        ))
+    }
+    fn visit_is(&self, expr: &Is) -> Result<String, JokerError> {
+        Ok(format!(
+            "Is(expr: {}, target: {})",
+            expr.expr.accept(self)?,
+            expr.target
+        ))
+    }
+    fn visit_safe_getter(&self, expr: &SafeGetter) -> Result<String, JokerError> {
+        Ok(format!(
+            "SafeGetter(expr: {}, name: {})",
+            expr.expr.accept(self)?,
+            expr.name.lexeme,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,8 @@
 //!
 //! - Callable Trait
 //!
+//! - Signature
+//!
 //! - Error
 //!     - NonError
 //!     - ArgumentError
@@ -13,8 +15,9 @@ use std::fmt::{Debug, Display};
 use super::{
     error::{JokerError, ReportError},
     interpreter::Interpreter,
+    object::{List, UpCast},
     token::{Token, TokenType},
-    types::Object,
+    types::{Object, ParamPair},
 };
 
 pub trait Callable: Debug + Display {
@@ -23,7 +26,120 @@ pub trait Callable: Debug + Display {
         interpreter: &Interpreter,
         arguments: &[Object],
     ) -> Result<Option<Object>, JokerError>;
-    fn arity(&self) -> usize;
+    fn signature(&self) -> Signature;
+    // call with arguments bound by signature: None is default parameter without argument.
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, JokerError> {
+        let arguments: Vec<Object> = arguments.into_iter().flatten().collect();
+        self.call(interpreter, &arguments)
+    }
+}
+
+// callable parameters: names for named argument, parameters after required have default,
+// variadic rest parameter collect extra positional arguments to list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub names: Vec<String>,
+    pub required: usize,
+    pub variadic: bool,
+}
+
+impl Signature {
+    // native callable: fixed positional parameters, not have name.
+    pub fn arity(arity: usize) -> Signature {
+        Signature {
+            names: vec![String::new(); arity],
+            required: arity,
+            variadic: false,
+        }
+    }
+    // user callable: 'this' skip, default parameter optional, rest parameter variadic.
+    pub fn from_params(params: &[ParamPair]) -> Signature {
+        let mut signature: Signature = Signature::arity(0);
+        for param in params.iter().filter(|param| !param.is_this()) {
+            match param {
+                ParamPair::Rest { .. } => signature.variadic = true,
+                param => {
+                    if !param.is_default() {
+                        signature.required += 1;
+                    }
+                    signature.names.push(
+                        param
+                            .get_param()
+                            .map_or(String::new(), |param| param.lexeme.clone()),
+                    );
+                }
+            }
+        }
+        signature
+    }
+    pub fn is_fixed(&self) -> bool {
+        !self.variadic && self.required == self.names.len()
+    }
+    pub fn accepts(&self, count: usize) -> bool {
+        self.required <= count && (self.variadic || count <= self.names.len())
+    }
+    // arguments to parameter slots: positional by order, named by parameter name,
+    // extra positional to rest list, missing slot is None and need default.
+    pub fn bind(
+        &self,
+        paren: &Token,
+        arguments: Vec<Object>,
+        named: Vec<(Token, Object)>,
+    ) -> Result<Vec<Option<Object>>, JokerError> {
+        let error = |msg: String| -> Result<Vec<Option<Object>>, JokerError> {
+            Err(JokerError::Call(Error::Argument(ArgumentError::new(
+                paren, msg,
+            ))))
+        };
+        if self.is_fixed() && named.is_empty() && arguments.len() != self.names.len() {
+            return error(format!(
+                "call expected {} arguments but got {}.",
+                self.names.len(),
+                arguments.len()
+            ));
+        }
+        if !self.variadic && arguments.len() > self.names.len() {
+            return error(format!(
+                "call expected at most {} arguments but got {}.",
+                self.names.len(),
+                arguments.len()
+            ));
+        }
+        let mut slots: Vec<Option<Object>> = vec![None; self.names.len()];
+        let mut rest: Vec<Object> = Vec::new();
+        for (index, argument) in arguments.into_iter().enumerate() {
+            match slots.get_mut(index) {
+                Some(slot) => *slot = Some(argument),
+                None => rest.push(argument),
+            }
+        }
+        for (name, argument) in named {
+            match self.names.iter().position(|param| param.eq(&name.lexeme)) {
+                Some(index) if slots[index].is_some() => {
+                    return error(format!("call argument '{}' given twice.", name.lexeme))
+                }
+                Some(index) => slots[index] = Some(argument),
+                None => return error(format!("call not have parameter named '{}'.", name.lexeme)),
+            }
+        }
+        if let Some(index) = slots[..self.required]
+            .iter()
+            .position(|slot| slot.is_none())
+        {
+            return error(format!(
+                "call missing argument for parameter '{}'.",
+                self.names[index]
+            ));
+        }
+        if self.variadic {
+            slots.push(Some(Object::new(List::new(rest).upcast_into())));
+        }
+        Ok(slots)
+    }
 }
 
 #[derive(Debug)]
@@ -75,10 +191,11 @@ impl NonError {
             msg,
         }
     }
-    pub fn report_error(token: &Token, msg: String) -> NonError {
-        let non_err = NonError::new(token, msg);
-        non_err.report();
-        non_err
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn msg(&self) -> &str {
+        &self.msg
     }
 }
 
@@ -123,10 +240,11 @@ impl ArgumentError {
             msg,
         }
     }
-    pub fn report_error(token: &Token, msg: String) -> ArgumentError {
-        let arg_err = ArgumentError::new(token, msg);
-        arg_err.report();
-        arg_err
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn msg(&self) -> &str {
+        &self.msg
     }
 }
 
@@ -176,6 +294,12 @@ impl StructError {
         arg_err.report();
         arg_err
     }
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
 }
 
 impl Display for StructError {
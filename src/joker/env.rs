@@ -2,6 +2,8 @@
 //!
 //! - Env
 //!
+//! - Upvalue
+//!
 //! - EnvError
 //!
 //!
@@ -21,27 +23,86 @@ use super::{
     types::Object,
 };
 
+// variable cell: closure capture share the cell, assign through any env is seen by all.
+pub type Upvalue = Rc<RefCell<Option<Object>>>;
+// closure captured upvalue: (depth from closure creation env, name).
+pub type Capture = (usize, String);
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Env {
-    pub symbol: HashMap<String, Option<Object>>,
+    pub symbol: HashMap<String, Upvalue>,
     pub enclosing: Option<Rc<RefCell<Env>>>, // rc: 引用计数， RefCell: 运行时管理生命周期
+    pub module: usize, // module id: which module global and local resolve table, main is 0.
 }
 
 impl Env {
     pub fn new() -> Env {
+        Env::new_module(0)
+    }
+    pub fn new_module(module: usize) -> Env {
         Env {
             symbol: HashMap::new(),
             enclosing: None,
+            module,
         }
     }
     pub fn new_with_enclosing(enclosing: Rc<RefCell<Env>>) -> Env {
+        let module: usize = enclosing.borrow().module;
         Env {
             symbol: HashMap::new(),
             enclosing: Some(enclosing),
+            module,
         }
     }
     pub fn define(&mut self, name: String, value: Option<Object>) {
-        self.symbol.insert(name, value);
+        self.symbol.insert(name, Rc::new(RefCell::new(value)));
+    }
+    // closure env: same depth shape as env, but only captured (depth, name) upvalues,
+    // unreferenced outer scope not retained, chain end link to module global.
+    pub fn capture(env: &Rc<RefCell<Env>>, upvalues: &[Capture]) -> Rc<RefCell<Env>> {
+        let mut global: Rc<RefCell<Env>> = Rc::clone(env);
+        while let Some(enclosing) = global.clone().borrow().enclosing.clone() {
+            global = enclosing;
+        }
+        let depth: usize = match upvalues.iter().map(|(depth, _)| *depth).max() {
+            Some(depth) => depth,
+            None => return global,
+        };
+        let module: usize = env.borrow().module;
+        let mut scopes: Vec<Env> = (0..=depth).map(|_| Env::new_module(module)).collect();
+        for (distance, name) in upvalues {
+            let source: Option<Rc<RefCell<Env>>> = match distance {
+                0 => Some(Rc::clone(env)),
+                _ => env.borrow().ancestor(*distance),
+            };
+            if let Some(upvalue) = source.and_then(|env| env.borrow().symbol.get(name).cloned()) {
+                scopes[*distance].symbol.insert(name.clone(), upvalue);
+            }
+        }
+        scopes
+            .into_iter()
+            .rev()
+            .fold(global, |enclosing, mut scope| {
+                scope.enclosing = Some(enclosing);
+                Rc::new(RefCell::new(scope))
+            })
+    }
+    // fresh binding with same values, every loop iteration new cell.
+    pub fn fresh(&self) -> Env {
+        Env {
+            symbol: self
+                .symbol
+                .iter()
+                .map(|(name, upvalue)| {
+                    (
+                        name.clone(),
+                        Rc::new(RefCell::new(upvalue.borrow().clone())),
+                    )
+                })
+                .collect(),
+            enclosing: self.enclosing.clone(),
+            module: self.module,
+        }
     }
     pub fn ancestor(&self, distance: usize) -> Option<Rc<RefCell<Env>>> {
         let mut current_env: Option<Rc<RefCell<Env>>> = self.enclosing.clone();
@@ -58,19 +119,19 @@ impl Env {
     pub fn get_with_depth(&self, depth: usize, name: &Token) -> Result<Option<Object>, JokerError> {
         match depth {
             0 => {
-                if let Some(object) = self.symbol.get(&name.lexeme) {
-                    return Ok(object.clone());
+                if let Some(upvalue) = self.symbol.get(&name.lexeme) {
+                    return Ok(upvalue.borrow().clone());
                 }
             }
             1.. => {
                 if let Some(env) = &self.ancestor(depth) {
-                    if let Some(object) = env.borrow().symbol.get(&name.lexeme) {
-                        return Ok(object.clone());
+                    if let Some(upvalue) = env.borrow().symbol.get(&name.lexeme) {
+                        return Ok(upvalue.borrow().clone());
                     }
                 }
             }
         }
-        Err(JokerError::Env(EnvError::report_error(
+        Err(JokerError::Env(EnvError::new(
             name,
             format!(
                 "[Env::get_with_depth] Undefined variable '{}' at line {}.",
@@ -80,10 +141,10 @@ impl Env {
     }
     pub fn get(&self, name: &Token) -> Result<Option<Object>, JokerError> {
         match self.symbol.get(&name.lexeme) {
-            Some(value) => Ok(value.clone()),
+            Some(upvalue) => Ok(upvalue.borrow().clone()),
             None => match &self.enclosing {
                 Some(enclosing) => enclosing.borrow().get(name),
-                None => Err(JokerError::Env(EnvError::report_error(
+                None => Err(JokerError::Env(EnvError::new(
                     name,
                     format!("[Env::get] Undefined variable '{}'.", name.lexeme),
                 ))),
@@ -103,19 +164,17 @@ impl Env {
 
         match depth {
             0 => {
-                self.symbol.insert(name.lexeme.clone(), Some(value));
+                self.set(name, value);
                 return Ok(());
             }
             1.. => {
                 if let Some(env) = &self.ancestor(depth) {
-                    env.borrow_mut()
-                        .symbol
-                        .insert(name.lexeme.clone(), Some(value));
+                    env.borrow_mut().set(name, value);
                     return Ok(());
                 }
             }
         }
-        Err(JokerError::Env(EnvError::report_error(
+        Err(JokerError::Env(EnvError::new(
             name,
             format!(
                 "[Env::assign_with_depth] Undefined variable '{}' at line {}.",
@@ -123,14 +182,23 @@ impl Env {
             ),
         )))
     }
+    // assign write into the shared cell, not new binding.
+    fn set(&mut self, name: &Token, value: Object) {
+        match self.symbol.entry(name.lexeme.clone()) {
+            Entry::Occupied(entry) => *entry.get().borrow_mut() = Some(value),
+            Entry::Vacant(entry) => {
+                entry.insert(Rc::new(RefCell::new(Some(value))));
+            }
+        }
+    }
     pub fn assign(&mut self, name: &Token, value: Object) -> Result<(), JokerError> {
-        if let Entry::Occupied(mut entry) = self.symbol.entry(name.lexeme.clone()) {
-            entry.insert(Some(value));
+        if let Some(upvalue) = self.symbol.get(&name.lexeme) {
+            *upvalue.borrow_mut() = Some(value);
             Ok(())
         } else {
             match &self.enclosing {
                 Some(enclosing) => enclosing.borrow_mut().assign(name, value),
-                None => Err(JokerError::Env(EnvError::report_error(
+                None => Err(JokerError::Env(EnvError::new(
                     name,
                     format!("Undefined variable '{}'.", name.lexeme),
                 ))),
@@ -164,6 +232,12 @@ impl EnvError {
         env_err.report();
         env_err
     }
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
 }
 
 impl Display for EnvError {
@@ -263,6 +337,47 @@ mod tests {
         assert_eq!(sub_get, Some(Object::new(literal_i32(100))));
     }
 
+    #[test]
+    fn test_env_capture_share_upvalue() {
+        let parent: Rc<RefCell<Env>> = Rc::new(RefCell::new(Env::new()));
+        let outer: Rc<RefCell<Env>> =
+            Rc::new(RefCell::new(Env::new_with_enclosing(Rc::clone(&parent))));
+        outer
+            .borrow_mut()
+            .define(String::from("count"), Some(Object::new(literal_i32(0))));
+        outer
+            .borrow_mut()
+            .define(String::from("unused"), Some(Object::new(literal_i32(1))));
+        let inner: Rc<RefCell<Env>> =
+            Rc::new(RefCell::new(Env::new_with_enclosing(Rc::clone(&outer))));
+
+        let closure: Rc<RefCell<Env>> = Env::capture(&inner, &[(1, String::from("count"))]);
+        closure
+            .borrow_mut()
+            .assign_with_depth(
+                1,
+                &maker_token(String::from("count")),
+                Object::new(literal_i32(5)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            outer
+                .borrow()
+                .get(&maker_token(String::from("count")))
+                .unwrap(),
+            Some(Object::new(literal_i32(5)))
+        );
+        assert!(!closure
+            .borrow()
+            .ancestor(1)
+            .unwrap()
+            .borrow()
+            .symbol
+            .contains_key("unused"));
+        assert_eq!(closure.borrow().ancestor(2), Some(parent));
+    }
+
     #[test]
     #[should_panic = "\"Undefined variable 'sub_key'.\""]
     fn test_env_get_undefined_value() {
@@ -2,44 +2,177 @@
 //!
 //!
 
-use std::{cell::RefCell, collections::HashMap, error::Error, fmt::Display, hash::Hash, rc::Rc};
+use std::{
+    cell::RefCell, collections::HashMap, error::Error, fmt::Display, hash::Hash, path::PathBuf,
+    rc::Rc, slice,
+};
 
 use crate::joker::{object::Lambda, types::DeepClone};
 
 use super::{
     abort::{ControlFlowAbort, Error::ControlFlow},
     ast::{
-        Assign, Binary, BlockStmt, BreakStmt, Call, ClassStmt, ContinueStmt, Expr, ExprAcceptor,
-        ExprStmt, ExprVisitor, FnStmt, ForStmt, Getter, Grouping, IfStmt, Lambda as LambdaExpr,
-        Literal, Logical, PrintStmt, ReturnStmt, Setter, Stmt, StmtAcceptor, StmtVisitor, Super,
-        This, Trinomial, Unary, VarStmt, Variable, WhileStmt,
+        Assign, AssignOp, Binary, BlockStmt, BreakStmt, Call, Cast, ClassStmt, ConstStmt,
+        ContinueStmt, DestructStmt, DoWhileStmt, EnumStmt, ExportStmt, Expr, ExprAcceptor,
+        ExprStmt, ExprVisitor, FnStmt, ForInStmt, ForStmt, Getter, Grouping, IfStmt, ImportStmt,
+        Index, IndexSet, InterfaceStmt, Is, Lambda as LambdaExpr, List as ListExpr, Literal,
+        Logical, Map as MapExpr, Match, Pattern, PrintStmt, Range as RangeExpr, ReturnStmt,
+        SafeGetter, Setter, Stmt, StmtAcceptor, StmtVisitor, StructInit, StructStmt, Super,
+        Template, This, ThrowStmt, Trinomial, TryStmt, Tuple as TupleExpr, TypeStmt, Unary,
+        VarStmt, Variable, WhileStmt, YieldStmt,
     },
     callable::{
-        ArgumentError, Callable,
-        Error::{Argument, NonCallable},
-        NonError,
+        Callable,
+        Error::{Argument, NonCallable, Struct as StructErr},
+        NonError, Signature,
     },
-    env::Env,
+    env::{Capture, Env},
     error::{JokerError, ReportError, SystemError, SystemTimeError},
     object::{
-        Binder, Caller, Class, Function, Literal as ObL, MethodFunction, NativeFunction,
-        Object as OEnum, UserFunction,
+        literal_bool, literal_f64, literal_i32, literal_i64, literal_null, literal_str, literal_u8,
+        Binder, Caller, Class, EnumDecl, Function, Generator, List, Literal as ObL, Map,
+        MethodFunction, NativeFunction, Object as OEnum, Range, Struct, StructDecl, Tuple, UpCast,
+        UserFunction, ERROR_STRUCT,
     },
     parse::ParserError,
     token::{Token, TokenType},
-    types::Object,
+    types::{Object, Type},
 };
 
+// i32, i64 and u8 binary operator, checked arithmetic: overflow is runtime error.
+// division `/` truncate, floor division `~/` and `%` round toward negative infinity,
+// so `l == (l ~/ r) * r + l % r` and `%` result sign follow the divisor.
+macro_rules! integer_binary {
+    ($opera:ident, $l:expr, $r:expr, $variant:path, $name:literal, $expr:expr) => {{
+        let (l, r) = ($l, $r);
+        let error = |msg: String| -> Result<Option<Object>, JokerError> {
+            Err(JokerError::Interpreter(InterpreterError::new(
+                $opera,
+                format!("{} !({})", msg, $expr),
+            )))
+        };
+        let bool_ = |value: bool| Ok(Some(Object::new(OEnum::Literal(ObL::Bool(value)))));
+        let value = match $opera.ttype {
+            TokenType::EqualEqual => return bool_(l == r),
+            TokenType::BangEqual => return bool_(l != r),
+            TokenType::Greater => return bool_(l > r),
+            TokenType::GreaterEqual => return bool_(l >= r),
+            TokenType::Less => return bool_(l < r),
+            TokenType::LessEqual => return bool_(l <= r),
+            TokenType::Plus => l.checked_add(r),
+            TokenType::Minus => l.checked_sub(r),
+            TokenType::Star => l.checked_mul(r),
+            TokenType::Slash | TokenType::Percent | TokenType::TildeSlash if r == 0 => {
+                return error(format!(
+                    "[[{:?}::ZeroDivisionError]] division by zero.",
+                    $opera.ttype
+                ))
+            }
+            TokenType::Slash => l.checked_div(r),
+            // i32::MIN % -1 is 0, wrapping remainder is exact.
+            TokenType::Percent => Some(l.wrapping_rem(r)).map(|rem| {
+                if rem != 0 && ((rem ^ r) as i128) < 0 {
+                    rem + r
+                } else {
+                    rem
+                }
+            }),
+            // floor division: round toward negative infinity.
+            TokenType::TildeSlash => l.checked_div(r).map(|quotient| {
+                if quotient * r != l && ((l ^ r) as i128) < 0 {
+                    quotient - 1
+                } else {
+                    quotient
+                }
+            }),
+            TokenType::StarStar => match u32::try_from(r) {
+                Ok(exponent) => l.checked_pow(exponent),
+                Err(_) => {
+                    return error(format!(
+                        "[[StarStar::NegativeExponentError]] {} exponent need >= 0.",
+                        $name
+                    ))
+                }
+            },
+            TokenType::Amper => Some(l & r),
+            TokenType::Caret => Some(l ^ r),
+            TokenType::LessLess | TokenType::GreaterGreater => {
+                match u32::try_from(r).ok().and_then(|shift| {
+                    if $opera.ttype == TokenType::LessLess {
+                        l.checked_shl(shift)
+                    } else {
+                        l.checked_shr(shift)
+                    }
+                }) {
+                    Some(value) => Some(value),
+                    None => {
+                        return error(format!(
+                            "[[Shift::OverflowError]] shift amount need in 0..{}.",
+                            std::mem::size_of_val(&l) * 8
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return error(format!(
+                    "[[{:?}]] The {} literal cannot take this operator.",
+                    $opera.ttype, $name
+                ))
+            }
+        };
+        match value {
+            Some(value) => Ok(Some(Object::new(OEnum::Literal($variant(value))))),
+            None => error(format!(
+                "[[{:?}::OverflowError]] {} overflow.",
+                $opera.ttype, $name
+            )),
+        }
+    }};
+}
+
+// closure key of captured upvalues table: local fn declaration or lambda expression.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Closure {
+    Fn(FnStmt),
+    Lambda(LambdaExpr),
+}
+
+// for in element source, owned state resume by next_element.
+#[derive(Debug)]
+pub enum Elements {
+    Range(Range, Option<i32>),
+    Snapshot(std::vec::IntoIter<Object>),
+    Iterator(Object),
+    Generator(Generator),
+}
+
 #[derive(Debug)]
 pub struct Interpreter {
-    pub global: Rc<RefCell<Env>>,
-    local_resolve: RefCell<HashMap<Expr, usize>>,
+    local_resolve: RefCell<Vec<HashMap<Expr, usize>>>, // module id index
+    local_fold: RefCell<Vec<HashMap<Expr, Object>>>,   // module id index, const folded value
+    local_capture: RefCell<Vec<HashMap<Closure, Vec<Capture>>>>, // module id index, closure upvalues
     pub run_env: RefCell<Rc<RefCell<Env>>>,
+    globals: RefCell<Vec<Rc<RefCell<Env>>>>, // module id index
+    modules: RefCell<HashMap<PathBuf, (Object, Type)>>,
+    module_paths: RefCell<Vec<PathBuf>>, // loading module stack
 }
 
 impl Interpreter {
     pub fn new() -> Interpreter {
-        let global: Rc<RefCell<Env>> = Rc::new(RefCell::new(Env::new()));
+        let global: Rc<RefCell<Env>> = Interpreter::builtin_env(Env::new());
+        Interpreter {
+            local_resolve: RefCell::new(vec![HashMap::new()]),
+            local_fold: RefCell::new(vec![HashMap::new()]),
+            local_capture: RefCell::new(vec![HashMap::new()]),
+            run_env: RefCell::new(Rc::clone(&global)),
+            globals: RefCell::new(vec![global]),
+            modules: RefCell::new(HashMap::new()),
+            module_paths: RefCell::new(Vec::new()),
+        }
+    }
+    // module global env with native function and builtin struct.
+    fn builtin_env(env: Env) -> Rc<RefCell<Env>> {
+        let global: Rc<RefCell<Env>> = Rc::new(RefCell::new(env));
         global.borrow_mut().define(
             String::from("clock"),
             Some(Object::new(OEnum::Caller(Caller::Func(Function::Native(
@@ -67,8 +200,8 @@ impl Interpreter {
                                 ))),
                             }
                         }
-                        fn arity(&self) -> usize {
-                            0
+                        fn signature(&self) -> Signature {
+                            Signature::arity(0)
                         }
                     }
                     impl Display for NativeClock {
@@ -84,15 +217,81 @@ impl Interpreter {
             ))))),
         );
 
-        Interpreter {
-            global: Rc::clone(&global),
-            local_resolve: RefCell::new(HashMap::new()),
-            run_env: RefCell::new(Rc::clone(&global)),
-        }
+        global.borrow_mut().define(
+            String::from(ERROR_STRUCT),
+            Some(Object::new(StructDecl::error().upcast_into())),
+        );
+        global
+    }
+    fn module(&self) -> usize {
+        self.run_env.borrow().borrow().module
+    }
+    fn module_global(&self) -> Rc<RefCell<Env>> {
+        Rc::clone(&self.globals.borrow()[self.module()])
+    }
+    // new module id with self global env and local resolve table.
+    pub fn new_module(&self) -> (usize, Rc<RefCell<Env>>) {
+        let module: usize = self.globals.borrow().len();
+        let global: Rc<RefCell<Env>> = Interpreter::builtin_env(Env::new_module(module));
+        self.globals.borrow_mut().push(Rc::clone(&global));
+        self.local_resolve.borrow_mut().push(HashMap::new());
+        self.local_fold.borrow_mut().push(HashMap::new());
+        self.local_capture.borrow_mut().push(HashMap::new());
+        (module, global)
+    }
+    pub fn interpreter_module(
+        &self,
+        stmts: &[Stmt],
+        global: Rc<RefCell<Env>>,
+    ) -> Result<(), JokerError> {
+        let previous: Rc<RefCell<Env>> = self.run_env.replace(global);
+        let result: Result<(), JokerError> = stmts.iter().try_for_each(|stmt| self.execute(stmt));
+        self.run_env.replace(previous);
+        result
+    }
+    // import path relative to current loading module file, other current dir.
+    pub fn module_path(&self, path: &Token) -> Result<PathBuf, JokerError> {
+        let base: PathBuf = match self.module_paths.borrow().last() {
+            Some(current) => current.parent().map(PathBuf::from).unwrap_or_default(),
+            None => PathBuf::new(),
+        };
+        let relative: String = match &path.literal {
+            OEnum::Literal(ObL::Str(relative)) => relative.clone(),
+            _ => path.lexeme.trim_matches('"').to_string(),
+        };
+        base.join(&relative).canonicalize().map_err(|err| {
+            JokerError::Interpreter(InterpreterError::new(
+                path,
+                format!("Cannot open module '{}': {}.", relative, err),
+            ))
+        })
+    }
+    pub fn push_module_path(&self, path: PathBuf) {
+        self.module_paths.borrow_mut().push(path);
+    }
+    pub fn pop_module_path(&self) {
+        self.module_paths.borrow_mut().pop();
+    }
+    // loading chain when path is loading, other None.
+    pub fn module_cycle(&self, path: &PathBuf) -> Option<Vec<PathBuf>> {
+        let module_paths = self.module_paths.borrow();
+        module_paths
+            .iter()
+            .position(|loading| loading.eq(path))
+            .map(|start| module_paths[start..].to_vec())
     }
-    fn is_true(&self, object: &Object) -> bool {
+    pub fn get_module(&self, path: &PathBuf) -> Option<(Object, Type)> {
+        self.modules.borrow().get(path).cloned()
+    }
+    pub fn cache_module(&self, path: PathBuf, module: Object, type_: Type) {
+        self.modules.borrow_mut().insert(path, (module, type_));
+    }
+    pub fn is_true(&self, object: &Object) -> bool {
         matches!(*object.get(), OEnum::Literal(ObL::Bool(true)))
     }
+    fn is_null(&self, object: &Object) -> bool {
+        matches!(*object.get(), OEnum::Literal(ObL::Null))
+    }
     fn execute(&self, stmt: &Stmt) -> Result<(), JokerError> {
         stmt.accept(self)
     }
@@ -102,6 +301,32 @@ impl Interpreter {
         self.run_env.replace(previous);
         result
     }
+    // run stmts in env and give env back: lambda tuple params destructure before body.
+    pub fn execute_in(&self, stmts: &[Stmt], env: Env) -> Result<Env, JokerError> {
+        let env: Rc<RefCell<Env>> = Rc::new(RefCell::new(env));
+        let previous: Rc<RefCell<Env>> = self.run_env.replace(Rc::clone(&env));
+        let result: Result<(), JokerError> = stmts.iter().try_for_each(|stmt| self.execute(stmt));
+        self.run_env.replace(previous);
+        result?;
+        Ok(Rc::try_unwrap(env).map_or_else(|env| env.borrow().clone(), RefCell::into_inner))
+    }
+    // run in shared env, generator frame env keep between resume.
+    pub fn execute_with(&self, stmt: &Stmt, env: Rc<RefCell<Env>>) -> Result<(), JokerError> {
+        let previous: Rc<RefCell<Env>> = self.run_env.replace(env);
+        let result: Result<(), JokerError> = self.execute(stmt);
+        self.run_env.replace(previous);
+        result
+    }
+    pub fn evaluate_with(
+        &self,
+        expr: &Expr,
+        env: Rc<RefCell<Env>>,
+    ) -> Result<Option<Object>, JokerError> {
+        let previous: Rc<RefCell<Env>> = self.run_env.replace(env);
+        let result: Result<Option<Object>, JokerError> = self.evaluate(expr);
+        self.run_env.replace(previous);
+        result
+    }
     pub fn evaluate(&self, expr: &Expr) -> Result<Option<Object>, JokerError> {
         expr.accept(self)
     }
@@ -111,19 +336,359 @@ impl Interpreter {
         self.run_env.replace(previous);
         result
     }
-    pub fn resolve(&self, expr: Expr, depth: usize) {
-        self.local_resolve.borrow_mut().insert(expr, depth);
+    // loop body error: own break is true, own continue is false, other error and
+    // outer loop label break or continue propagate. no label is own loop.
+    pub fn is_loop_break(err: JokerError, label: &Option<Token>) -> Result<bool, JokerError> {
+        let is_own = |target: &Option<String>| match (target, label) {
+            (None, _) => true,
+            (Some(target), Some(label)) => target.eq(&label.lexeme),
+            (Some(_), None) => false,
+        };
+        match err {
+            JokerError::Abort(ControlFlow(ControlFlowAbort::Break(target))) if is_own(&target) => {
+                Ok(true)
+            }
+            JokerError::Abort(ControlFlow(ControlFlowAbort::Continue(target)))
+                if is_own(&target) =>
+            {
+                Ok(false)
+            }
+            err => Err(err),
+        }
+    }
+    // catchable error value: thrown value or native error as Error struct, other none.
+    fn catch_value(&self, err: &JokerError) -> Option<Object> {
+        let error: Struct = match err {
+            JokerError::Abort(ControlFlow(ControlFlowAbort::Throw(value))) => {
+                return Some(value.clone())
+            }
+            JokerError::Interpreter(inter_err) => {
+                Struct::error("InterpreterError", inter_err.msg(), inter_err.line())
+            }
+            JokerError::Env(env_err) => Struct::error("EnvError", env_err.msg(), env_err.line()),
+            JokerError::Call(Argument(arg_err)) => {
+                Struct::error("ArgumentError", arg_err.msg(), arg_err.line())
+            }
+            JokerError::Call(NonCallable(non_err)) => {
+                Struct::error("NonCallableError", non_err.msg(), non_err.line())
+            }
+            JokerError::Call(StructErr(struct_err)) => {
+                Struct::error("StructError", struct_err.msg(), struct_err.line())
+            }
+            _ => return None,
+        };
+        Some(Object::new(error.upcast_into()))
+    }
+    // runtime type test: `x is T` and builtin type pattern.
+    fn is_type(value: &OEnum, type_: &Type) -> bool {
+        match (type_, value) {
+            (Type::Alias { name: _, target }, value) => Interpreter::is_type(value, target),
+            (Type::Union(types), value) => {
+                types.iter().any(|type_| Interpreter::is_type(value, type_))
+            }
+            (Type::Nullable(_), OEnum::Literal(ObL::Null)) => true,
+            (Type::Nullable(type_), value) => Interpreter::is_type(value, type_),
+            (Type::I32, OEnum::Literal(ObL::I32(_)))
+            | (Type::I64, OEnum::Literal(ObL::I64(_)))
+            | (Type::U8, OEnum::Literal(ObL::U8(_)))
+            | (Type::F64, OEnum::Literal(ObL::F64(_)))
+            | (Type::Str, OEnum::Literal(ObL::Str(_)))
+            | (Type::Bool, OEnum::Literal(ObL::Bool(_)))
+            | (Type::Null, OEnum::Literal(ObL::Null))
+            | (Type::List(_), OEnum::List(_))
+            | (Type::Map(_, _), OEnum::Map(_))
+            | (Type::Range, OEnum::Range(_))
+            | (Type::Generator(_), OEnum::Generator(_))
+            | (Type::Fn { .. }, OEnum::Caller(_)) => true,
+            (Type::Tuple(types), OEnum::Tuple(tuple)) => {
+                types.len() == tuple.elements.len()
+                    && types
+                        .iter()
+                        .zip(&tuple.elements)
+                        .all(|(type_, element)| Interpreter::is_type(&element.get(), type_))
+            }
+            (
+                Type::UserDefined(name) | Type::Generic { name, args: _ },
+                OEnum::Instance(instance),
+            ) => instance.class.borrow().is_class_or_inherit(&name.lexeme),
+            (Type::UserDefined(name), OEnum::Struct(struct_)) => {
+                struct_.name.lexeme.eq(&name.lexeme)
+            }
+            (Type::UserDefined(name), OEnum::Enum(enum_)) => enum_.name.lexeme.eq(&name.lexeme),
+            _ => false,
+        }
+    }
+    fn is_match_pattern(&self, pattern: &Pattern, value: &Object) -> bool {
+        match (pattern, &*value.get()) {
+            (Pattern::Wildcard { token: _ }, _) => true,
+            // builtin type pattern: i32(n), str(s).
+            (Pattern::Class { name, binding: _ }, value)
+                if Type::builtin(&name.lexeme).is_some() =>
+            {
+                Type::builtin(&name.lexeme).is_some_and(|type_| Interpreter::is_type(value, &type_))
+            }
+            (
+                Pattern::Literal {
+                    token: _,
+                    value: pattern,
+                },
+                value,
+            ) => pattern.eq(value),
+            (
+                Pattern::Range {
+                    token: _,
+                    start: OEnum::Literal(ObL::I32(start)),
+                    end: OEnum::Literal(ObL::I32(end)),
+                    inclusive,
+                },
+                OEnum::Literal(ObL::I32(value)),
+            ) => start <= value && (value < end || (*inclusive && value == end)),
+            (
+                Pattern::Range {
+                    token: _,
+                    start: OEnum::Literal(ObL::F64(start)),
+                    end: OEnum::Literal(ObL::F64(end)),
+                    inclusive,
+                },
+                OEnum::Literal(ObL::F64(value)),
+            ) => start <= value && (value < end || (*inclusive && value == end)),
+            (Pattern::Class { name, binding: _ }, OEnum::Instance(instance)) => {
+                instance.class.borrow().is_class_or_inherit(&name.lexeme)
+            }
+            (Pattern::Class { name, binding: _ }, OEnum::Struct(struct_)) => {
+                struct_.name.lexeme.eq(&name.lexeme)
+            }
+            (
+                Pattern::Variant {
+                    name,
+                    variant,
+                    bindings: _,
+                },
+                OEnum::Enum(enum_),
+            ) => enum_.name.lexeme.eq(&name.lexeme) && enum_.variant.eq(&variant.lexeme),
+            _ => false,
+        }
+    }
+    pub fn resolve(&self, module: usize, expr: Expr, depth: usize) {
+        self.local_resolve.borrow_mut()[module].insert(expr, depth);
+    }
+    pub fn fold(&self, module: usize, expr: Expr, value: Object) {
+        self.local_fold.borrow_mut()[module].insert(expr, value);
+    }
+    pub fn capture(&self, module: usize, closure: Closure, upvalues: Vec<Capture>) {
+        self.local_capture.borrow_mut()[module].insert(closure, upvalues);
+    }
+    // closure env keep only resolved upvalues, not resolved closure keep whole env.
+    fn closure_env(&self, closure: &Closure) -> Rc<RefCell<Env>> {
+        match self.local_capture.borrow()[self.module()].get(closure) {
+            Some(upvalues) => Env::capture(&self.run_env.borrow(), upvalues),
+            None => Rc::clone(&self.run_env.borrow()),
+        }
+    }
+    // element source: range, iterator and generator lazy, list and map snapshot elements.
+    pub fn elements(&self, keyword: &Token, iterable: &Object) -> Result<Elements, JokerError> {
+        match &*iterable.get() {
+            OEnum::Range(range) => Ok(Elements::Range(range.clone(), None)),
+            OEnum::List(list) => Ok(Elements::Snapshot(
+                list.elements.borrow().clone().into_iter(),
+            )),
+            OEnum::Map(map) => Ok(Elements::Snapshot(
+                map.keys()
+                    .into_iter()
+                    .zip(map.values())
+                    .map(|(key, value)| Object::new(Tuple::new(vec![key, value]).upcast_into()))
+                    .collect::<Vec<Object>>()
+                    .into_iter(),
+            )),
+            OEnum::Generator(generator) => Ok(Elements::Generator(generator.clone())),
+            OEnum::Instance(_) => {
+                let iterator: Object = self
+                    .call_operator(keyword, "iter", iterable, &[])?
+                    .unwrap_or_else(|| iterable.clone());
+                // 'fn* iter' method give generator.
+                if let OEnum::Generator(generator) = &*iterator.get() {
+                    return Ok(Elements::Generator(generator.clone()));
+                }
+                Ok(Elements::Iterator(iterator))
+            }
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                keyword,
+                format!("object '{}' is not iterable.", other),
+            ))),
+        }
+    }
+    pub fn next_element(
+        &self,
+        keyword: &Token,
+        elements: &mut Elements,
+    ) -> Result<Option<Object>, JokerError> {
+        match elements {
+            Elements::Range(range, current) => {
+                *current = range.next(*current);
+                Ok(current.map(|value| Object::new(OEnum::Literal(ObL::I32(value)))))
+            }
+            Elements::Snapshot(elements) => Ok(elements.next()),
+            Elements::Generator(generator) => generator.resume(self, keyword),
+            Elements::Iterator(iterator) => {
+                match self.call_operator(keyword, "next", iterator, &[])? {
+                    Some(element) if self.is_null(&element) => Ok(None),
+                    Some(element) => Ok(Some(element)),
+                    None => Err(JokerError::Interpreter(InterpreterError::new(
+                        keyword,
+                        format!("iterator '{}' not have method 'next'.", iterator),
+                    ))),
+                }
+            }
+        }
+    }
+    // for in loop variable env of one element, '_' skip.
+    pub fn for_in_env(
+        &self,
+        stmt: &ForInStmt,
+        enclosing: Rc<RefCell<Env>>,
+        element: Object,
+    ) -> Result<Env, JokerError> {
+        let mut loop_env: Env = Env::new_with_enclosing(enclosing);
+        let elements: Vec<Object> = if stmt.destruct {
+            match &*element.get() {
+                OEnum::Tuple(tuple) if tuple.elements.len() == stmt.names.len() => {
+                    tuple.elements.clone()
+                }
+                other => {
+                    return Err(JokerError::Interpreter(InterpreterError::new(
+                        &stmt.keyword,
+                        format!(
+                            "can't destructure '{}' to {} names.",
+                            other,
+                            stmt.names.len()
+                        ),
+                    )))
+                }
+            }
+        } else {
+            vec![element]
+        };
+        for (name, element) in stmt.names.iter().zip(elements) {
+            if name.lexeme.ne("_") {
+                loop_env.define(name.lexeme.clone(), Some(element.value_copy()));
+            }
+        }
+        Ok(loop_env)
+    }
+    fn for_loop(&self, stmt: &ForStmt) -> Result<(), JokerError> {
+        if let Some(initializer) = &stmt.initializer {
+            self.execute(initializer)?
+        }
+        while self.is_true(&self.evaluate(&stmt.condition)?.unwrap()) {
+            if let Err(err) = self.execute(&stmt.body) {
+                if Interpreter::is_loop_break(err, &stmt.label)? {
+                    break;
+                }
+            }
+            let fresh: Env = self.run_env.borrow().borrow().fresh();
+            self.run_env.replace(Rc::new(RefCell::new(fresh)));
+            if let Some(increment) = &stmt.increment {
+                self.evaluate(increment)?;
+            }
+        }
+        Ok(())
     }
     fn look_up_variable(&self, name: &Token, expr: &Expr) -> Result<Option<Object>, JokerError> {
-        match self.local_resolve.borrow().get(expr) {
+        match self.local_resolve.borrow()[self.module()].get(expr) {
             Some(depth) => self
                 .run_env
                 .borrow()
                 .borrow_mut()
                 .get_with_depth(*depth, name),
-            None => self.global.borrow_mut().get(name),
+            None => self.module_global().borrow_mut().get(name),
         }
     }
+    // i32 arithmetic overflow is runtime error, not wrap around.
+    fn checked_i32(
+        &self,
+        opera: &Token,
+        value: Option<i32>,
+        expr: String,
+    ) -> Result<Option<Object>, JokerError> {
+        match value {
+            Some(value) => Ok(Some(Object::new(OEnum::Literal(ObL::I32(value))))),
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                opera,
+                format!(
+                    "[[{:?}::OverflowError]] i32 overflow. !({})",
+                    opera.ttype, expr
+                ),
+            ))),
+        }
+    }
+    // operator overload: instance class method `name(this, args...)`, not instance or not method is None.
+    fn call_operator(
+        &self,
+        opera: &Token,
+        name: &str,
+        object: &Object,
+        arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        let method: Object = match &*object.get() {
+            OEnum::Instance(instance) => match instance.getter(&Token::new(
+                TokenType::Identifier,
+                name.to_string(),
+                literal_null(),
+                opera.line,
+            ))? {
+                Some(method) => method,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let arguments: Vec<Object> = arguments.iter().map(|arg| arg.value_copy()).collect();
+        let result: Option<Object> = match &*method.get() {
+            OEnum::Caller(caller) if caller.signature().accepts(arguments.len()) => {
+                caller.call(self, &arguments)?
+            }
+            _ => {
+                return Err(JokerError::Interpreter(InterpreterError::new(
+                    opera,
+                    format!(
+                        "operator method '{}' need {} arguments callable.",
+                        name,
+                        arguments.len()
+                    ),
+                )))
+            }
+        };
+        Ok(Some(result.unwrap_or_else(|| Object::new(literal_null()))))
+    }
+    // a != b: a.ne(b), not have ne then !a.eq(b).
+    fn binary_operator(
+        &self,
+        opera: &Token,
+        l_expr: &Object,
+        r_expr: &Object,
+    ) -> Result<Option<Object>, JokerError> {
+        let method: &str = match opera.binary_method() {
+            Some(method) => method,
+            None => return Ok(None),
+        };
+        if let Some(value) = self.call_operator(opera, method, l_expr, slice::from_ref(r_expr))? {
+            return Ok(Some(value));
+        }
+        if opera.ttype == TokenType::BangEqual {
+            if let Some(value) = self.call_operator(opera, "eq", l_expr, slice::from_ref(r_expr))? {
+                let not_equal: bool = match &*value.get() {
+                    OEnum::Literal(ObL::Bool(equal)) => !equal,
+                    other => {
+                        return Err(JokerError::Interpreter(InterpreterError::new(
+                            opera,
+                            format!("operator method 'eq' need return bool, found '{}'.", other),
+                        )))
+                    }
+                };
+                return Ok(Some(Object::new(OEnum::Literal(ObL::Bool(not_equal)))));
+            }
+        }
+        Ok(None)
+    }
     pub fn interpreter(&self, stmts: &[Stmt]) -> Result<(), JokerError> {
         // let printer: AstPrinter = AstPrinter::new();
         for stmt in stmts {
@@ -140,13 +705,508 @@ impl Interpreter {
     ) -> Result<Object, JokerError> {
         match self.evaluate(expr)? {
             Some(object) => Ok(object),
-            None => Err(JokerError::Interpreter(InterpreterError::report_error(
-                token, msg,
+            None => Err(JokerError::Interpreter(InterpreterError::new(token, msg))),
+        }
+    }
+    pub fn println_local(&self) {
+        println!("{:?}", self.run_env);
+    }
+    // binary operator apply on evaluated operands, share with compound assignment.
+    fn binary(
+        &self,
+        opera: &Token,
+        l_expr: &Object,
+        r_expr: &Object,
+    ) -> Result<Option<Object>, JokerError> {
+        // any object compare with null, before operator overload.
+        if matches!(opera.ttype, TokenType::EqualEqual | TokenType::BangEqual)
+            && (self.is_null(l_expr) || self.is_null(r_expr))
+        {
+            let equal: bool = self.is_null(l_expr) && self.is_null(r_expr);
+            return Ok(Some(Object::new(OEnum::Literal(ObL::Bool(
+                equal == (opera.ttype == TokenType::EqualEqual),
+            )))));
+        }
+        // operator overload: left instance class method.
+        if let Some(value) = self.binary_operator(opera, l_expr, r_expr)? {
+            return Ok(Some(value));
+        }
+        if let (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) =
+            (&*l_expr.get(), &*r_expr.get())
+        {
+            let expr: String = format!("{l_literal} {} {r_literal}", opera.lexeme);
+            match (l_literal, r_literal) {
+                (ObL::I32(l_i32), ObL::I32(r_i32)) => {
+                    return integer_binary!(opera, *l_i32, *r_i32, ObL::I32, "i32", expr)
+                }
+                (ObL::I64(l_i64), ObL::I64(r_i64)) => {
+                    return integer_binary!(opera, *l_i64, *r_i64, ObL::I64, "i64", expr)
+                }
+                (ObL::U8(l_u8), ObL::U8(r_u8)) => {
+                    return integer_binary!(opera, *l_u8, *r_u8, ObL::U8, "u8", expr)
+                }
+                _ => {}
+            }
+        }
+        match opera.ttype {
+            TokenType::BangEqual => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 != r_f64))))),
+                    (ObL::Bool(l_bool), ObL::Bool(r_bool)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_bool != r_bool))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str != r_str))))),
+                    (ObL::Null, ObL::Null) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(false))))),
+                    (ObL::Null, _) | (_, ObL::Null)=> Ok(Some(Object::new(OEnum::Literal(ObL::Bool(true))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[BangEqual]] The literal cannot take bang equal values. !({l_literal} != {r_literal})")
+                    )))
+                }
+                (OEnum::Struct(l_struct), OEnum::Struct(r_struct)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_struct != r_struct))))),
+                (OEnum::Enum(l_enum), OEnum::Enum(r_enum)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_enum != r_enum))))),
+                (OEnum::Tuple(l_tuple), OEnum::Tuple(r_tuple)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_tuple != r_tuple))))),
+                (OEnum::Range(l_range), OEnum::Range(r_range)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_range != r_range))))),
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl BangEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            },
+            TokenType::EqualEqual => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 == r_f64))))),
+                    (ObL::Bool(l_bool), ObL::Bool(r_bool)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_bool == r_bool))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str == r_str))))),
+                    (ObL::Null, ObL::Null) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(true))))),
+                    (ObL::Null, _) | (_, ObL::Null)=> Ok(Some(Object::new(OEnum::Literal(ObL::Bool(false))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[EqualEqual]] The literal cannot take equal values. !({l_literal} == {r_literal})")
+                    )))
+                },
+                (OEnum::Struct(l_struct), OEnum::Struct(r_struct)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_struct == r_struct))))),
+                (OEnum::Enum(l_enum), OEnum::Enum(r_enum)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_enum == r_enum))))),
+                (OEnum::Tuple(l_tuple), OEnum::Tuple(r_tuple)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_tuple == r_tuple))))),
+                (OEnum::Range(l_range), OEnum::Range(r_range)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_range == r_range))))),
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl EqualEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            },
+            TokenType::Greater => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 > r_f64))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str > r_str))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Greater]] The literal cannot take greater values. !({l_literal} > {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Greater! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            },
+            TokenType::GreaterEqual => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 >= r_f64))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str >= r_str))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[GreaterEqual]] The literal cannot take greater equal values. !({l_literal} >= {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl GreaterEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            },
+            TokenType::Less => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 < r_f64))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str < r_str))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Less]] The literal cannot take less values. !({l_literal} < {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Less! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            },
+            TokenType::LessEqual => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 <= r_f64))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str <= r_str))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[LessEqual]] The literal cannot take less equal values. !({l_literal} <= {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl LessEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            },
+            TokenType::Plus => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 + r_f64))))),
+                    (ObL::Str(l_str), ObL::Str(r_str)) => {
+                        Ok(Some(Object::new(OEnum::Literal(ObL::Str(format!("{l_str}{r_str}"))))))
+                    },
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Plus]] The literal cannot take plus values. !({l_literal} + {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Plus! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::Minus => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 - r_f64))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Minus]] The literal cannot take minus values. !({l_literal} - {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Minus! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::Slash => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => {
+                        if r_f64 != &0f64 {
+                            Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 / r_f64)))))
+                        } else {
+                            Err(JokerError::Interpreter(InterpreterError::new(
+                                opera,
+                                format!("[[Slash::ZeroDivisionError]] division by zero. !({l_literal} / {r_literal})")
+                            )))
+                        }
+                    },
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Slash]] The literal cannot take slash values. !({l_literal} / {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Slash! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::Star => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 * r_f64))))),
+                    (ObL::Str(str_), ObL::I32(i32_))
+                    | (ObL::I32(i32_), ObL::Str(str_)) => {
+                        let mut count = *i32_;
+                        let mut r_str: String = String::with_capacity(size_of_val(str_)* count as usize);
+                        while count > 0 {
+                            r_str.push_str(str_);
+                            count -= 1;
+                        }
+                        Ok(Some(Object::new(OEnum::Literal(ObL::Str(r_str)))))
+                    },
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Star]] The literal cannot take star values. !({l_literal} * {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Star! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::Percent => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(_), ObL::F64(0.0)) => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Percent::ZeroDivisionError]] division by zero. !({l_literal} % {r_literal})")
+                    ))),
+                    // floor remainder like integer: result sign follow the divisor.
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(
+                        l_f64 - r_f64 * (l_f64 / r_f64).floor()
+                    ))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Percent]] The literal cannot take mod values. !({l_literal} % {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Percent! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::TildeSlash => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(_), ObL::F64(0.0)) => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[TildeSlash::ZeroDivisionError]] division by zero. !({l_literal} ~/ {r_literal})")
+                    ))),
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64((l_f64 / r_f64).floor()))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[TildeSlash]] The literal cannot take floor division values. !({l_literal} ~/ {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl TildeSlash! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::StarStar => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64.powf(*r_f64)))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[StarStar]] The literal cannot take power values. !({l_literal} ** {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl StarStar! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::Amper => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::Bool(l_bool), ObL::Bool(r_bool)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_bool & r_bool))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Amper]] The literal cannot take bit and values. !({l_literal} & {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Amper! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::Caret => match (&*l_expr.get(), &*r_expr.get()) {
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
+                    (ObL::Bool(l_bool), ObL::Bool(r_bool)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_bool ^ r_bool))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("[[Caret]] The literal cannot take bit xor values. !({l_literal} ^ {r_literal})")
+                    )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Caret! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            TokenType::LessLess | TokenType::GreaterGreater => match (&*l_expr.get(), &*r_expr.get()) {
+                // shift need integer, integer handled by integer_binary.
+                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => Err(JokerError::Interpreter(InterpreterError::new(
+                    opera,
+                    format!("[[Shift]] The literal cannot take shift values. !({l_literal} {} {r_literal})", opera.lexeme)
+                ))),
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        opera,
+                        format!("not impl Shift! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ))),
+            }
+            _ => Err(JokerError::Interpreter(InterpreterError::new(
+                opera,
+                String::from("Unreachable according other type!")
+            )))
+        }
+    }
+    // checked literal cast, None is lossy or failed conversion.
+    fn cast_literal(literal: &ObL, target: &Type) -> Option<ObL> {
+        let method: &str = match (literal, target) {
+            (ObL::Str(str_), Type::Str) => return Some(ObL::Str(str_.clone())),
+            (literal, Type::Str) => return Some(ObL::Str(literal.to_string())),
+            (ObL::Str(str_), Type::I32) => return str_.trim().parse::<i32>().ok().map(ObL::I32),
+            (ObL::Str(str_), Type::I64) => return str_.trim().parse::<i64>().ok().map(ObL::I64),
+            (ObL::Str(str_), Type::U8) => return str_.trim().parse::<u8>().ok().map(ObL::U8),
+            (ObL::Str(str_), Type::F64) => return str_.trim().parse::<f64>().ok().map(ObL::F64),
+            (ObL::Str(str_), Type::Bool) => return str_.trim().parse::<bool>().ok().map(ObL::Bool),
+            (ObL::Bool(bool_), Type::Bool) => return Some(ObL::Bool(*bool_)),
+            (ObL::Bool(bool_), target) => {
+                return Interpreter::cast_literal(&ObL::I32(*bool_ as i32), target)
+            }
+            (_, Type::I32) => "to_i32",
+            (_, Type::I64) => "to_i64",
+            (_, Type::U8) => "to_u8",
+            (_, Type::F64) => "to_f64",
+            _ => return None,
+        };
+        literal.convert(method)
+    }
+    // compound assignment right value, postfix `a++` step one of the current value type.
+    fn assign_op_value(&self, expr: &AssignOp, current: &Object) -> Result<Object, JokerError> {
+        match &expr.value {
+            Some(value) => {
+                self.value_or_raise(&expr.opera, value, String::from("assign op invalid value."))
+            }
+            None => Ok(Object::new(match &*current.get() {
+                OEnum::Literal(ObL::I64(_)) => literal_i64(1),
+                OEnum::Literal(ObL::U8(_)) => literal_u8(1),
+                OEnum::Literal(ObL::F64(_)) => literal_f64(1.0),
+                _ => literal_i32(1),
+            })),
+        }
+    }
+    fn get_attribute(&self, object: &Object, name: &Token) -> Result<Option<Object>, JokerError> {
+        let result: Result<Option<Object>, JokerError> = match &*object.get() {
+            OEnum::Instance(instance) => match instance.getter(name)? {
+                Some(object) => Ok(Some(object)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    format!("instance getter undefined attribute '{}'.", name.lexeme),
+                ))),
+            },
+            OEnum::Caller(caller) => match caller {
+                Caller::Class(class) => match class.getter(name)? {
+                    Some(object) => Ok(Some(object)),
+                    None => Err(JokerError::Interpreter(InterpreterError::new(
+                        name,
+                        format!("class getter undefined attribute '{}'.", name.lexeme),
+                    ))),
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    String::from("this caller not getter attribute."),
+                ))),
+            },
+            OEnum::List(list) => match list.getter(name)? {
+                Some(object) => Ok(Some(object)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    format!("list getter undefined attribute '{}'.", name.lexeme),
+                ))),
+            },
+            OEnum::Map(map) => match map.getter(name)? {
+                Some(object) => Ok(Some(object)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    format!("map getter undefined attribute '{}'.", name.lexeme),
+                ))),
+            },
+            OEnum::Struct(struct_) => Ok(Some(struct_.getter(name)?)),
+            OEnum::EnumDecl(enum_decl) => Ok(Some(enum_decl.getter(name)?)),
+            OEnum::Module(module) => Ok(Some(module.getter(name)?)),
+            OEnum::Enum(enum_) => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!("enum value '{}' not getter attribute.", enum_),
+            ))),
+            OEnum::StructDecl(struct_decl) => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!("struct '{}' not getter attribute.", struct_decl),
+            ))),
+            OEnum::Literal(literal) => match literal.getter(name)? {
+                Some(object) => Ok(Some(object)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    format!("literal '{}' not getter attribute.", literal),
+                ))),
+            },
+            OEnum::Tuple(tuple) => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!("tuple '{}' not getter attribute, use index.", tuple),
+            ))),
+            OEnum::Range(range) => match range.getter(name)? {
+                Some(method) => Ok(Some(method)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    format!("range '{}' not have method '{}'.", range, name.lexeme),
+                ))),
+            },
+            OEnum::Generator(generator) => match generator.getter(name)? {
+                Some(method) => Ok(Some(method)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    name,
+                    format!(
+                        "generator '{}' not have method '{}'.",
+                        generator, name.lexeme
+                    ),
+                ))),
+            },
+        };
+
+        result
+    }
+    fn set_attribute(
+        &self,
+        object: &Object,
+        name: &Token,
+        value: Object,
+    ) -> Result<Option<Object>, JokerError> {
+        // class static setter only borrow class, class maybe calling now.
+        if let OEnum::Caller(Caller::Class(class)) = &*object.get() {
+            class.set_static(name, value.clone())?;
+            return Ok(Some(value));
+        }
+        let result: Result<Option<Object>, JokerError> = match &mut *object.get_mut() {
+            OEnum::Instance(instance) => {
+                instance.setter(name, value.clone())?;
+                Ok(Some(value))
+            }
+            OEnum::Struct(struct_) => {
+                struct_.setter(name, value.clone())?;
+                Ok(Some(value))
+            }
+            _ => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                String::from("setter only instance and struct have attribute."),
+            ))),
+        };
+
+        result
+    }
+    fn get_index(
+        &self,
+        object: &Object,
+        bracket: &Token,
+        index: &Object,
+    ) -> Result<Option<Object>, JokerError> {
+        if let Some(value) = self.call_operator(bracket, "index", object, slice::from_ref(index))? {
+            return Ok(Some(value));
+        }
+        let result: Result<Option<Object>, JokerError> = match &*object.get() {
+            OEnum::List(list) => Ok(Some(list.get(bracket, index)?)),
+            OEnum::Tuple(tuple) => Ok(Some(tuple.get(bracket, index)?)),
+            OEnum::Map(map) => match map.get(bracket, index)? {
+                Some(value) => Ok(Some(value)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    bracket,
+                    format!("map undefined key '{}'.", index),
+                ))),
+            },
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                bracket,
+                format!("object '{}' not support index.", other),
+            ))),
+        };
+
+        result
+    }
+    fn set_index(
+        &self,
+        object: &Object,
+        bracket: &Token,
+        index: &Object,
+        value: Object,
+    ) -> Result<Option<Object>, JokerError> {
+        let result: Result<Option<Object>, JokerError> = match &*object.get() {
+            OEnum::List(list) => {
+                list.set(bracket, index, value.clone())?;
+                Ok(Some(value))
+            }
+            OEnum::Map(map) => {
+                map.set(bracket, index, value.clone())?;
+                Ok(Some(value))
+            }
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                bracket,
+                format!("object '{}' not support index set.", other),
             ))),
-        }
-    }
-    pub fn println_local(&self) {
-        println!("{:?}", self.run_env);
+        };
+
+        result
     }
 }
 
@@ -159,6 +1219,10 @@ impl StmtVisitor<()> for Interpreter {
         match self.evaluate(&stmt.expr) {
             Ok(value) => {
                 if let Some(value) = value {
+                    // instance print through class method `str(this)`.
+                    let value: Object = self
+                        .call_operator(&Token::eof(0), "str", &value, &[])?
+                        .unwrap_or(value);
                     println!("{value}")
                 }
                 Ok(())
@@ -196,69 +1260,83 @@ impl StmtVisitor<()> for Interpreter {
     fn visit_while(&self, stmt: &WhileStmt) -> Result<(), JokerError> {
         while self.is_true(&self.evaluate(&stmt.condition)?.unwrap()) {
             if let Err(err) = self.execute(&stmt.body) {
-                match err {
-                    JokerError::Abort(ControlFlow(control_flow)) => match control_flow {
-                        ControlFlowAbort::Break => break,
-                        ControlFlowAbort::Continue => continue,
-                        ControlFlowAbort::Return(return_value) => {
-                            return Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Return(
-                                return_value,
-                            ))));
-                        }
-                    },
-                    _ => return Err(err),
+                if Interpreter::is_loop_break(err, &stmt.label)? {
+                    break;
                 }
             }
         }
 
         Ok(())
     }
-    fn visit_for(&self, stmt: &ForStmt) -> Result<(), JokerError> {
-        if let Some(initializer) = &stmt.initializer {
-            self.execute(initializer)?
-        }
-        while self.is_true(&self.evaluate(&stmt.condition)?.unwrap()) {
+    fn visit_do_while(&self, stmt: &DoWhileStmt) -> Result<(), JokerError> {
+        loop {
             if let Err(err) = self.execute(&stmt.body) {
-                match err {
-                    JokerError::Abort(ControlFlow(control_flow)) => match control_flow {
-                        ControlFlowAbort::Break => break,
-                        ControlFlowAbort::Continue => {
-                            if let Some(increment) = &stmt.increment {
-                                self.evaluate(increment)?;
-                            }
-                            continue;
-                        }
-                        ControlFlowAbort::Return(return_value) => {
-                            return Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Return(
-                                return_value,
-                            ))));
-                        }
-                    },
-                    _ => return Err(err),
+                if Interpreter::is_loop_break(err, &stmt.label)? {
+                    break;
                 }
             }
-            if let Some(increment) = &stmt.increment {
-                self.evaluate(increment)?;
+            if !self.is_true(&self.evaluate(&stmt.condition)?.unwrap()) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+    fn visit_yield(&self, stmt: &YieldStmt) -> Result<(), JokerError> {
+        // generator body yield run by generator frames, never reach here.
+        Err(JokerError::Interpreter(InterpreterError::new(
+            &stmt.keyword,
+            String::from("Cannot use 'yield' outside of a generator function."),
+        )))
+    }
+    fn visit_for_in(&self, stmt: &ForInStmt) -> Result<(), JokerError> {
+        let iterable: Object = self.value_or_raise(
+            &stmt.keyword,
+            &stmt.iterable,
+            String::from("for in invalid iterable value."),
+        )?;
+        let mut elements: Elements = self.elements(&stmt.keyword, &iterable)?;
+        while let Some(element) = self.next_element(&stmt.keyword, &mut elements)? {
+            // every iteration new env, closure capture this iteration variable.
+            let loop_env: Env =
+                self.for_in_env(stmt, Rc::clone(&self.run_env.borrow()), element)?;
+            if let Err(err) = self.execute_block(slice::from_ref(&*stmt.body), loop_env) {
+                if Interpreter::is_loop_break(err, &stmt.label)? {
+                    break;
+                }
             }
         }
 
         Ok(())
     }
-    fn visit_break(&self, _stmt: &BreakStmt) -> Result<(), JokerError> {
-        Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Break)))
+    fn visit_for(&self, stmt: &ForStmt) -> Result<(), JokerError> {
+        // loop variable live in for scope, every iteration fresh copy, closure capture this iteration.
+        let loop_env: Env = Env::new_with_enclosing(Rc::clone(&self.run_env.borrow()));
+        let previous: Rc<RefCell<Env>> = self.run_env.replace(Rc::new(RefCell::new(loop_env)));
+        let result: Result<(), JokerError> = self.for_loop(stmt);
+        self.run_env.replace(previous);
+        result
+    }
+    fn visit_break(&self, stmt: &BreakStmt) -> Result<(), JokerError> {
+        Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Break(
+            stmt.label.as_ref().map(|label| label.lexeme.clone()),
+        ))))
     }
-    fn visit_continue(&self, _stmt: &ContinueStmt) -> Result<(), JokerError> {
-        Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Continue)))
+    fn visit_continue(&self, stmt: &ContinueStmt) -> Result<(), JokerError> {
+        Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Continue(
+            stmt.label.as_ref().map(|label| label.lexeme.clone()),
+        ))))
     }
     fn visit_fn(&self, stmt: &FnStmt) -> Result<(), JokerError> {
-        let func: Object = Object::new(OEnum::Caller(Caller::Func(Function::User(
-            UserFunction::new(stmt, Rc::clone(&self.run_env.borrow())),
-        ))));
+        // name cell define before capture, recursive fn see itself.
         self.run_env
             .borrow()
             .borrow_mut()
-            .define(stmt.name.lexeme.clone(), Some(func));
-        Ok(())
+            .define(stmt.name.lexeme.clone(), None);
+        let func: Object = Object::new(OEnum::Caller(Caller::Func(Function::User(
+            UserFunction::new(stmt, self.closure_env(&Closure::Fn(stmt.clone()))),
+        ))));
+        self.run_env.borrow().borrow_mut().assign(&stmt.name, func)
     }
     fn visit_return(&self, stmt: &ReturnStmt) -> Result<(), JokerError> {
         let value: Option<Object> = match &stmt.value {
@@ -269,6 +1347,82 @@ impl StmtVisitor<()> for Interpreter {
             value,
         ))))
     }
+    fn visit_throw(&self, stmt: &ThrowStmt) -> Result<(), JokerError> {
+        let value: Object = match self.evaluate(&stmt.value)? {
+            Some(value) => value,
+            None => Object::new(OEnum::Literal(ObL::Null)),
+        };
+        Err(JokerError::Abort(ControlFlow(ControlFlowAbort::Throw(
+            value,
+        ))))
+    }
+    fn visit_try(&self, stmt: &TryStmt) -> Result<(), JokerError> {
+        let mut result: Result<(), JokerError> = self.execute(&stmt.try_branch);
+        if let (Err(_), Some(name), Some(catch_branch)) =
+            (&result, &stmt.catch_name, &stmt.catch_branch)
+        {
+            let err: JokerError = result.unwrap_err();
+            // `catch (e: T)` only catch value of type T, other value rethrow.
+            result = match self.catch_value(&err) {
+                Some(value)
+                    if stmt
+                        .catch_type
+                        .as_ref()
+                        .is_none_or(|type_| Interpreter::is_type(&value.get(), type_)) =>
+                {
+                    let mut catch_env: Env =
+                        Env::new_with_enclosing(Rc::clone(&self.run_env.borrow()));
+                    catch_env.define(name.lexeme.clone(), Some(value));
+                    self.execute_block(std::slice::from_ref(catch_branch), catch_env)
+                }
+                _ => Err(err),
+            };
+        }
+        // finally always run, it's abort replace try or catch result.
+        if let Some(finally_branch) = &stmt.finally_branch {
+            self.execute(finally_branch)?;
+        }
+        result
+    }
+    fn visit_import(&self, stmt: &ImportStmt) -> Result<(), JokerError> {
+        // module loaded and cached at resolve time.
+        let path: PathBuf = self.module_path(&stmt.path)?;
+        match self.get_module(&path) {
+            Some((module, _)) => {
+                self.run_env
+                    .borrow()
+                    .borrow_mut()
+                    .define(stmt.name.lexeme.clone(), Some(module));
+                Ok(())
+            }
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                &stmt.path,
+                format!("module '{}' is not loaded.", stmt.path.lexeme),
+            ))),
+        }
+    }
+    fn visit_export(&self, stmt: &ExportStmt) -> Result<(), JokerError> {
+        self.execute(&stmt.stmt)
+    }
+    fn visit_const(&self, stmt: &ConstStmt) -> Result<(), JokerError> {
+        // value folded at resolve time.
+        let value: Option<Object> = self.local_fold.borrow()[self.module()]
+            .get(&stmt.value)
+            .cloned();
+        match value {
+            Some(value) => {
+                self.run_env
+                    .borrow()
+                    .borrow_mut()
+                    .define(stmt.name.lexeme.clone(), Some(value));
+                Ok(())
+            }
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                &stmt.name,
+                format!("constant '{}' is not folded.", stmt.name.lexeme),
+            ))),
+        }
+    }
     fn visit_class(&self, stmt: &ClassStmt) -> Result<(), JokerError> {
         self.run_env
             .borrow()
@@ -282,16 +1436,14 @@ impl StmtVisitor<()> for Interpreter {
                         match &*obj.get() {
                             OEnum::Caller(Caller::Class(class)) => Some(class.clone()),
                             _ => {
-                                return Err(JokerError::Interpreter(
-                                    InterpreterError::report_error(
-                                        &super_var.name,
-                                        String::from("super class must be a class."),
-                                    ),
-                                ))
+                                return Err(JokerError::Interpreter(InterpreterError::new(
+                                    &super_var.name,
+                                    String::from("super class must be a class."),
+                                )))
                             }
                         }
                     } else {
-                        return Err(JokerError::Interpreter(InterpreterError::report_error(
+                        return Err(JokerError::Interpreter(InterpreterError::new(
                             &super_var.name,
                             String::from("variable is declare, but not define."),
                         )));
@@ -368,12 +1520,34 @@ impl StmtVisitor<()> for Interpreter {
             None => None,
         };
 
+        // static value is one object cell, class clone share it.
+        let statics: Option<HashMap<String, Object>> = match &stmt.statics {
+            Some(stmts) => {
+                let mut statics: HashMap<String, Object> = HashMap::new();
+                for stmt in stmts {
+                    if let Stmt::VarStmt(var_stmt) = stmt {
+                        let value: Object = match &var_stmt.value {
+                            Some(expr) => match self.evaluate(expr)? {
+                                Some(value) => value.deep_clone(),
+                                None => Object::new(literal_null()),
+                            },
+                            None => Object::new(literal_null()),
+                        };
+                        statics.insert(var_stmt.name.lexeme.clone(), value);
+                    }
+                }
+                Some(statics)
+            }
+            None => None,
+        };
+
         let class: Object = Object::new(OEnum::Caller(Caller::Class(Box::new(Class::new(
             stmt.name.clone(),
             super_class,
             fields,
             methods,
             functions,
+            statics,
         )))));
 
         // super -> run_env
@@ -387,259 +1561,171 @@ impl StmtVisitor<()> for Interpreter {
             .assign(&stmt.name, class)?;
         Ok(())
     }
-}
-
-impl ExprVisitor<Option<Object>> for Interpreter {
-    fn visit_literal(&self, expr: &Literal) -> Result<Option<Object>, JokerError> {
-        Ok(Some(Object::new(expr.value.clone())))
+    fn visit_enum(&self, stmt: &EnumStmt) -> Result<(), JokerError> {
+        let enum_decl: EnumDecl = EnumDecl::new(
+            stmt.name.clone(),
+            stmt.variants
+                .iter()
+                .map(|(variant, payload)| (variant.lexeme.clone(), payload.len()))
+                .collect(),
+        );
+        self.run_env.borrow().borrow_mut().define(
+            stmt.name.lexeme.clone(),
+            Some(Object::new(enum_decl.upcast_into())),
+        );
+        Ok(())
     }
-    fn visit_unary(&self, expr: &Unary) -> Result<Option<Object>, JokerError> {
-        let r_expr: Object = self.value_or_raise(
-            &expr.l_opera,
-            &expr.r_expr,
-            String::from("unary object invalid value."),
-        )?;
-        match expr.l_opera.ttype {
-            TokenType::Minus => match &*r_expr.get() {
-                OEnum::Literal(literal) => match literal {
-                    ObL::I32(i32_) => Ok(Some(Object::new(OEnum::Literal(ObL::I32(-i32_))))),
-                    ObL::F64(f64_) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(-f64_))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.l_opera,
-                        format!(
-                            "[[Minus]] The literal cannot take negative values. {} !=> -{}",
-                            literal, literal
-                        ),
-                    ))),
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                    &expr.l_opera,
-                    String::from("not impl Minus!"),
-                ))),
-            },
-            TokenType::Bang => match &*r_expr.get() {
-                OEnum::Literal(ref literal) => match literal {
-                    ObL::Bool(bool_) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(!bool_))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.l_opera,
-                        format!(
-                            "[[Bang]] The literal cannot take reversed values. {} !=> !{}",
-                            literal, literal
-                        ),
-                    ))),
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                    &expr.l_opera,
-                    String::from("not impl Bang!"),
-                ))),
-            },
-            _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                &expr.l_opera,
-                String::from("Unreachable according to Literal Num!"),
-            ))),
-        }
+    fn visit_interface(&self, _stmt: &InterfaceStmt) -> Result<(), JokerError> {
+        // interface is checked at resolve time, method call dispatch by instance.
+        Ok(())
     }
-    fn visit_binary(&self, expr: &Binary) -> Result<Option<Object>, JokerError> {
-        let l_expr: Object = self.value_or_raise(
-            &expr.m_opera,
-            &expr.l_expr,
-            String::from("binary invalid left value."),
-        )?;
-        let r_expr: Object = self.value_or_raise(
-            &expr.m_opera,
-            &expr.r_expr,
-            String::from("binary invalid right value."),
+    fn visit_type(&self, _stmt: &TypeStmt) -> Result<(), JokerError> {
+        // type alias is resolved at parse time, don't have value.
+        Ok(())
+    }
+    fn visit_destruct(&self, stmt: &DestructStmt) -> Result<(), JokerError> {
+        let value: Object = self.value_or_raise(
+            &stmt.paren,
+            &stmt.value,
+            String::from("destructure invalid value."),
         )?;
-        match expr.m_opera.ttype {
-            TokenType::BangEqual => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_i32 != r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 != r_f64))))),
-                    (ObL::Bool(l_bool), ObL::Bool(r_bool)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_bool != r_bool))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str != r_str))))),
-                    (ObL::Null, ObL::Null) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(false))))),
-                    (ObL::Null, _) | (_, ObL::Null)=> Ok(Some(Object::new(OEnum::Literal(ObL::Bool(true))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[BangEqual]] The literal cannot take bang equal values. !({l_literal} != {r_literal})")
-                    )))
-                }
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl BangEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
-            },
-            TokenType::EqualEqual => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_i32 == r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 == r_f64))))),
-                    (ObL::Bool(l_bool), ObL::Bool(r_bool)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_bool == r_bool))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str == r_str))))),
-                    (ObL::Null, ObL::Null) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(true))))),
-                    (ObL::Null, _) | (_, ObL::Null)=> Ok(Some(Object::new(OEnum::Literal(ObL::Bool(false))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[EqualEqual]] The literal cannot take equal values. !({l_literal} == {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl EqualEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
-            },
-            TokenType::Greater => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_i32 > r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 > r_f64))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str > r_str))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[Greater]] The literal cannot take greater values. !({l_literal} > {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl Greater! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
-            },
-            TokenType::GreaterEqual => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_i32 >= r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 >= r_f64))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str >= r_str))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[GreaterEqual]] The literal cannot take greater equal values. !({l_literal} >= {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl GreaterEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
-            },
-            TokenType::Less => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_i32 < r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 < r_f64))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str < r_str))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[Less]] The literal cannot take less values. !({l_literal} < {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl Less! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
-            },
-            TokenType::LessEqual => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_i32 <= r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_f64 <= r_f64))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(l_str <= r_str))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[LessEqual]] The literal cannot take less equal values. !({l_literal} <= {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl LessEqual! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
-            },
-            TokenType::Plus => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::I32(l_i32 + r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 + r_f64))))),
-                    (ObL::Str(l_str), ObL::Str(r_str)) => {
-                        Ok(Some(Object::new(OEnum::Literal(ObL::Str(format!("{l_str}{r_str}"))))))
-                    },
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[Plus]] The literal cannot take plus values. !({l_literal} + {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl Plus! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
+        let elements: Vec<Object> = match &*value.get() {
+            OEnum::Tuple(tuple) if tuple.elements.len() == stmt.names.len() => {
+                tuple.elements.clone()
             }
-            TokenType::Minus => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::I32(l_i32 - r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 - r_f64))))),
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[Minus]] The literal cannot take minus values. !({l_literal} - {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl Minus! (l_expr: {}, r_expr: {})", l_expr, r_expr),
-                    ))),
+            other => {
+                return Err(JokerError::Interpreter(InterpreterError::new(
+                    &stmt.paren,
+                    format!(
+                        "can't destructure '{}' to {} names.",
+                        other,
+                        stmt.names.len()
+                    ),
+                )))
+            }
+        };
+        for (name, element) in stmt.names.iter().zip(elements) {
+            // '_' skip element.
+            if name.lexeme.ne("_") {
+                self.run_env
+                    .borrow()
+                    .borrow_mut()
+                    .define(name.lexeme.clone(), Some(element.value_copy()));
+            }
+        }
+        Ok(())
+    }
+    fn visit_struct(&self, stmt: &StructStmt) -> Result<(), JokerError> {
+        let struct_decl: StructDecl = StructDecl::new(
+            stmt.name.clone(),
+            stmt.fields
+                .iter()
+                .map(|(field, _)| field.lexeme.clone())
+                .collect(),
+        );
+        self.run_env.borrow().borrow_mut().define(
+            stmt.name.lexeme.clone(),
+            Some(Object::new(struct_decl.upcast_into())),
+        );
+        Ok(())
+    }
+}
+
+impl ExprVisitor<Option<Object>> for Interpreter {
+    fn visit_literal(&self, expr: &Literal) -> Result<Option<Object>, JokerError> {
+        Ok(Some(Object::new(expr.value.clone())))
+    }
+    fn visit_unary(&self, expr: &Unary) -> Result<Option<Object>, JokerError> {
+        let r_expr: Object = self.value_or_raise(
+            &expr.l_opera,
+            &expr.r_expr,
+            String::from("unary object invalid value."),
+        )?;
+        if let Some(method) = expr.l_opera.unary_method() {
+            if let Some(value) = self.call_operator(&expr.l_opera, method, &r_expr, &[])? {
+                return Ok(Some(value));
             }
-            TokenType::Slash => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => {
-                        if r_i32 != &0 {
-                            Ok(Some(Object::new(OEnum::Literal(ObL::I32(l_i32 / r_i32)))))
-                        } else {
-                            Err(JokerError::Interpreter(InterpreterError::report_error(
-                                &expr.m_opera,
-                                format!("[[Slash::ZeroSlashError]]. !({l_literal} / {r_literal})")
-                            )))
-                        }
-                    },
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => {
-                        if r_f64 != &0f64 {
-                            Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 / r_f64)))))
-                        } else {
-                            Err(JokerError::Interpreter(InterpreterError::report_error(
-                                &expr.m_opera,
-                                format!("[[Slash::ZeroSlashError]] . !({l_literal} / {r_literal})")
-                            )))
-                        }
+        }
+        match expr.l_opera.ttype {
+            TokenType::Minus => match &*r_expr.get() {
+                OEnum::Literal(literal) => match literal {
+                    ObL::I32(i32_) => {
+                        self.checked_i32(&expr.l_opera, i32_.checked_neg(), format!("-{literal}"))
+                    }
+                    ObL::I64(i64_) => match i64_.checked_neg() {
+                        Some(value) => Ok(Some(Object::new(literal_i64(value)))),
+                        None => Err(JokerError::Interpreter(InterpreterError::new(
+                            &expr.l_opera,
+                            format!("[[Minus::OverflowError]] i64 overflow. !(-{literal})"),
+                        ))),
                     },
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[Slash]] The literal cannot take slash values. !({l_literal} / {r_literal})")
-                    )))
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl Slash! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                    ObL::F64(f64_) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(-f64_))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        &expr.l_opera,
+                        format!(
+                            "[[Minus]] The literal cannot take negative values. {} !=> -{}",
+                            literal, literal
+                        ),
                     ))),
-            }
-            TokenType::Star => match (&*l_expr.get(), &*r_expr.get()) {
-                (OEnum::Literal(l_literal), OEnum::Literal(r_literal)) => match (l_literal, r_literal) {
-                    (ObL::I32(l_i32), ObL::I32(r_i32)) => Ok(Some(Object::new(OEnum::Literal(ObL::I32(l_i32 / r_i32))))),
-                    (ObL::F64(l_f64), ObL::F64(r_f64)) => Ok(Some(Object::new(OEnum::Literal(ObL::F64(l_f64 * r_f64))))),
-                    (ObL::Str(str_), ObL::I32(i32_))
-                    | (ObL::I32(i32_), ObL::Str(str_)) => {
-                        let mut count = *i32_;
-                        let mut r_str: String = String::with_capacity(size_of_val(str_)* count as usize);
-                        while count > 0 {
-                            r_str.push_str(str_);
-                            count -= 1;
-                        }
-                        Ok(Some(Object::new(OEnum::Literal(ObL::Str(r_str)))))
-                    },
-                    _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("[[Star]] The literal cannot take star values. !({l_literal} * {r_literal})")
-                    )))
                 },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.m_opera,
-                        format!("not impl Star! (l_expr: {}, r_expr: {})", l_expr, r_expr),
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                    &expr.l_opera,
+                    String::from("not impl Minus!"),
+                ))),
+            },
+            TokenType::Bang => match &*r_expr.get() {
+                OEnum::Literal(ref literal) => match literal {
+                    ObL::Bool(bool_) => Ok(Some(Object::new(OEnum::Literal(ObL::Bool(!bool_))))),
+                    _ => Err(JokerError::Interpreter(InterpreterError::new(
+                        &expr.l_opera,
+                        format!(
+                            "[[Bang]] The literal cannot take reversed values. {} !=> !{}",
+                            literal, literal
+                        ),
                     ))),
-            }
-            _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                &expr.m_opera,
-                String::from("Unreachable according other type!")
-            )))
+                },
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                    &expr.l_opera,
+                    String::from("not impl Bang!"),
+                ))),
+            },
+            TokenType::Tilde => match &*r_expr.get() {
+                OEnum::Literal(ObL::I32(i32_)) => {
+                    Ok(Some(Object::new(OEnum::Literal(ObL::I32(!i32_)))))
+                }
+                OEnum::Literal(ObL::I64(i64_)) => Ok(Some(Object::new(literal_i64(!i64_)))),
+                OEnum::Literal(ObL::U8(u8_)) => Ok(Some(Object::new(literal_u8(!u8_)))),
+                OEnum::Literal(literal) => Err(JokerError::Interpreter(InterpreterError::new(
+                    &expr.l_opera,
+                    format!(
+                        "[[Tilde]] The literal cannot take bit not values. {} !=> ~{}",
+                        literal, literal
+                    ),
+                ))),
+                _ => Err(JokerError::Interpreter(InterpreterError::new(
+                    &expr.l_opera,
+                    String::from("not impl Tilde!"),
+                ))),
+            },
+            _ => Err(JokerError::Interpreter(InterpreterError::new(
+                &expr.l_opera,
+                String::from("Unreachable according to Literal Num!"),
+            ))),
         }
     }
+    fn visit_binary(&self, expr: &Binary) -> Result<Option<Object>, JokerError> {
+        let l_expr: Object = self.value_or_raise(
+            &expr.m_opera,
+            &expr.l_expr,
+            String::from("binary invalid left value."),
+        )?;
+        let r_expr: Object = self.value_or_raise(
+            &expr.m_opera,
+            &expr.r_expr,
+            String::from("binary invalid right value."),
+        )?;
+        self.binary(&expr.m_opera, &l_expr, &r_expr)
+    }
     fn visit_grouping(&self, expr: &Grouping) -> Result<Option<Object>, JokerError> {
         self.evaluate(&expr.expr)
     }
@@ -647,18 +1733,23 @@ impl ExprVisitor<Option<Object>> for Interpreter {
         self.look_up_variable(&expr.name, &Expr::Variable(expr.clone()))
     }
     fn visit_assign(&self, expr: &Assign) -> Result<Option<Object>, JokerError> {
-        let value: Object = self.value_or_raise(
-            &expr.name,
-            &expr.value,
-            String::from("assign invalid value."),
-        )?;
-        match self.local_resolve.borrow().get(&Expr::Assign(expr.clone())) {
+        let value: Object = self
+            .value_or_raise(
+                &expr.name,
+                &expr.value,
+                String::from("assign invalid value."),
+            )?
+            .value_copy();
+        match self.local_resolve.borrow()[self.module()].get(&Expr::Assign(expr.clone())) {
             Some(depth) => self.run_env.borrow().borrow_mut().assign_with_depth(
                 *depth,
                 &expr.name,
                 value.clone(),
             )?,
-            None => self.global.borrow_mut().assign(&expr.name, value.clone())?,
+            None => self
+                .module_global()
+                .borrow_mut()
+                .assign(&expr.name, value.clone())?,
         }
         Ok(Some(value))
     }
@@ -669,6 +1760,14 @@ impl ExprVisitor<Option<Object>> for Interpreter {
             String::from("logical invalid left value"),
         )?;
         match expr.m_opera.ttype {
+            // a ?? b: a is null then b, b only evaluate when need.
+            TokenType::QuestionQuestion => {
+                if self.is_null(&l_object) {
+                    self.evaluate(&expr.r_expr)
+                } else {
+                    Ok(Some(l_object))
+                }
+            }
             TokenType::Or => {
                 if self.is_true(&l_object) {
                     Ok(Some(Object::new(OEnum::Literal(ObL::Bool(true)))))
@@ -701,7 +1800,7 @@ impl ExprVisitor<Option<Object>> for Interpreter {
                     }
                 }
             }
-            _ => Err(JokerError::Interpreter(InterpreterError::report_error(
+            _ => Err(JokerError::Interpreter(InterpreterError::new(
                 &expr.m_opera,
                 format!("Unsupported logic operator: {:?}", expr.m_opera.ttype),
             ))),
@@ -718,32 +1817,51 @@ impl ExprVisitor<Option<Object>> for Interpreter {
         }
     }
     fn visit_call(&self, expr: &Call) -> Result<Option<Object>, JokerError> {
-        let callee: Object = self.value_or_raise(
-            &expr.paren,
-            &expr.callee,
-            String::from("call object invalid value."),
-        )?;
+        let callee: Object = match expr.callee.as_ref() {
+            // a?.m(): null object skip method call and arguments.
+            Expr::SafeGetter(getter) => {
+                let object: Object = self.value_or_raise(
+                    &getter.name,
+                    &getter.expr,
+                    String::from("safe getter object invalid value."),
+                )?;
+                if self.is_null(&object) {
+                    return Ok(Some(Object::new(literal_null())));
+                }
+                match self.get_attribute(&object, &getter.name)? {
+                    Some(callee) => callee,
+                    None => {
+                        return Err(JokerError::Interpreter(InterpreterError::new(
+                            &expr.paren,
+                            String::from("call object invalid value."),
+                        )))
+                    }
+                }
+            }
+            callee => self.value_or_raise(
+                &expr.paren,
+                callee,
+                String::from("call object invalid value."),
+            )?,
+        };
 
         let mut arguments: Vec<Object> = Vec::new();
         for arg in &expr.arguments {
-            arguments.push(self.evaluate(arg)?.unwrap());
+            arguments.push(self.evaluate(arg)?.unwrap().value_copy());
+        }
+        let mut named: Vec<(Token, Object)> = Vec::new();
+        for (name, arg) in &expr.named {
+            named.push((name.clone(), self.evaluate(arg)?.unwrap().value_copy()));
         }
 
         let result: Result<Option<Object>, JokerError> =
             if let OEnum::Caller(caller) = &*callee.get() {
-                if arguments.len() != caller.arity() {
-                    return Err(JokerError::Call(Argument(ArgumentError::report_error(
-                        &expr.paren,
-                        format!(
-                            "call expected {} arguments but got {}.",
-                            caller.arity(),
-                            arguments.len()
-                        ),
-                    ))));
-                }
-                caller.call(self, &arguments)
+                // positional and named arguments bind to parameters, default and rest by signature.
+                let arguments: Vec<Option<Object>> =
+                    caller.signature().bind(&expr.paren, arguments, named)?;
+                caller.call_with(self, arguments)
             } else {
-                Err(JokerError::Call(NonCallable(NonError::report_error(
+                Err(JokerError::Call(NonCallable(NonError::new(
                     &expr.paren,
                     format!("caller this object is not callable object: '{}'", callee),
                 ))))
@@ -753,7 +1871,7 @@ impl ExprVisitor<Option<Object>> for Interpreter {
     }
     fn visit_lambda(&self, expr: &LambdaExpr) -> Result<Option<Object>, JokerError> {
         let lambda: Object = Object::new(OEnum::Caller(Caller::Func(Function::Lambda(
-            Lambda::new(expr, Rc::clone(&self.run_env.borrow())),
+            Lambda::new(expr, self.closure_env(&Closure::Lambda(expr.clone()))),
         ))));
         Ok(Some(lambda))
     }
@@ -763,39 +1881,7 @@ impl ExprVisitor<Option<Object>> for Interpreter {
             &expr.expr,
             String::from("getter object invalid value."),
         )?;
-        let result: Result<Option<Object>, JokerError> = match &*object.get() {
-            OEnum::Instance(instance) => match instance.getter(&expr.name)? {
-                Some(object) => Ok(Some(object)),
-                None => Err(JokerError::Interpreter(InterpreterError::report_error(
-                    &expr.name,
-                    format!(
-                        "instance getter undefined attribute '{}'.",
-                        expr.name.lexeme
-                    ),
-                ))),
-            },
-            OEnum::Caller(caller) => match caller {
-                Caller::Class(class) => match class.getter(&expr.name)? {
-                    Some(object) => Ok(Some(object)),
-                    None => Err(JokerError::Interpreter(InterpreterError::report_error(
-                        &expr.name,
-                        format!("class getter undefined attribute '{}'.", expr.name.lexeme),
-                    ))),
-                },
-                _ => Err(JokerError::Interpreter(InterpreterError::report_error(
-                    &expr.name,
-                    String::from("this caller not getter attribute."),
-                ))),
-            },
-            OEnum::Literal(literal) => {
-                Err(JokerError::Interpreter(InterpreterError::report_error(
-                    &expr.name,
-                    format!("literal '{}' not getter attribute.", literal),
-                )))
-            }
-        };
-
-        result
+        self.get_attribute(&object, &expr.name)
     }
     fn visit_setter(&self, expr: &Setter) -> Result<Option<Object>, JokerError> {
         let object: Object = self.value_or_raise(
@@ -805,30 +1891,21 @@ impl ExprVisitor<Option<Object>> for Interpreter {
         )?;
         // if this handle in object.get_mut, can raise error:
         //      - error: already mutably borrowed: BorrowError
-        let value: Object = self.value_or_raise(
-            &expr.name,
-            &expr.r_expr,
-            String::from("setter object invalid right value."),
-        )?;
-        let result: Result<Option<Object>, JokerError> = match &mut *object.get_mut() {
-            OEnum::Instance(instance) => {
-                instance.setter(&expr.name, value.clone())?;
-                Ok(Some(value))
-            }
-            _ => Err(JokerError::Interpreter(InterpreterError::report_error(
+        let value: Object = self
+            .value_or_raise(
                 &expr.name,
-                String::from("setter only instance have attribute."),
-            ))),
-        };
-
-        result
+                &expr.r_expr,
+                String::from("setter object invalid right value."),
+            )?
+            .value_copy();
+        self.set_attribute(&object, &expr.name, value)
     }
     fn visit_this(&self, expr: &This) -> Result<Option<Object>, JokerError> {
         self.look_up_variable(&expr.keyword, &Expr::This(expr.clone()))
     }
     fn visit_super(&self, expr: &Super) -> Result<Option<Object>, JokerError> {
-        let binding: std::cell::Ref<HashMap<Expr, usize>> = self.local_resolve.borrow();
-        if let Some(depth) = binding.get(&Expr::Super(expr.clone())) {
+        let binding: std::cell::Ref<Vec<HashMap<Expr, usize>>> = self.local_resolve.borrow();
+        if let Some(depth) = binding[self.module()].get(&Expr::Super(expr.clone())) {
             if let Some(super_obj) = self
                 .run_env
                 .borrow()
@@ -857,29 +1934,389 @@ impl ExprVisitor<Option<Object>> for Interpreter {
                             }
                         }
                         None => {
-                            return Err(JokerError::Interpreter(InterpreterError::report_error(
+                            return Err(JokerError::Interpreter(InterpreterError::new(
                                 &expr.method,
                                 format!("super class undefined method '{}'.", expr.method.lexeme),
                             )))
                         }
                     }
                 };
-                return Err(JokerError::Interpreter(InterpreterError::report_error(
+                return Err(JokerError::Interpreter(InterpreterError::new(
                     &expr.keyword,
                     String::from("super object need class instance, but not find this instance."),
                 )));
             };
-            Err(JokerError::Interpreter(InterpreterError::report_error(
+            Err(JokerError::Interpreter(InterpreterError::new(
                 &expr.keyword,
                 String::from("super object is declared, but not define."),
             )))
         } else {
-            Err(JokerError::Interpreter(InterpreterError::report_error(
+            Err(JokerError::Interpreter(InterpreterError::new(
                 &expr.keyword,
                 String::from("env not have super pos info."),
             )))
         }
     }
+    fn visit_list(&self, expr: &ListExpr) -> Result<Option<Object>, JokerError> {
+        let mut elements: Vec<Object> = Vec::new();
+        for element in &expr.elements {
+            elements.push(
+                self.value_or_raise(
+                    &expr.bracket,
+                    element,
+                    String::from("list element invalid value."),
+                )?
+                .value_copy(),
+            );
+        }
+        Ok(Some(Object::new(OEnum::List(List::new(elements)))))
+    }
+    fn visit_match(&self, expr: &Match) -> Result<Option<Object>, JokerError> {
+        let value: Object = self.value_or_raise(
+            &expr.keyword,
+            &expr.value,
+            String::from("match object invalid value."),
+        )?;
+        for arm in &expr.arms {
+            if self.is_match_pattern(&arm.pattern, &value) {
+                if let Pattern::Class {
+                    name: _,
+                    binding: Some(binding),
+                } = &arm.pattern
+                {
+                    let mut arm_env: Env =
+                        Env::new_with_enclosing(Rc::clone(&self.run_env.borrow()));
+                    arm_env.define(binding.lexeme.clone(), Some(value.clone()));
+                    return self.evaluate_local(&arm.body, arm_env);
+                }
+                if let Pattern::Variant {
+                    name: _,
+                    variant: _,
+                    bindings,
+                } = &arm.pattern
+                {
+                    // variant arm always have self scope, same as resolver.
+                    let mut arm_env: Env =
+                        Env::new_with_enclosing(Rc::clone(&self.run_env.borrow()));
+                    if let OEnum::Enum(enum_) = &*value.get() {
+                        for (binding, payload) in bindings.iter().zip(enum_.values.iter()) {
+                            arm_env.define(binding.lexeme.clone(), Some(payload.clone()));
+                        }
+                    }
+                    return self.evaluate_local(&arm.body, arm_env);
+                }
+                return self.evaluate(&arm.body);
+            }
+        }
+        Err(JokerError::Interpreter(InterpreterError::new(
+            &expr.keyword,
+            format!("match not have arm matched value '{}'.", value),
+        )))
+    }
+    fn visit_map(&self, expr: &MapExpr) -> Result<Option<Object>, JokerError> {
+        let map: Map = Map::new();
+        for (key, value) in &expr.pairs {
+            let key: Object =
+                self.value_or_raise(&expr.brace, key, String::from("map key invalid value."))?;
+            let value: Object = self
+                .value_or_raise(&expr.brace, value, String::from("map value invalid value."))?
+                .value_copy();
+            map.set(&expr.brace, &key, value)?;
+        }
+        Ok(Some(Object::new(OEnum::Map(map))))
+    }
+    fn visit_index(&self, expr: &Index) -> Result<Option<Object>, JokerError> {
+        let object: Object = self.value_or_raise(
+            &expr.bracket,
+            &expr.l_expr,
+            String::from("index object invalid value."),
+        )?;
+        let index: Object = self.value_or_raise(
+            &expr.bracket,
+            &expr.index,
+            String::from("index invalid value."),
+        )?;
+        self.get_index(&object, &expr.bracket, &index)
+    }
+    fn visit_index_set(&self, expr: &IndexSet) -> Result<Option<Object>, JokerError> {
+        let object: Object = self.value_or_raise(
+            &expr.bracket,
+            &expr.l_expr,
+            String::from("index set object invalid left value."),
+        )?;
+        let index: Object = self.value_or_raise(
+            &expr.bracket,
+            &expr.index,
+            String::from("index set invalid index value."),
+        )?;
+        let value: Object = self
+            .value_or_raise(
+                &expr.bracket,
+                &expr.r_expr,
+                String::from("index set object invalid right value."),
+            )?
+            .value_copy();
+        self.set_index(&object, &expr.bracket, &index, value)
+    }
+    fn visit_struct_init(&self, expr: &StructInit) -> Result<Option<Object>, JokerError> {
+        let struct_decl: Object =
+            match self.look_up_variable(&expr.name, &Expr::StructInit(expr.clone()))? {
+                Some(object) => object,
+                None => {
+                    return Err(JokerError::Interpreter(InterpreterError::new(
+                        &expr.name,
+                        String::from("struct is declare, but not define."),
+                    )))
+                }
+            };
+        let field_names: Vec<String> = match &*struct_decl.get() {
+            OEnum::StructDecl(struct_decl) => struct_decl.fields.clone(),
+            other => {
+                return Err(JokerError::Interpreter(InterpreterError::new(
+                    &expr.name,
+                    format!("'{}' is not struct, can't init.", other),
+                )))
+            }
+        };
+        // fields order by struct declaration, not by init.
+        let mut fields: Vec<(String, Object)> = Vec::with_capacity(field_names.len());
+        for field_name in field_names {
+            match expr
+                .fields
+                .iter()
+                .find(|(field, _)| field.lexeme.eq(&field_name))
+            {
+                Some((field, value)) => {
+                    let value: Object = self
+                        .value_or_raise(field, value, String::from("struct field invalid value."))?
+                        .value_copy();
+                    fields.push((field_name, value));
+                }
+                None => {
+                    return Err(JokerError::Interpreter(InterpreterError::new(
+                        &expr.name,
+                        format!(
+                            "struct '{}' missing field '{}'.",
+                            expr.name.lexeme, field_name
+                        ),
+                    )))
+                }
+            }
+        }
+        Ok(Some(Object::new(
+            Struct::new(expr.name.clone(), fields).upcast_into(),
+        )))
+    }
+    fn visit_assign_op(&self, expr: &AssignOp) -> Result<Option<Object>, JokerError> {
+        // target evaluate only once: `a.b += c` get and set on the same object.
+        match &*expr.target {
+            Expr::Variable(variable) => {
+                let current: Object =
+                    match self.look_up_variable(&variable.name, &Expr::AssignOp(expr.clone()))? {
+                        Some(object) => object,
+                        None => {
+                            return Err(JokerError::Interpreter(InterpreterError::new(
+                                &variable.name,
+                                format!("variable '{}' is not define.", variable.name.lexeme),
+                            )))
+                        }
+                    };
+                let value: Object = self.assign_op_value(expr, &current)?;
+                // postfix value is the old value, copy it before set.
+                let old: Object = Object::new(current.get().clone());
+                let result: Object = self
+                    .binary(&expr.opera, &current, &value)?
+                    .unwrap_or_else(|| Object::new(literal_null()))
+                    .value_copy();
+                match self.local_resolve.borrow()[self.module()].get(&Expr::AssignOp(expr.clone()))
+                {
+                    Some(depth) => self.run_env.borrow().borrow_mut().assign_with_depth(
+                        *depth,
+                        &variable.name,
+                        result.clone(),
+                    )?,
+                    None => self
+                        .module_global()
+                        .borrow_mut()
+                        .assign(&variable.name, result.clone())?,
+                }
+                Ok(Some(match expr.value {
+                    Some(_) => result,
+                    None => old,
+                }))
+            }
+            Expr::Getter(getter) => {
+                let object: Object = self.value_or_raise(
+                    &getter.name,
+                    &getter.expr,
+                    String::from("assign op object invalid value."),
+                )?;
+                let current: Object = self
+                    .get_attribute(&object, &getter.name)?
+                    .unwrap_or_else(|| Object::new(literal_null()));
+                let value: Object = self.assign_op_value(expr, &current)?;
+                // postfix value is the old value, copy it before set.
+                let old: Object = Object::new(current.get().clone());
+                let result: Object = self
+                    .binary(&expr.opera, &current, &value)?
+                    .unwrap_or_else(|| Object::new(literal_null()))
+                    .value_copy();
+                let value: Option<Object> = self.set_attribute(&object, &getter.name, result)?;
+                Ok(match expr.value {
+                    Some(_) => value,
+                    None => Some(old),
+                })
+            }
+            Expr::Index(index_expr) => {
+                let object: Object = self.value_or_raise(
+                    &index_expr.bracket,
+                    &index_expr.l_expr,
+                    String::from("assign op object invalid value."),
+                )?;
+                let index: Object = self.value_or_raise(
+                    &index_expr.bracket,
+                    &index_expr.index,
+                    String::from("assign op invalid index value."),
+                )?;
+                let current: Object = self
+                    .get_index(&object, &index_expr.bracket, &index)?
+                    .unwrap_or_else(|| Object::new(literal_null()));
+                let value: Object = self.assign_op_value(expr, &current)?;
+                // postfix value is the old value, copy it before set.
+                let old: Object = Object::new(current.get().clone());
+                let result: Object = self
+                    .binary(&expr.opera, &current, &value)?
+                    .unwrap_or_else(|| Object::new(literal_null()))
+                    .value_copy();
+                let value: Option<Object> =
+                    self.set_index(&object, &index_expr.bracket, &index, result)?;
+                Ok(match expr.value {
+                    Some(_) => value,
+                    None => Some(old),
+                })
+            }
+            _ => Err(JokerError::Interpreter(InterpreterError::new(
+                &expr.opera,
+                String::from("Invalid assignment target."),
+            ))),
+        }
+    }
+    fn visit_template(&self, expr: &Template) -> Result<Option<Object>, JokerError> {
+        let mut text: String = String::new();
+        for part in &expr.parts {
+            let value: Object =
+                self.value_or_raise(&expr.token, part, String::from("template invalid value."))?;
+            // instance to string through class method `str(this)`.
+            let value: Object = self
+                .call_operator(&expr.token, "str", &value, &[])?
+                .unwrap_or(value);
+            let part: String = match &*value.get() {
+                OEnum::Literal(ObL::Str(str_)) => str_.clone(),
+                other => other.to_string(),
+            };
+            text.push_str(&part);
+        }
+        Ok(Some(Object::new(literal_str(text))))
+    }
+    fn visit_cast(&self, expr: &Cast) -> Result<Option<Object>, JokerError> {
+        let value: Object = self.value_or_raise(
+            &expr.keyword,
+            &expr.expr,
+            String::from("cast invalid value."),
+        )?;
+        let result: Result<Option<Object>, JokerError> = match &*value.get() {
+            OEnum::Literal(literal) => match Interpreter::cast_literal(literal, &expr.target) {
+                Some(casted) => Ok(Some(Object::new(OEnum::Literal(casted)))),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    &expr.keyword,
+                    format!(
+                        "{} !({} as {})",
+                        if let ObL::Str(_) = literal {
+                            "[[Cast::ParseError]] invalid text."
+                        } else {
+                            "[[Cast::OverflowError]] value out of range."
+                        },
+                        literal,
+                        expr.target
+                    ),
+                ))),
+            },
+            // identity, class up cast and checked down cast keep the same object.
+            other if Interpreter::is_type(other, &expr.target) => Ok(Some(value.clone())),
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                &expr.keyword,
+                format!(
+                    "[[Cast]] object '{}' can't cast to '{}'.",
+                    other, expr.target
+                ),
+            ))),
+        };
+
+        result
+    }
+    fn visit_range(&self, expr: &RangeExpr) -> Result<Option<Object>, JokerError> {
+        let start: Object = self.value_or_raise(
+            &expr.opera,
+            &expr.start,
+            String::from("range start invalid value."),
+        )?;
+        let end: Object = self.value_or_raise(
+            &expr.opera,
+            &expr.end,
+            String::from("range end invalid value."),
+        )?;
+        let result = match (&*start.get(), &*end.get()) {
+            (OEnum::Literal(ObL::I32(start)), OEnum::Literal(ObL::I32(end))) => {
+                Ok(Some(Object::new(
+                    Range::new(*start, *end, expr.opera.ttype == TokenType::DotDotEqual)
+                        .upcast_into(),
+                )))
+            }
+            (start, end) => Err(JokerError::Interpreter(InterpreterError::new(
+                &expr.opera,
+                format!(
+                    "range bound need i32, but this is '{}{}{}'.",
+                    start, expr.opera.lexeme, end
+                ),
+            ))),
+        };
+
+        result
+    }
+    fn visit_tuple(&self, expr: &TupleExpr) -> Result<Option<Object>, JokerError> {
+        let mut elements: Vec<Object> = Vec::new();
+        for element in &expr.elements {
+            elements.push(
+                self.value_or_raise(
+                    &expr.paren,
+                    element,
+                    String::from("tuple element invalid value."),
+                )?
+                .value_copy(),
+            );
+        }
+        Ok(Some(Object::new(Tuple::new(elements).upcast_into())))
+    }
+    fn visit_is(&self, expr: &Is) -> Result<Option<Object>, JokerError> {
+        let value: Object = self.value_or_raise(
+            &expr.keyword,
+            &expr.expr,
+            String::from("type test invalid value."),
+        )?;
+        let is_type: bool = Interpreter::is_type(&value.get(), &expr.target);
+        Ok(Some(Object::new(literal_bool(is_type))))
+    }
+    fn visit_safe_getter(&self, expr: &SafeGetter) -> Result<Option<Object>, JokerError> {
+        let object: Object = self.value_or_raise(
+            &expr.name,
+            &expr.expr,
+            String::from("safe getter object invalid value."),
+        )?;
+        if self.is_null(&object) {
+            return Ok(Some(Object::new(literal_null())));
+        }
+        self.get_attribute(&object, &expr.name)
+    }
 }
 
 #[derive(Debug)]
@@ -902,10 +2339,11 @@ impl InterpreterError {
             msg,
         }
     }
-    pub fn report_error(token: &Token, msg: String) -> InterpreterError {
-        let inter_err = InterpreterError::new(token, msg);
-        inter_err.report();
-        inter_err
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn msg(&self) -> &str {
+        &self.msg
     }
 }
 
@@ -5,6 +5,7 @@
 use std::env;
 use std::fs;
 use std::io::{self, stdout, Write};
+use std::path::Path;
 use std::rc::Rc;
 use std::result;
 
@@ -43,6 +44,9 @@ impl Joker {
 
     fn run_file(&self, path: &str) -> io::Result<()> {
         let contents: String = fs::read_to_string(path)?;
+        // main module path, import relative to it.
+        self.interpreter
+            .push_module_path(Path::new(path).canonicalize()?);
         if let Err(err) = self.run(contents) {
             match err {
                 JokerError::Scanner(scanner_err) => {
@@ -50,10 +54,23 @@ impl Joker {
                     std::process::exit(65);
                 }
                 JokerError::Parser(_) => std::process::exit(66),
-                JokerError::Env(_) => std::process::exit(67),
-                JokerError::Interpreter(_) => std::process::exit(68),
-                JokerError::Abort(_) => std::process::exit(69),
-                JokerError::Call(_) => std::process::exit(70),
+                // runtime error report when uncaught, try catch can handle it.
+                JokerError::Env(env_err) => {
+                    env_err.report();
+                    std::process::exit(67);
+                }
+                JokerError::Interpreter(inter_err) => {
+                    inter_err.report();
+                    std::process::exit(68);
+                }
+                JokerError::Abort(abort_err) => {
+                    abort_err.report();
+                    std::process::exit(69);
+                }
+                JokerError::Call(call_err) => {
+                    call_err.report();
+                    std::process::exit(70);
+                }
                 JokerError::System(_) => std::process::exit(71),
                 JokerError::Resolver(_) => std::process::exit(72),
             }
@@ -74,7 +91,16 @@ impl Joker {
                 if line == "@" {
                     self.interpreter.println_local()
                 };
-                let _ = self.run(line);
+                // runtime error and uncaught throw report, same as run file.
+                if let Err(
+                    err @ (JokerError::Env(_)
+                    | JokerError::Interpreter(_)
+                    | JokerError::Abort(_)
+                    | JokerError::Call(_)),
+                ) = self.run(line)
+                {
+                    err.report();
+                }
                 print!("> ");
                 let _ = stdout().flush();
             } else {
@@ -5,7 +5,7 @@ use std::fmt::Display;
 use std::time::SystemTime;
 
 use super::{
-    callable::Callable,
+    callable::{Callable, Signature},
     error::{JokerError, SystemError, SystemTimeError},
     interpreter::Interpreter,
     object::{Literal, Object as OEnum},
@@ -32,8 +32,8 @@ impl Callable for NativeClock {
             ))),
         }
     }
-    fn arity(&self) -> usize {
-        0
+    fn signature(&self) -> Signature {
+        Signature::arity(0)
     }
 }
 impl Display for NativeClock {
@@ -5,7 +5,7 @@ use std::{
 };
 
 use crate::joker::{
-    callable::Callable,
+    callable::{Callable, Signature},
     error::JokerError,
     interpreter::{Interpreter, InterpreterError},
     object::{Instance, Object as OEnum, UpCast},
@@ -23,6 +23,7 @@ pub struct Class {
     pub fields: Option<HashMap<String, Option<Object>>>,
     pub methods: Option<HashMap<String, MethodFunction>>,
     pub functions: Option<HashMap<String, UserFunction>>,
+    pub statics: Option<HashMap<String, Object>>, // shared by class clone, instance and sub class
 }
 
 impl DeepClone for Class {
@@ -53,6 +54,7 @@ impl Class {
         fields: Option<HashMap<String, Option<Object>>>,
         methods: Option<HashMap<String, MethodFunction>>,
         functions: Option<HashMap<String, UserFunction>>,
+        statics: Option<HashMap<String, Object>>,
     ) -> Class {
         Class {
             name,
@@ -60,6 +62,60 @@ impl Class {
             fields,
             methods,
             functions,
+            statics,
+        }
+    }
+    // self class or super class name is eq name.
+    pub fn is_class_or_inherit(&self, name: &str) -> bool {
+        let mut current: Option<&Class> = Some(self);
+        while let Some(class) = current {
+            if class.name.lexeme.eq(name) {
+                return true;
+            }
+            current = class.super_class.as_deref();
+        }
+        false
+    }
+    // static value cell, self class first then super class.
+    pub fn get_static(&self, name: &str) -> Option<Object> {
+        if let Some(value) = self.statics.as_ref().and_then(|statics| statics.get(name)) {
+            return Some(value.clone());
+        }
+        match &self.super_class {
+            Some(super_class) => super_class.get_static(name),
+            None => None,
+        }
+    }
+    // modify static value in place, all class clone see it.
+    pub fn set_static(&self, name: &Token, value: Object) -> Result<(), JokerError> {
+        match self.get_static(&name.lexeme) {
+            Some(static_value) => {
+                static_value.set(value.get().clone());
+                Ok(())
+            }
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!(
+                    "class '{}' don't have static attribute '{}'.",
+                    self.name.lexeme, name.lexeme
+                ),
+            ))),
+        }
+    }
+    // instance class copy, every instance own declared field value, class keep only initial value.
+    fn fresh(&self) -> Box<Class> {
+        let mut class: Box<Class> = Box::new(self.clone());
+        class.fresh_fields();
+        class
+    }
+    fn fresh_fields(&mut self) {
+        if let Some(fields) = self.fields.as_mut() {
+            for value in fields.values_mut().flatten() {
+                *value = value.deep_clone();
+            }
+        }
+        if let Some(super_class) = self.super_class.as_mut() {
+            super_class.fresh_fields();
         }
     }
     pub fn get_field(&self, name: &str) -> Option<&Option<Object>> {
@@ -98,12 +154,18 @@ impl Class {
     }
     // used class find
     pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
+        if let Some(statics) = &self.statics {
+            if let Some(object) = statics.get(&name.lexeme) {
+                return Ok(Some(object.clone()));
+            }
+        }
+
         if let Some(fields) = &self.fields {
             if let Some(object) = fields.get(&name.lexeme) {
                 match object {
                     Some(_) => return Ok(object.clone()),
                     None => {
-                        return Err(JokerError::Interpreter(InterpreterError::report_error(
+                        return Err(JokerError::Interpreter(InterpreterError::new(
                             name,
                             format!(
                                 "Error: class attribute '{}' is declared, but not define.",
@@ -161,6 +223,12 @@ impl Hash for Class {
                 fun.hash(state);
             }
         }
+        if let Some(statics) = &self.statics {
+            for (name, obj) in statics {
+                name.hash(state);
+                obj.hash(state);
+            }
+        }
     }
 }
 
@@ -170,15 +238,26 @@ impl Callable for Box<Class> {
         interpreter: &Interpreter,
         arguments: &[Object],
     ) -> Result<Option<Object>, JokerError> {
-        let instance: Instance = Instance::new(self.clone());
+        let instance: Instance = Instance::new(self.fresh());
         match self.get_method("init") {
             Some(initializer) => initializer.bind(instance).call(interpreter, arguments),
             None => Ok(Some(Object::new(OEnum::Instance(Box::new(instance))))),
         }
     }
-    fn arity(&self) -> usize {
+    fn signature(&self) -> Signature {
         self.get_method("init")
-            .map_or(0, |initializer| initializer.arity())
+            .map_or(Signature::arity(0), |initializer| initializer.signature())
+    }
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, JokerError> {
+        let instance: Instance = Instance::new(self.fresh());
+        match self.get_method("init") {
+            Some(initializer) => initializer.bind(instance).call_with(interpreter, arguments),
+            None => Ok(Some(Object::new(OEnum::Instance(Box::new(instance))))),
+        }
     }
 }
 
@@ -190,8 +269,8 @@ impl Display for Class {
         };
         write!(
             f,
-            "Class(name: {}, super_class: {}, fields: {:?}, methods: {:?}, functions: {:?})",
-            self.name, super_name, self.fields, self.methods, self.functions
+            "Class(name: {}, super_class: {}, fields: {:?}, methods: {:?}, functions: {:?}, statics: {:?})",
+            self.name, super_name, self.fields, self.methods, self.functions, self.statics
         )
     }
 }
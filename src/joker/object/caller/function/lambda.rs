@@ -14,7 +14,7 @@ use std::{
 use crate::joker::{
     abort::{ControlFlowAbort, Error::ControlFlow},
     ast::{ExprStmt, Lambda as LambdaExpr, Stmt},
-    callable::{Callable, Error::Struct, StructError},
+    callable::{Callable, Error::Struct, Signature, StructError},
     env::Env,
     error::JokerError,
     interpreter::Interpreter,
@@ -31,12 +31,10 @@ pub struct Lambda {
     closure: Rc<RefCell<Env>>,
 }
 
+// function value share closure upvalues, copy is same closure.
 impl DeepClone for Lambda {
     fn deep_clone(&self) -> Self {
-        Lambda {
-            expr: Rc::clone(&self.expr),
-            closure: Rc::new(RefCell::new((*self.closure.borrow()).clone())),
-        }
+        self.clone()
     }
 }
 
@@ -88,6 +86,10 @@ impl Callable for Lambda {
                 );
             }
         }
+        // tuple params: var (a, b) = (a, b);
+        if !self.expr.destructs.is_empty() {
+            lambda_env = interpreter.execute_in(&self.expr.destructs, lambda_env)?;
+        }
         match &*self.expr.body {
             Stmt::BlockStmt(block) => {
                 if let Err(err) = interpreter.execute_block(&block.stmts, lambda_env) {
@@ -111,8 +113,8 @@ impl Callable for Lambda {
         }
         Ok(None)
     }
-    fn arity(&self) -> usize {
-        self.expr.params.as_ref().map_or(0, |params| params.len())
+    fn signature(&self) -> Signature {
+        Signature::from_params(self.expr.params.as_deref().unwrap_or_default())
     }
 }
 
@@ -14,16 +14,15 @@ use std::{
 use crate::joker::{
     abort::{ControlFlowAbort, Error::ControlFlow},
     ast::FnStmt,
-    callable::Callable,
+    callable::{Callable, Signature},
     env::Env,
     error::JokerError,
     interpreter::Interpreter,
-    object::{Caller, Instance, Object as OEnum, UpCast},
-    token::Token,
+    object::{Caller, Generator, Instance, Object as OEnum, UpCast},
     types::{DeepClone, Object},
 };
 
-use super::{Binder, Function};
+use super::{define_params, Binder, Function};
 
 #[derive(Clone)]
 pub struct MethodFunction {
@@ -31,12 +30,10 @@ pub struct MethodFunction {
     closure: Rc<RefCell<Env>>,
 }
 
+// function value share closure upvalues, copy is same closure.
 impl DeepClone for MethodFunction {
     fn deep_clone(&self) -> Self {
-        MethodFunction {
-            stmt: Rc::clone(&self.stmt),
-            closure: Rc::new(RefCell::new((*self.closure.borrow()).clone())),
-        }
+        self.clone()
     }
 }
 
@@ -90,22 +87,35 @@ impl Callable for MethodFunction {
         &self,
         interpreter: &Interpreter,
         arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        let arguments: Vec<Option<Object>> =
+            self.signature()
+                .bind(&self.stmt.name, arguments.to_vec(), Vec::new())?;
+        self.call_with(interpreter, arguments)
+    }
+    fn signature(&self) -> Signature {
+        Signature::from_params(self.stmt.params.as_deref().unwrap_or_default())
+    }
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
     ) -> Result<Option<Object>, JokerError> {
         let mut instance_env: Env = Env::new_with_enclosing(Rc::clone(&self.closure));
 
         if let Some(params) = &self.stmt.params {
-            for (name, value) in params[1..].iter().zip(arguments) {
-                instance_env.define(
-                    name.parse_ref::<Token>()?.lexeme.clone(),
-                    Some(value.clone()),
-                );
-            }
+            define_params(interpreter, params, arguments, &mut instance_env)?;
+        }
+        if self.stmt.generator {
+            return Ok(Some(Object::new(
+                Generator::new(Rc::clone(&self.stmt), instance_env).upcast_into(),
+            )));
         }
         match interpreter.execute_block(&self.stmt.body, instance_env) {
             Ok(_) => {
                 if self.stmt.name.lexeme.eq("init") {
                     if let Some(this) = self.closure.borrow().symbol.get("this") {
-                        return Ok(this.clone());
+                        return Ok(this.borrow().clone());
                     } else {
                         eprintln!("class init method return instance error.")
                     }
@@ -115,7 +125,7 @@ impl Callable for MethodFunction {
                 JokerError::Abort(ControlFlow(ControlFlowAbort::Return(return_value))) => {
                     if self.stmt.name.lexeme.eq("init") {
                         if let Some(this) = self.closure.borrow().symbol.get("this") {
-                            return Ok(this.clone());
+                            return Ok(this.borrow().clone());
                         } else {
                             eprintln!("class init method return instance error.")
                         }
@@ -128,12 +138,6 @@ impl Callable for MethodFunction {
         };
         Ok(None)
     }
-    fn arity(&self) -> usize {
-        self.stmt
-            .params
-            .as_ref()
-            .map_or(0, |params| params.len() - 1) // this
-    }
 }
 
 impl Display for MethodFunction {
@@ -17,13 +17,13 @@ use std::{
 use crate::joker::{
     abort::{ControlFlowAbort, Error::ControlFlow},
     ast::FnStmt,
-    callable::Callable,
+    callable::{ArgumentError, Callable, Error::Argument, Signature},
     env::Env,
     error::JokerError,
     interpreter::Interpreter,
-    object::{Caller, Instance, Object as OEnum, UpCast},
+    object::{literal_null, Caller, Generator, Instance, Object as OEnum, UpCast},
     token::Token,
-    types::{DeepClone, Object},
+    types::{DeepClone, Object, ParamPair},
 };
 
 use super::{Lambda, MethodFunction};
@@ -32,6 +32,36 @@ pub trait Binder {
     fn bind(&self, instance: Instance) -> Function;
 }
 
+// bound arguments define in call env, 'this' skip. default parameter without argument
+// evaluate default value every call, earlier parameters visible.
+pub fn define_params(
+    interpreter: &Interpreter,
+    params: &[ParamPair],
+    arguments: Vec<Option<Object>>,
+    env: &mut Env,
+) -> Result<(), JokerError> {
+    for (param, argument) in params
+        .iter()
+        .filter(|param| !param.is_this())
+        .zip(arguments)
+    {
+        let value: Object = match (argument, param) {
+            (Some(value), _) => value,
+            (None, ParamPair::Default { value, .. }) => interpreter
+                .evaluate_local(value, env.clone())?
+                .unwrap_or_else(|| Object::new(literal_null())),
+            (None, param) => {
+                return Err(JokerError::Call(Argument(ArgumentError::new(
+                    param.parse_ref::<Token>()?,
+                    String::from("call missing argument for parameter."),
+                ))))
+            }
+        };
+        env.define(param.parse_ref::<Token>()?.lexeme.clone(), Some(value));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Function {
     Native(NativeFunction),
@@ -94,12 +124,24 @@ impl Callable for Function {
             Function::Lambda(lambda) => Callable::call(lambda, interpreter, arguments),
         }
     }
-    fn arity(&self) -> usize {
+    fn signature(&self) -> Signature {
+        match self {
+            Function::Native(native) => Callable::signature(native),
+            Function::User(user) => Callable::signature(user),
+            Function::Method(method) => Callable::signature(method),
+            Function::Lambda(lambda) => Callable::signature(lambda),
+        }
+    }
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, JokerError> {
         match self {
-            Function::Native(native) => Callable::arity(native),
-            Function::User(user) => Callable::arity(user),
-            Function::Method(method) => Callable::arity(method),
-            Function::Lambda(lambda) => Callable::arity(lambda),
+            Function::Native(native) => Callable::call_with(native, interpreter, arguments),
+            Function::User(user) => Callable::call_with(user, interpreter, arguments),
+            Function::Method(method) => Callable::call_with(method, interpreter, arguments),
+            Function::Lambda(lambda) => Callable::call_with(lambda, interpreter, arguments),
         }
     }
 }
@@ -152,8 +194,8 @@ impl Callable for NativeFunction {
     ) -> Result<Option<Object>, JokerError> {
         self.fun.call(interpreter, arguments)
     }
-    fn arity(&self) -> usize {
-        self.fun.arity()
+    fn signature(&self) -> Signature {
+        self.fun.signature()
     }
 }
 
@@ -175,12 +217,10 @@ pub struct UserFunction {
     closure: Rc<RefCell<Env>>,
 }
 
+// function value share closure upvalues, copy is same closure.
 impl DeepClone for UserFunction {
     fn deep_clone(&self) -> Self {
-        UserFunction {
-            stmt: Rc::clone(&self.stmt),
-            closure: Rc::new(RefCell::new((*self.closure.borrow()).clone())),
-        }
+        self.clone()
     }
 }
 
@@ -231,16 +271,30 @@ impl Callable for UserFunction {
         &self,
         interpreter: &Interpreter,
         arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        let arguments: Vec<Option<Object>> =
+            self.signature()
+                .bind(&self.stmt.name, arguments.to_vec(), Vec::new())?;
+        self.call_with(interpreter, arguments)
+    }
+    fn signature(&self) -> Signature {
+        Signature::from_params(self.stmt.params.as_deref().unwrap_or_default())
+    }
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
     ) -> Result<Option<Object>, JokerError> {
         let mut fun_env: Env = Env::new_with_enclosing(self.closure.clone());
 
         if let Some(params) = self.stmt.params.as_ref() {
-            for (name, value) in params.iter().zip(arguments) {
-                fun_env.define(
-                    name.parse_ref::<Token>()?.lexeme.clone(),
-                    Some(value.clone()),
-                );
-            }
+            define_params(interpreter, params, arguments, &mut fun_env)?;
+        }
+        // fn* call not run body, return generator resume by next().
+        if self.stmt.generator {
+            return Ok(Some(Object::new(
+                Generator::new(Rc::clone(&self.stmt), fun_env).upcast_into(),
+            )));
         }
         if let Err(err) = interpreter.execute_block(&self.stmt.body, fun_env) {
             match err {
@@ -252,9 +306,6 @@ impl Callable for UserFunction {
         }
         Ok(None)
     }
-    fn arity(&self) -> usize {
-        self.stmt.params.as_ref().map_or(0, |params| params.len())
-    }
 }
 
 impl Display for UserFunction {
@@ -301,10 +352,20 @@ impl Callable for BinderFunction {
             BinderFunction::Method(method) => Callable::call(method, interpreter, arguments),
         }
     }
-    fn arity(&self) -> usize {
+    fn signature(&self) -> Signature {
+        match self {
+            BinderFunction::User(user) => Callable::signature(user),
+            BinderFunction::Method(method) => Callable::signature(method),
+        }
+    }
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, JokerError> {
         match self {
-            BinderFunction::User(user) => Callable::arity(user),
-            BinderFunction::Method(method) => Callable::arity(method),
+            BinderFunction::User(user) => Callable::call_with(user, interpreter, arguments),
+            BinderFunction::Method(method) => Callable::call_with(method, interpreter, arguments),
         }
     }
 }
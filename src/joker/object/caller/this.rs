@@ -11,7 +11,7 @@ use std::fmt::Display;
 
 use crate::joker::{
     ast::Stmt,
-    callable::Callable,
+    callable::{Callable, Signature},
     error::JokerError,
     interpreter::Interpreter,
     object::{Object as OEnum, UpCast},
@@ -91,10 +91,20 @@ impl Callable for Caller {
             Caller::Class(class) => Callable::call(class, interpreter, arguments),
         }
     }
-    fn arity(&self) -> usize {
+    fn signature(&self) -> Signature {
         match self {
-            Caller::Func(func) => Callable::arity(func),
-            Caller::Class(class) => Callable::arity(class),
+            Caller::Func(func) => Callable::signature(func),
+            Caller::Class(class) => Callable::signature(class),
+        }
+    }
+    fn call_with(
+        &self,
+        interpreter: &Interpreter,
+        arguments: Vec<Option<Object>>,
+    ) -> Result<Option<Object>, JokerError> {
+        match self {
+            Caller::Func(func) => Callable::call_with(func, interpreter, arguments),
+            Caller::Class(class) => Callable::call_with(class, interpreter, arguments),
         }
     }
 }
@@ -0,0 +1,187 @@
+//! This file is enum rs
+//!
+//! - EnumDecl
+//!     - runtime enum declaration: name and variants payload arity.
+//! - Enum
+//!     - variant value: enum name, variant name and payload values.
+//!     - EnumConstructor(native variant constructor: Shape.Circle(1.0))
+//!
+//!
+
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::joker::{
+    callable::{Callable, Signature},
+    error::JokerError,
+    interpreter::{Interpreter, InterpreterError},
+    object::Object as OEnum,
+    token::Token,
+    types::{DeepClone, Object},
+};
+
+use super::{Caller, Function, NativeFunction, UpCast};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumDecl {
+    pub name: Token,
+    pub variants: Vec<(String, usize)>,
+}
+
+impl EnumDecl {
+    pub fn new(name: Token, variants: Vec<(String, usize)>) -> EnumDecl {
+        EnumDecl { name, variants }
+    }
+    // enum variant getter: no payload variant is value, other is constructor.
+    pub fn getter(&self, name: &Token) -> Result<Object, JokerError> {
+        match self
+            .variants
+            .iter()
+            .find(|(variant, _)| variant.eq(&name.lexeme))
+        {
+            Some((_, 0)) => Ok(Object::new(
+                Enum::new(self.name.clone(), name.lexeme.clone(), Vec::new()).upcast_into(),
+            )),
+            Some((_, arity)) => Ok(Object::new(OEnum::Caller(Caller::Func(Function::Native(
+                NativeFunction {
+                    fun: Rc::new(EnumConstructor {
+                        name: self.name.clone(),
+                        variant: name.lexeme.clone(),
+                        arity: *arity,
+                    }),
+                },
+            ))))),
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!(
+                    "enum '{}' undefined variant '{}'.",
+                    self.name.lexeme, name.lexeme
+                ),
+            ))),
+        }
+    }
+}
+
+impl UpCast<OEnum> for EnumDecl {
+    fn upcast(&self) -> OEnum {
+        OEnum::EnumDecl(Box::new(self.clone()))
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::EnumDecl(Box::new(self))
+    }
+}
+
+impl Display for EnumDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "enum({})", self.name.lexeme)
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct Enum {
+    pub name: Token,
+    pub variant: String,
+    pub values: Vec<Object>,
+}
+
+// structural: same enum, same variant and payload values.
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.lexeme == other.name.lexeme
+            && self.variant == other.variant
+            && self.values == other.values
+    }
+}
+
+impl Hash for Enum {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.lexeme.hash(state);
+        self.variant.hash(state);
+        self.values.hash(state);
+    }
+}
+
+impl DeepClone for Enum {
+    fn deep_clone(&self) -> Self {
+        Enum {
+            name: self.name.clone(),
+            variant: self.variant.clone(),
+            values: self.values.iter().map(|value| value.value_copy()).collect(),
+        }
+    }
+}
+
+impl UpCast<OEnum> for Enum {
+    fn upcast(&self) -> OEnum {
+        OEnum::Enum(Box::new(self.clone()))
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Enum(Box::new(self))
+    }
+}
+
+impl Enum {
+    pub fn new(name: Token, variant: String, values: Vec<Object>) -> Enum {
+        Enum {
+            name,
+            variant,
+            values,
+        }
+    }
+}
+
+impl Display for Enum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.values.is_empty() {
+            write!(f, "{}.{}", self.name.lexeme, self.variant)
+        } else {
+            write!(
+                f,
+                "{}.{}({})",
+                self.name.lexeme,
+                self.variant,
+                self.values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EnumConstructor {
+    name: Token,
+    variant: String,
+    arity: usize,
+}
+
+impl Callable for EnumConstructor {
+    fn call(
+        &self,
+        _interpreter: &Interpreter,
+        arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        Ok(Some(Object::new(
+            Enum::new(
+                self.name.clone(),
+                self.variant.clone(),
+                arguments.iter().map(|value| value.value_copy()).collect(),
+            )
+            .upcast_into(),
+        )))
+    }
+    fn signature(&self) -> Signature {
+        Signature::arity(self.arity)
+    }
+}
+
+impl Display for EnumConstructor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EnumConstructor({}.{})", self.name.lexeme, self.variant)
+    }
+}
@@ -0,0 +1,416 @@
+//! This file is generator rs
+//!
+//! - Generator
+//!     - fn* call result: body run on self frame stack, next() resume until yield.
+//!     - GeneratorMethod(native bound method: next)
+//!
+//!
+
+use std::{
+    cell::RefCell,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::joker::{
+    abort::{ControlFlowAbort, Error::ControlFlow},
+    ast::{DoWhileStmt, FnStmt, ForInStmt, ForStmt, IfStmt, Stmt, WhileStmt, YieldStmt},
+    callable::{Callable, Signature},
+    env::Env,
+    error::JokerError,
+    interpreter::{Elements, Interpreter, InterpreterError},
+    object::Object as OEnum,
+    token::Token,
+    types::{Object, Type},
+};
+
+use super::{literal_null, Caller, Function, NativeFunction, UpCast};
+
+// generator body node: statement without yield run by interpreter at once,
+// statement with yield split, its state live in frame between resume.
+#[derive(Debug)]
+enum Node {
+    Run(Stmt),
+    Yield(YieldStmt),
+    Block(Vec<Rc<Node>>),
+    If(IfStmt, Rc<Node>, Option<Rc<Node>>),
+    While(WhileStmt, Rc<Node>),
+    DoWhile(DoWhileStmt, Rc<Node>),
+    For(ForStmt, Rc<Node>),
+    ForIn(ForInStmt, Rc<Node>),
+}
+
+impl Node {
+    fn lower(stmt: &Stmt) -> Rc<Node> {
+        if !stmt.has_yield() {
+            return Rc::new(Node::Run(stmt.clone()));
+        }
+        Rc::new(match stmt {
+            Stmt::YieldStmt(yield_) => Node::Yield(yield_.clone()),
+            Stmt::BlockStmt(block) => Node::Block(block.stmts.iter().map(Node::lower).collect()),
+            Stmt::IfStmt(if_) => Node::If(
+                if_.clone(),
+                Node::lower(&if_.then_branch),
+                if_.else_branch.as_deref().map(Node::lower),
+            ),
+            Stmt::WhileStmt(while_) => Node::While(while_.clone(), Node::lower(&while_.body)),
+            Stmt::DoWhileStmt(do_while) => {
+                Node::DoWhile(do_while.clone(), Node::lower(&do_while.body))
+            }
+            Stmt::ForStmt(for_) => Node::For(for_.clone(), Node::lower(&for_.body)),
+            Stmt::ForInStmt(for_in) => Node::ForIn(for_in.clone(), Node::lower(&for_in.body)),
+            // yield inside other statement is rejected by resolver.
+            _ => Node::Run(stmt.clone()),
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Frame {
+    Block {
+        node: Rc<Node>,
+        index: usize,
+        env: Rc<RefCell<Env>>,
+    },
+    // while, loop and do while.
+    While {
+        node: Rc<Node>,
+        env: Rc<RefCell<Env>>,
+        first: bool,
+        label: Option<Token>,
+    },
+    For {
+        node: Rc<Node>,
+        env: Rc<RefCell<Env>>,
+        first: bool,
+        label: Option<Token>,
+    },
+    ForIn {
+        node: Rc<Node>,
+        env: Rc<RefCell<Env>>,
+        elements: Elements,
+        label: Option<Token>,
+    },
+}
+
+enum Step {
+    Next,
+    Push(Frame),
+    Pop,
+    Yield(Object),
+}
+
+impl Frame {
+    fn label(&self) -> Option<&Option<Token>> {
+        match self {
+            Frame::Block { .. } => None,
+            Frame::While { label, .. } | Frame::For { label, .. } | Frame::ForIn { label, .. } => {
+                Some(label)
+            }
+        }
+    }
+    fn step(&mut self, interpreter: &Interpreter) -> Result<Step, JokerError> {
+        match self {
+            Frame::Block { node, index, env } => match &**node {
+                Node::Block(nodes) if *index < nodes.len() => {
+                    *index += 1;
+                    Frame::run(interpreter, &nodes[*index - 1], env)
+                }
+                _ => Ok(Step::Pop),
+            },
+            Frame::While {
+                node, env, first, ..
+            } => {
+                let (condition, body) = match &**node {
+                    Node::While(stmt, body) => (&stmt.condition, body),
+                    Node::DoWhile(stmt, body) if !*first => (&stmt.condition, body),
+                    // do while body run first.
+                    Node::DoWhile(_, body) => {
+                        *first = false;
+                        return Frame::run(interpreter, body, env);
+                    }
+                    _ => return Ok(Step::Pop),
+                };
+                *first = false;
+                if Frame::is_true(interpreter, condition, env)? {
+                    Frame::run(interpreter, body, env)
+                } else {
+                    Ok(Step::Pop)
+                }
+            }
+            Frame::For {
+                node, env, first, ..
+            } => {
+                let Node::For(stmt, body) = &**node else {
+                    return Ok(Step::Pop);
+                };
+                // every iteration fresh copy, increment run in new iteration env.
+                if !*first {
+                    let fresh: Env = env.borrow().fresh();
+                    *env = Rc::new(RefCell::new(fresh));
+                    if let Some(increment) = &stmt.increment {
+                        interpreter.evaluate_with(increment, Rc::clone(env))?;
+                    }
+                }
+                *first = false;
+                if Frame::is_true(interpreter, &stmt.condition, env)? {
+                    Frame::run(interpreter, body, env)
+                } else {
+                    Ok(Step::Pop)
+                }
+            }
+            Frame::ForIn {
+                node,
+                env,
+                elements,
+                ..
+            } => {
+                let Node::ForIn(stmt, body) = &**node else {
+                    return Ok(Step::Pop);
+                };
+                match interpreter.next_element(&stmt.keyword, elements)? {
+                    Some(element) => {
+                        let loop_env: Env =
+                            interpreter.for_in_env(stmt, Rc::clone(env), element)?;
+                        Frame::run(interpreter, body, &Rc::new(RefCell::new(loop_env)))
+                    }
+                    None => Ok(Step::Pop),
+                }
+            }
+        }
+    }
+    fn is_true(
+        interpreter: &Interpreter,
+        condition: &crate::joker::ast::Expr,
+        env: &Rc<RefCell<Env>>,
+    ) -> Result<bool, JokerError> {
+        Ok(interpreter
+            .evaluate_with(condition, Rc::clone(env))?
+            .is_some_and(|value| interpreter.is_true(&value)))
+    }
+    // run node in env: simple statement at once, compound node push new frame.
+    fn run(
+        interpreter: &Interpreter,
+        node: &Rc<Node>,
+        env: &Rc<RefCell<Env>>,
+    ) -> Result<Step, JokerError> {
+        match &**node {
+            Node::Run(stmt) => {
+                interpreter.execute_with(stmt, Rc::clone(env))?;
+                Ok(Step::Next)
+            }
+            Node::Yield(stmt) => Ok(Step::Yield(
+                interpreter
+                    .evaluate_with(&stmt.value, Rc::clone(env))?
+                    .unwrap_or_else(|| Object::new(literal_null())),
+            )),
+            Node::Block(_) => Ok(Step::Push(Frame::Block {
+                node: Rc::clone(node),
+                index: 0,
+                env: Rc::new(RefCell::new(Env::new_with_enclosing(Rc::clone(env)))),
+            })),
+            Node::If(stmt, then_branch, else_branch) => {
+                if Frame::is_true(interpreter, &stmt.condition, env)? {
+                    Frame::run(interpreter, then_branch, env)
+                } else if let Some(else_branch) = else_branch {
+                    Frame::run(interpreter, else_branch, env)
+                } else {
+                    Ok(Step::Next)
+                }
+            }
+            Node::While(WhileStmt { label, .. }, _)
+            | Node::DoWhile(DoWhileStmt { label, .. }, _) => Ok(Step::Push(Frame::While {
+                node: Rc::clone(node),
+                env: Rc::clone(env),
+                first: true,
+                label: label.clone(),
+            })),
+            Node::For(stmt, _) => {
+                // loop variable live in for scope.
+                let loop_env: Rc<RefCell<Env>> =
+                    Rc::new(RefCell::new(Env::new_with_enclosing(Rc::clone(env))));
+                if let Some(initializer) = &stmt.initializer {
+                    interpreter.execute_with(initializer, Rc::clone(&loop_env))?;
+                }
+                Ok(Step::Push(Frame::For {
+                    node: Rc::clone(node),
+                    env: loop_env,
+                    first: true,
+                    label: stmt.label.clone(),
+                }))
+            }
+            Node::ForIn(stmt, _) => {
+                let iterable: Object =
+                    match interpreter.evaluate_with(&stmt.iterable, Rc::clone(env))? {
+                        Some(iterable) => iterable,
+                        None => {
+                            return Err(JokerError::Interpreter(InterpreterError::new(
+                                &stmt.keyword,
+                                String::from("for in invalid iterable value."),
+                            )))
+                        }
+                    };
+                Ok(Step::Push(Frame::ForIn {
+                    node: Rc::clone(node),
+                    env: Rc::clone(env),
+                    elements: interpreter.elements(&stmt.keyword, &iterable)?,
+                    label: stmt.label.clone(),
+                }))
+            }
+        }
+    }
+}
+
+// generator share frame stack, copy resume same body.
+#[derive(Clone)]
+pub struct Generator {
+    pub stmt: Rc<FnStmt>,
+    frames: Rc<RefCell<Vec<Frame>>>,
+}
+
+impl UpCast<OEnum> for Generator {
+    fn upcast(&self) -> OEnum {
+        OEnum::Generator(self.clone())
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Generator(self)
+    }
+}
+
+impl PartialEq for Generator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.frames, &other.frames)
+    }
+}
+
+impl Eq for Generator {}
+
+impl Hash for Generator {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.frames).hash(state)
+    }
+}
+
+impl Generator {
+    // body run in fn env with bound parameters, first next() start it.
+    pub fn new(stmt: Rc<FnStmt>, env: Env) -> Generator {
+        let body: Rc<Node> = Rc::new(Node::Block(stmt.body.iter().map(Node::lower).collect()));
+        Generator {
+            stmt,
+            frames: Rc::new(RefCell::new(vec![Frame::Block {
+                node: body,
+                index: 0,
+                env: Rc::new(RefCell::new(env)),
+            }])),
+        }
+    }
+    pub fn type_(&self) -> Type {
+        match &self.stmt.return_type {
+            Some(return_type) => *return_type.clone(),
+            None => Type::Generator(Box::new(Type::Null)),
+        }
+    }
+    // run until next yield value, none is finish: body end, return or error.
+    pub fn resume(
+        &self,
+        interpreter: &Interpreter,
+        keyword: &Token,
+    ) -> Result<Option<Object>, JokerError> {
+        let mut frames = self.frames.try_borrow_mut().map_err(|_| {
+            JokerError::Interpreter(InterpreterError::new(
+                keyword,
+                format!("generator '{}' is already running.", self.stmt.name.lexeme),
+            ))
+        })?;
+        loop {
+            let step: Result<Step, JokerError> = match frames.last_mut() {
+                Some(frame) => frame.step(interpreter),
+                None => return Ok(None),
+            };
+            match step {
+                Ok(Step::Next) => {}
+                Ok(Step::Push(frame)) => frames.push(frame),
+                Ok(Step::Pop) => {
+                    frames.pop();
+                }
+                Ok(Step::Yield(value)) => return Ok(Some(value)),
+                Err(err) => Generator::unwind(&mut frames, err)?,
+            }
+        }
+    }
+    // break and continue stop at own loop frame, return finish generator, other error propagate.
+    fn unwind(frames: &mut Vec<Frame>, mut err: JokerError) -> Result<(), JokerError> {
+        while let Some(frame) = frames.pop() {
+            if let Some(label) = frame.label() {
+                match Interpreter::is_loop_break(err, label) {
+                    Ok(true) => return Ok(()),
+                    Ok(false) => {
+                        frames.push(frame);
+                        return Ok(());
+                    }
+                    Err(other) => err = other,
+                }
+            }
+        }
+        match err {
+            JokerError::Abort(ControlFlow(ControlFlowAbort::Return(_))) => Ok(()),
+            err => Err(err),
+        }
+    }
+    // generator bound native method: generator.next()
+    pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
+        match name.lexeme.as_str() {
+            "next" => Ok(Some(Object::new(OEnum::Caller(Caller::Func(
+                Function::Native(NativeFunction {
+                    fun: Rc::new(GeneratorMethod {
+                        generator: self.clone(),
+                        name: name.clone(),
+                    }),
+                }),
+            ))))),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Display for Generator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Gen({})", self.stmt.name.lexeme)
+    }
+}
+
+impl Debug for Generator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Gen(<frames>)")
+    }
+}
+
+#[derive(Debug)]
+pub struct GeneratorMethod {
+    generator: Generator,
+    name: Token,
+}
+
+impl Callable for GeneratorMethod {
+    fn call(
+        &self,
+        interpreter: &Interpreter,
+        _arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        Ok(Some(
+            self.generator
+                .resume(interpreter, &self.name)?
+                .unwrap_or_else(|| Object::new(literal_null())),
+        ))
+    }
+    fn signature(&self) -> Signature {
+        Signature::arity(0)
+    }
+}
+
+impl Display for GeneratorMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GeneratorMethod({})", self.name.lexeme)
+    }
+}
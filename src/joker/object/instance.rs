@@ -22,7 +22,7 @@ use crate::joker::{
     types::{DeepClone, Object},
 };
 
-use super::{Caller, Class, Function, UpCast};
+use super::{Binder, BinderFunction, Caller, Class, Function, UpCast};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Instance {
@@ -74,8 +74,15 @@ impl Instance {
             methods: Rc::new(RefCell::new(HashMap::new())),
         }
     }
-    // find link: instance fields -> instance methods -> class fields -> class methods -> class functions
-    // -> super fields -> super methods -> super functions
+    // class method bind this instance, class function not have this.
+    fn bind(&self, function: BinderFunction) -> OEnum {
+        match function {
+            BinderFunction::Method(method) => method.bind(self.clone()).upcast_into(),
+            BinderFunction::User(user) => user.upcast_into(),
+        }
+    }
+    // find link: instance fields -> instance methods -> class statics -> class fields -> class methods
+    // -> class functions -> super fields -> super methods -> super functions
     pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
         // instance fields -> instance methods
         if let Some(instance_value) = self.fields.borrow().get(&name.lexeme) {
@@ -84,12 +91,16 @@ impl Instance {
         if let Some(instance_md) = self.methods.borrow().get(&name.lexeme) {
             return Ok(Some(Object::new(instance_md.clone().upcast_into())));
         }
+        // class statics(shared with class and sub class)
+        if let Some(static_value) = self.class.borrow().get_static(&name.lexeme) {
+            return Ok(Some(static_value));
+        }
         // class fields -> class methods -> class functions
         if let Some(class_value_status) = self.class.borrow().get_field(&name.lexeme) {
             if let Some(class_value) = class_value_status {
                 return Ok(Some(class_value.clone()));
             } else {
-                return Err(JokerError::Interpreter(InterpreterError::report_error(
+                return Err(JokerError::Interpreter(InterpreterError::new(
                     name,
                     format!(
                         "class attribute '{}' is declared, but not define.",
@@ -99,7 +110,7 @@ impl Instance {
             }
         }
         if let Some(class_method) = self.class.borrow().get_method(&name.lexeme) {
-            return Ok(Some(Object::new(class_method.upcast_into())));
+            return Ok(Some(Object::new(self.bind(class_method))));
         }
         // super fields -> super methods -> super functions
         if let Some(super_class) = self.class.borrow().super_class.as_ref() {
@@ -107,7 +118,7 @@ impl Instance {
                 if let Some(super_value) = super_value_status {
                     return Ok(Some(super_value.clone()));
                 } else {
-                    return Err(JokerError::Interpreter(InterpreterError::report_error(
+                    return Err(JokerError::Interpreter(InterpreterError::new(
                         name,
                         format!(
                             "super class attribute '{}' is declared, but not define.",
@@ -117,51 +128,43 @@ impl Instance {
                 }
             }
             if let Some(super_method) = super_class.get_method(&name.lexeme) {
-                return Ok(Some(Object::new(super_method.upcast_into())));
+                return Ok(Some(Object::new(self.bind(super_method))));
             }
         }
 
         Ok(None)
     }
-    // first find name: getter, if have modify else insert.
+    // first fill class declared but not define field, then find name: getter, if have modify else insert.
     pub fn setter(&mut self, name: &Token, value: Object) -> Result<(), JokerError> {
-        if let Ok(defined_value) = self.getter(name) {
-            match defined_value {
-                Some(defined_value) => {
-                    let new_value: OEnum = value.get().clone();
-                    defined_value.set(new_value);
-                }
-                None => {
-                    if value.is_fn() {
-                        if let OEnum::Caller(Caller::Func(func)) = value.into_inner() {
-                            self.methods.borrow_mut().insert(name.lexeme.clone(), func);
-                        }
-                    } else {
-                        self.fields.borrow_mut().insert(name.lexeme.clone(), value);
-                    }
+        // class declared but not define.
+        if let Some(class_fields) = self.class.borrow_mut().fields.as_mut() {
+            if let Some(class_field) = class_fields.get_mut(&name.lexeme) {
+                if class_field.is_none() {
+                    *class_field = Some(value);
+                    return Ok(());
                 }
             }
-        } else {
-            // class declared but not define.
-            if let Some(class_fields) = self.class.borrow_mut().fields.as_mut() {
-                if let Some(class_field) = class_fields.get_mut(&name.lexeme) {
-                    if class_field.is_none() {
-                        *class_field = Some(value);
+        }
+        // super class declared but not define.
+        if let Some(super_class) = self.class.borrow_mut().super_class.as_mut() {
+            if let Some(super_fields) = super_class.fields.as_mut() {
+                if let Some(super_field) = super_fields.get_mut(&name.lexeme) {
+                    if super_field.is_none() {
+                        *super_field = Some(value);
                         return Ok(());
                     }
                 }
             }
-            // super class declared but not define.
-            if let Some(super_class) = self.class.borrow_mut().super_class.as_mut() {
-                if let Some(super_fields) = super_class.fields.as_mut() {
-                    if let Some(super_field) = super_fields.get_mut(&name.lexeme) {
-                        if super_field.is_none() {
-                            *super_field = Some(value);
-                            return Ok(());
-                        }
-                    }
-                }
+        }
+        if let Some(defined_value) = self.getter(name)? {
+            let new_value: OEnum = value.get().clone();
+            defined_value.set(new_value);
+        } else if value.is_fn() {
+            if let OEnum::Caller(Caller::Func(func)) = value.into_inner() {
+                self.methods.borrow_mut().insert(name.lexeme.clone(), func);
             }
+        } else {
+            self.fields.borrow_mut().insert(name.lexeme.clone(), value);
         }
         Ok(())
     }
@@ -198,6 +201,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )));
         println!("instance: {:#?}", instance);
         let mut clone_instance = instance.clone();
@@ -0,0 +1,220 @@
+//! This file is list rs
+//!
+//! - List
+//!     - ListMethod(native bound method: len, push, pop)
+//!
+//!
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::joker::{
+    callable::{Callable, Signature},
+    error::JokerError,
+    interpreter::{Interpreter, InterpreterError},
+    object::Object as OEnum,
+    token::Token,
+    types::{DeepClone, Object},
+};
+
+use super::{literal_i32, Caller, Function, NativeFunction, UpCast};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List {
+    pub elements: Rc<RefCell<Vec<Object>>>,
+}
+
+// var binding copy whole list: nested list, map and instance element copy too.
+impl DeepClone for List {
+    fn deep_clone(&self) -> Self {
+        List {
+            elements: Rc::new(RefCell::new(
+                self.elements
+                    .borrow()
+                    .iter()
+                    .map(DeepClone::deep_clone)
+                    .collect(),
+            )),
+        }
+    }
+}
+
+impl UpCast<OEnum> for List {
+    fn upcast(&self) -> OEnum {
+        OEnum::List(self.clone())
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::List(self)
+    }
+}
+
+impl Hash for List {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.elements).hash(state)
+    }
+}
+
+impl List {
+    pub fn new(elements: Vec<Object>) -> List {
+        List {
+            elements: Rc::new(RefCell::new(elements)),
+        }
+    }
+    fn len(&self) -> usize {
+        self.elements.borrow().len()
+    }
+    fn position(&self, bracket: &Token, index: &Object) -> Result<usize, JokerError> {
+        match &*index.get() {
+            OEnum::Literal(super::Literal::I32(i32_)) => {
+                if *i32_ >= 0 && (*i32_ as usize) < self.len() {
+                    Ok(*i32_ as usize)
+                } else {
+                    Err(JokerError::Interpreter(InterpreterError::new(
+                        bracket,
+                        format!(
+                            "list index out of range: the len is {} but the index is {}.",
+                            self.len(),
+                            i32_
+                        ),
+                    )))
+                }
+            }
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                bracket,
+                format!("list index need i32, but this is '{}'.", other),
+            ))),
+        }
+    }
+    pub fn get(&self, bracket: &Token, index: &Object) -> Result<Object, JokerError> {
+        let pos: usize = self.position(bracket, index)?;
+        Ok(self.elements.borrow()[pos].clone())
+    }
+    pub fn set(&self, bracket: &Token, index: &Object, value: Object) -> Result<(), JokerError> {
+        let pos: usize = self.position(bracket, index)?;
+        self.elements.borrow_mut()[pos] = value;
+        Ok(())
+    }
+    // list bound native method: list.len(), list.push(value), list.pop()
+    pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
+        match name.lexeme.as_str() {
+            "len" | "push" | "pop" => Ok(Some(Object::new(OEnum::Caller(Caller::Func(
+                Function::Native(NativeFunction {
+                    fun: Rc::new(ListMethod {
+                        list: self.clone(),
+                        name: name.clone(),
+                    }),
+                }),
+            ))))),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Display for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}]",
+            self.elements
+                .borrow()
+                .iter()
+                .map(|element| element.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ListMethod {
+    list: List,
+    name: Token,
+}
+
+impl Callable for ListMethod {
+    fn call(
+        &self,
+        _interpreter: &Interpreter,
+        arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        match self.name.lexeme.as_str() {
+            "len" => Ok(Some(Object::new(literal_i32(self.list.len() as i32)))),
+            "push" => {
+                self.list.elements.borrow_mut().push(arguments[0].clone());
+                Ok(None)
+            }
+            "pop" => match self.list.elements.borrow_mut().pop() {
+                Some(value) => Ok(Some(value)),
+                None => Err(JokerError::Interpreter(InterpreterError::new(
+                    &self.name,
+                    String::from("pop from empty list."),
+                ))),
+            },
+            _ => unreachable!("[ListMethod::call] list not have method '{}'.", self.name),
+        }
+    }
+    fn signature(&self) -> Signature {
+        Signature::arity(match self.name.lexeme.as_str() {
+            "push" => 1,
+            _ => 0,
+        })
+    }
+}
+
+impl Display for ListMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ListMethod({})", self.name.lexeme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::joker::{object::literal_null, token::TokenType};
+
+    use super::*;
+
+    #[test]
+    fn test_list_deep_clone_not_share_elements() {
+        let list = List::new(vec![
+            Object::new(literal_i32(1)),
+            Object::new(literal_i32(2)),
+        ]);
+        let bracket = Token::new(TokenType::LeftBracket, String::from("["), literal_null(), 0);
+        let clone_list = list.deep_clone();
+        clone_list
+            .set(
+                &bracket,
+                &Object::new(literal_i32(0)),
+                Object::new(literal_i32(9)),
+            )
+            .unwrap();
+        assert_eq!(list.to_string(), "[1, 2]");
+        assert_eq!(clone_list.to_string(), "[9, 2]");
+    }
+
+    #[test]
+    fn test_list_deep_clone_not_share_nested_elements() {
+        let inner = List::new(vec![Object::new(literal_i32(1))]);
+        let list = List::new(vec![Object::new(OEnum::List(inner))]);
+        let bracket = Token::new(TokenType::LeftBracket, String::from("["), literal_null(), 0);
+        let clone_list = list.deep_clone();
+        let clone_inner = clone_list
+            .get(&bracket, &Object::new(literal_i32(0)))
+            .unwrap();
+        if let OEnum::List(clone_inner) = &*clone_inner.get() {
+            clone_inner
+                .set(
+                    &bracket,
+                    &Object::new(literal_i32(0)),
+                    Object::new(literal_i32(9)),
+                )
+                .unwrap();
+        }
+        assert_eq!(list.to_string(), "[[1]]");
+        assert_eq!(clone_list.to_string(), "[[9]]");
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! - Literal
 //!     - I32
+//!     - I64
+//!     - U8
 //!     - F64
 //!     - Str
 //!     - Bool
 //!     - Null
+//!     - LiteralMethod(native bound number conversion: to_i32, to_i64, to_u8, to_f64)
 //!
 //!
 //!
@@ -13,31 +16,136 @@
 use std::{
     fmt::{Debug, Display},
     hash::{Hash, Hasher},
+    rc::Rc,
 };
 
-use super::{Object, UpCast};
+use crate::joker::{
+    callable::{Callable, Signature},
+    error::JokerError,
+    interpreter::{Interpreter, InterpreterError},
+    token::Token,
+    types::Object,
+};
+
+use super::{Caller, Function, NativeFunction, Object as OEnum, UpCast};
 
 #[derive(Debug, Clone)]
 pub enum Literal {
     I32(i32),
+    I64(i64),
+    U8(u8),
     F64(f64),
     Str(String),
     Bool(bool),
     Null,
 }
 
-impl UpCast<Object> for Literal {
-    fn upcast(&self) -> Object {
+impl UpCast<OEnum> for Literal {
+    fn upcast(&self) -> OEnum {
+        match self {
+            Literal::I32(value) => OEnum::Literal(Literal::I32(*value)),
+            Literal::I64(value) => OEnum::Literal(Literal::I64(*value)),
+            Literal::U8(value) => OEnum::Literal(Literal::U8(*value)),
+            Literal::F64(value) => OEnum::Literal(Literal::F64(*value)),
+            Literal::Str(string) => OEnum::Literal(Literal::Str(string.clone())),
+            Literal::Bool(bool_) => OEnum::Literal(Literal::Bool(*bool_)),
+            Literal::Null => OEnum::Literal(Literal::Null),
+        }
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Literal(self)
+    }
+}
+
+impl Literal {
+    pub fn is_number(&self) -> bool {
+        matches!(
+            self,
+            Literal::I32(_) | Literal::I64(_) | Literal::U8(_) | Literal::F64(_)
+        )
+    }
+    // integer value of number, f64 truncate toward zero, nan and infinity is None.
+    fn integer(&self) -> Option<i128> {
         match self {
-            Literal::I32(value) => Object::Literal(Literal::I32(*value)),
-            Literal::F64(value) => Object::Literal(Literal::F64(*value)),
-            Literal::Str(string) => Object::Literal(Literal::Str(string.clone())),
-            Literal::Bool(bool_) => Object::Literal(Literal::Bool(*bool_)),
-            Literal::Null => Object::Literal(Literal::Null),
+            Literal::I32(i32_) => Some(*i32_ as i128),
+            Literal::I64(i64_) => Some(*i64_ as i128),
+            Literal::U8(u8_) => Some(*u8_ as i128),
+            Literal::F64(f64_) if f64_.is_finite() => Some(f64_.trunc() as i128),
+            _ => None,
         }
     }
-    fn upcast_into(self) -> Object {
-        Object::Literal(self)
+    // explicit number conversion, None is out of target range.
+    pub fn convert(&self, method: &str) -> Option<Literal> {
+        match method {
+            "to_i32" => self
+                .integer()
+                .and_then(|value| i32::try_from(value).ok())
+                .map(Literal::I32),
+            "to_i64" => self
+                .integer()
+                .and_then(|value| i64::try_from(value).ok())
+                .map(Literal::I64),
+            "to_u8" => self
+                .integer()
+                .and_then(|value| u8::try_from(value).ok())
+                .map(Literal::U8),
+            "to_f64" => match self {
+                Literal::I32(i32_) => Some(Literal::F64(*i32_ as f64)),
+                Literal::I64(i64_) => Some(Literal::F64(*i64_ as f64)),
+                Literal::U8(u8_) => Some(Literal::F64(*u8_ as f64)),
+                Literal::F64(f64_) => Some(Literal::F64(*f64_)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+    // number bound native method: 1.to_i64(), 300.to_u8(), 2i64.to_f64()
+    pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
+        match name.lexeme.as_str() {
+            "to_i32" | "to_i64" | "to_u8" | "to_f64" if self.is_number() => Ok(Some(Object::new(
+                OEnum::Caller(Caller::Func(Function::Native(NativeFunction {
+                    fun: Rc::new(LiteralMethod {
+                        literal: self.clone(),
+                        name: name.clone(),
+                    }),
+                }))),
+            ))),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LiteralMethod {
+    literal: Literal,
+    name: Token,
+}
+
+impl Callable for LiteralMethod {
+    fn call(
+        &self,
+        _interpreter: &Interpreter,
+        _arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        match self.literal.convert(&self.name.lexeme) {
+            Some(literal) => Ok(Some(Object::new(OEnum::Literal(literal)))),
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                &self.name,
+                format!(
+                    "[[Convert::OverflowError]] number out of range. !({}.{}())",
+                    self.literal, self.name.lexeme
+                ),
+            ))),
+        }
+    }
+    fn signature(&self) -> Signature {
+        Signature::arity(0)
+    }
+}
+
+impl Display for LiteralMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LiteralMethod({})", self.name.lexeme)
     }
 }
 
@@ -45,6 +153,8 @@ impl PartialEq for Literal {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Literal::I32(a), Literal::I32(b)) => a == b,
+            (Literal::I64(a), Literal::I64(b)) => a == b,
+            (Literal::U8(a), Literal::U8(b)) => a == b,
             (Literal::F64(a), Literal::F64(b)) => a.eq(b) || (a.is_nan() && b.is_nan()), // 特殊处理 NaN
             (Literal::Str(a), Literal::Str(b)) => a == b,
             (Literal::Bool(a), Literal::Bool(b)) => a == b,
@@ -60,6 +170,8 @@ impl Hash for Literal {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             Literal::I32(val) => val.hash(state),
+            Literal::I64(val) => val.hash(state),
+            Literal::U8(val) => val.hash(state),
             Literal::F64(val) => {
                 // 对于 f64，使用 to_bits 来确保 NaN 有相同的哈希码
                 // 并且正无穷和负无穷有不同的哈希码
@@ -76,6 +188,8 @@ impl Display for Literal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Literal::I32(i32_) => write!(f, "{i32_}"),
+            Literal::I64(i64_) => write!(f, "{i64_}"),
+            Literal::U8(u8_) => write!(f, "{u8_}"),
             Literal::F64(f64_) => write!(f, "{f64_}"),
             Literal::Str(str_) => write!(f, "\"{str_}\""),
             Literal::Bool(bool_) => write!(f, "{bool_}"),
@@ -84,24 +198,32 @@ impl Display for Literal {
     }
 }
 
-pub fn literal_str(text: String) -> Object {
-    Object::Literal(Literal::Str(text))
+pub fn literal_str(text: String) -> OEnum {
+    OEnum::Literal(Literal::Str(text))
+}
+
+pub fn literal_f64(f64_: f64) -> OEnum {
+    OEnum::Literal(Literal::F64(f64_))
+}
+
+pub fn literal_i32(i32_: i32) -> OEnum {
+    OEnum::Literal(Literal::I32(i32_))
 }
 
-pub fn literal_f64(f64_: f64) -> Object {
-    Object::Literal(Literal::F64(f64_))
+pub fn literal_i64(i64_: i64) -> OEnum {
+    OEnum::Literal(Literal::I64(i64_))
 }
 
-pub fn literal_i32(i32_: i32) -> Object {
-    Object::Literal(Literal::I32(i32_))
+pub fn literal_u8(u8_: u8) -> OEnum {
+    OEnum::Literal(Literal::U8(u8_))
 }
 
-pub fn literal_bool(bool_: bool) -> Object {
-    Object::Literal(Literal::Bool(bool_))
+pub fn literal_bool(bool_: bool) -> OEnum {
+    OEnum::Literal(Literal::Bool(bool_))
 }
 
-pub fn literal_null() -> Object {
-    Object::Literal(Literal::Null)
+pub fn literal_null() -> OEnum {
+    OEnum::Literal(Literal::Null)
 }
 
 #[cfg(test)]
@@ -122,22 +244,22 @@ mod tests {
         let lr_bool = &l_bool;
         let lr_null = &l_null;
         // copy clone
-        assert_eq!(Object::Literal(Literal::I32(100)), lr_i32.upcast());
-        assert_eq!(Object::Literal(Literal::F64(10.0)), lr_f64.upcast());
+        assert_eq!(OEnum::Literal(Literal::I32(100)), lr_i32.upcast());
+        assert_eq!(OEnum::Literal(Literal::F64(10.0)), lr_f64.upcast());
         assert_eq!(
-            Object::Literal(Literal::Str(String::from("literal_string"))),
+            OEnum::Literal(Literal::Str(String::from("literal_string"))),
             lr_string.upcast()
         );
-        assert_eq!(Object::Literal(Literal::Bool(true)), lr_bool.upcast());
-        assert_eq!(Object::Literal(Literal::Null), lr_null.upcast());
+        assert_eq!(OEnum::Literal(Literal::Bool(true)), lr_bool.upcast());
+        assert_eq!(OEnum::Literal(Literal::Null), lr_null.upcast());
         // move
-        assert_eq!(Object::Literal(Literal::I32(100)), l_i32.upcast_into());
-        assert_eq!(Object::Literal(Literal::F64(10.0)), l_f64.upcast_into());
+        assert_eq!(OEnum::Literal(Literal::I32(100)), l_i32.upcast_into());
+        assert_eq!(OEnum::Literal(Literal::F64(10.0)), l_f64.upcast_into());
         assert_eq!(
-            Object::Literal(Literal::Str(String::from("literal_string"))),
+            OEnum::Literal(Literal::Str(String::from("literal_string"))),
             l_string.upcast_into()
         );
-        assert_eq!(Object::Literal(Literal::Bool(true)), l_bool.upcast_into());
-        assert_eq!(Object::Literal(Literal::Null), l_null.upcast_into());
+        assert_eq!(OEnum::Literal(Literal::Bool(true)), l_bool.upcast_into());
+        assert_eq!(OEnum::Literal(Literal::Null), l_null.upcast_into());
     }
 }
@@ -0,0 +1,261 @@
+//! This file is map rs
+//!
+//! - Map
+//!     - MapMethod(native bound method: get, set, delete, contains, keys, values, len)
+//!
+//!
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::joker::{
+    callable::{Callable, Signature},
+    error::JokerError,
+    interpreter::{Interpreter, InterpreterError},
+    object::Object as OEnum,
+    token::Token,
+    types::{DeepClone, Object},
+};
+
+use super::{literal_bool, literal_i32, Caller, Function, List, Literal, NativeFunction, UpCast};
+
+// table: key -> value, order: keys insertion order(print, keys(), values()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    pub table: Rc<RefCell<HashMap<Literal, Object>>>,
+    pub order: Rc<RefCell<Vec<Literal>>>,
+}
+
+// var binding copy whole map: nested list, map and instance value copy too.
+impl DeepClone for Map {
+    fn deep_clone(&self) -> Self {
+        Map {
+            table: Rc::new(RefCell::new(
+                self.table
+                    .borrow()
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.deep_clone()))
+                    .collect(),
+            )),
+            order: Rc::new(RefCell::new((*self.order.borrow()).clone())),
+        }
+    }
+}
+
+impl UpCast<OEnum> for Map {
+    fn upcast(&self) -> OEnum {
+        OEnum::Map(self.clone())
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Map(self)
+    }
+}
+
+impl Hash for Map {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Rc::as_ptr(&self.table), Rc::as_ptr(&self.order)).hash(state)
+    }
+}
+
+impl Map {
+    pub fn new() -> Map {
+        Map {
+            table: Rc::new(RefCell::new(HashMap::new())),
+            order: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+    fn len(&self) -> usize {
+        self.order.borrow().len()
+    }
+    // only literal object can as map key.
+    fn key(token: &Token, key: &Object) -> Result<Literal, JokerError> {
+        match &*key.get() {
+            OEnum::Literal(literal) => Ok(literal.clone()),
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                token,
+                format!("map key need literal, but this is '{}'.", other),
+            ))),
+        }
+    }
+    pub fn get(&self, token: &Token, key: &Object) -> Result<Option<Object>, JokerError> {
+        let key: Literal = Map::key(token, key)?;
+        Ok(self.table.borrow().get(&key).cloned())
+    }
+    pub fn set(&self, token: &Token, key: &Object, value: Object) -> Result<(), JokerError> {
+        let key: Literal = Map::key(token, key)?;
+        if self.table.borrow_mut().insert(key.clone(), value).is_none() {
+            self.order.borrow_mut().push(key);
+        }
+        Ok(())
+    }
+    pub fn delete(&self, token: &Token, key: &Object) -> Result<bool, JokerError> {
+        let key: Literal = Map::key(token, key)?;
+        if self.table.borrow_mut().remove(&key).is_some() {
+            self.order.borrow_mut().retain(|k| k != &key);
+            return Ok(true);
+        }
+        Ok(false)
+    }
+    pub fn keys(&self) -> Vec<Object> {
+        self.order
+            .borrow()
+            .iter()
+            .map(|key| Object::new(OEnum::Literal(key.clone())))
+            .collect()
+    }
+    pub fn values(&self) -> Vec<Object> {
+        let table = self.table.borrow();
+        self.order
+            .borrow()
+            .iter()
+            .map(|key| table[key].clone())
+            .collect()
+    }
+    // map bound native method: map.get(key, default), map.set(key, value), map.delete(key),
+    // map.contains(key), map.keys(), map.values(), map.len()
+    pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
+        match name.lexeme.as_str() {
+            "get" | "set" | "delete" | "contains" | "keys" | "values" | "len" => {
+                Ok(Some(Object::new(OEnum::Caller(Caller::Func(
+                    Function::Native(NativeFunction {
+                        fun: Rc::new(MapMethod {
+                            map: self.clone(),
+                            name: name.clone(),
+                        }),
+                    }),
+                )))))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Map::new()
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let table = self.table.borrow();
+        write!(
+            f,
+            "{{{}}}",
+            self.order
+                .borrow()
+                .iter()
+                .map(|key| format!("{}: {}", key, table[key]))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct MapMethod {
+    map: Map,
+    name: Token,
+}
+
+impl Callable for MapMethod {
+    fn call(
+        &self,
+        _interpreter: &Interpreter,
+        arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        match self.name.lexeme.as_str() {
+            "get" => match self.map.get(&self.name, &arguments[0])? {
+                Some(value) => Ok(Some(value)),
+                None => Ok(Some(arguments[1].clone())),
+            },
+            "set" => {
+                self.map
+                    .set(&self.name, &arguments[0], arguments[1].clone())?;
+                Ok(None)
+            }
+            "delete" => Ok(Some(Object::new(literal_bool(
+                self.map.delete(&self.name, &arguments[0])?,
+            )))),
+            "contains" => Ok(Some(Object::new(literal_bool(
+                self.map.get(&self.name, &arguments[0])?.is_some(),
+            )))),
+            "keys" => Ok(Some(Object::new(OEnum::List(List::new(self.map.keys()))))),
+            "values" => Ok(Some(Object::new(OEnum::List(List::new(self.map.values()))))),
+            "len" => Ok(Some(Object::new(literal_i32(self.map.len() as i32)))),
+            _ => unreachable!("[MapMethod::call] map not have method '{}'.", self.name),
+        }
+    }
+    fn signature(&self) -> Signature {
+        Signature::arity(match self.name.lexeme.as_str() {
+            "get" | "set" => 2,
+            "delete" | "contains" => 1,
+            _ => 0,
+        })
+    }
+}
+
+impl Display for MapMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MapMethod({})", self.name.lexeme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::joker::{
+        object::{literal_null, literal_str},
+        token::TokenType,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_map_keep_insertion_order() -> Result<(), JokerError> {
+        let map = Map::new();
+        let token = Token::new(TokenType::LeftBrace, String::from("{"), literal_null(), 0);
+        map.set(
+            &token,
+            &Object::new(literal_str(String::from("b"))),
+            Object::new(literal_i32(2)),
+        )?;
+        map.set(
+            &token,
+            &Object::new(literal_str(String::from("a"))),
+            Object::new(literal_i32(1)),
+        )?;
+        map.set(
+            &token,
+            &Object::new(literal_str(String::from("b"))),
+            Object::new(literal_i32(3)),
+        )?;
+        assert_eq!(map.to_string(), "{\"b\": 3, \"a\": 1}");
+        assert!(map.delete(&token, &Object::new(literal_str(String::from("b"))))?);
+        assert_eq!(map.to_string(), "{\"a\": 1}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_deep_clone_not_share_nested_values() -> Result<(), JokerError> {
+        let token = Token::new(TokenType::LeftBrace, String::from("{"), literal_null(), 0);
+        let key = Object::new(literal_str(String::from("a")));
+        let inner = Map::new();
+        inner.set(&token, &key, Object::new(literal_i32(1)))?;
+        let map = Map::new();
+        map.set(&token, &key, Object::new(OEnum::Map(inner)))?;
+        let clone_map = map.deep_clone();
+        if let Some(clone_inner) = clone_map.get(&token, &key)? {
+            if let OEnum::Map(clone_inner) = &*clone_inner.get() {
+                clone_inner.set(&token, &key, Object::new(literal_i32(9)))?;
+            }
+        }
+        assert_eq!(map.to_string(), "{\"a\": {\"a\": 1}}");
+        assert_eq!(clone_map.to_string(), "{\"a\": {\"a\": 9}}");
+        Ok(())
+    }
+}
@@ -4,15 +4,36 @@
 //!     - Literal
 //!     - Caller
 //!     - Instance
+//!     - List
+//!     - Map
+//!     - Tuple
+//!     - Range
+//!     - Generator
 //!
 //!
 
 mod caller;
+mod enum_;
+mod generator;
 mod instance;
+mod list;
 mod literal;
+mod map;
+mod module;
+mod range;
+mod struct_;
 mod this;
+mod tuple;
 
 pub use caller::*;
+pub use enum_::*;
+pub use generator::*;
 pub use instance::*;
+pub use list::*;
 pub use literal::*;
+pub use map::*;
+pub use module::*;
+pub use range::*;
+pub use struct_::*;
 pub use this::*;
+pub use tuple::*;
@@ -0,0 +1,58 @@
+//! This file is module rs
+//!
+//! - Module
+//!     - imported module namespace: module path and exported values.
+//!
+//!
+
+use std::fmt::Display;
+
+use crate::joker::{
+    error::JokerError, interpreter::InterpreterError, object::Object as OEnum, token::Token,
+    types::Object,
+};
+
+use super::UpCast;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Module {
+    pub name: Token,
+    pub exports: Vec<(String, Object)>,
+}
+
+impl Module {
+    pub fn new(name: Token, exports: Vec<(String, Object)>) -> Module {
+        Module { name, exports }
+    }
+    pub fn getter(&self, name: &Token) -> Result<Object, JokerError> {
+        match self
+            .exports
+            .iter()
+            .find(|(export, _)| export.eq(&name.lexeme))
+        {
+            Some((_, value)) => Ok(value.clone()),
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!(
+                    "module '{}' not export '{}'.",
+                    self.name.lexeme, name.lexeme
+                ),
+            ))),
+        }
+    }
+}
+
+impl UpCast<OEnum> for Module {
+    fn upcast(&self) -> OEnum {
+        OEnum::Module(Box::new(self.clone()))
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Module(Box::new(self))
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "module({})", self.name.lexeme)
+    }
+}
@@ -0,0 +1,197 @@
+//! This file is range rs
+//!
+//! - Range
+//!     - i32 range value: a..b, a..=b, (a..b).step(n)
+//!     - RangeMethod(native bound method: step, contains, len)
+//!
+//!
+
+use std::{fmt::Display, rc::Rc};
+
+use crate::joker::{
+    callable::{Callable, Signature},
+    error::JokerError,
+    interpreter::{Interpreter, InterpreterError},
+    object::{Literal, Object as OEnum},
+    token::Token,
+    types::Object,
+};
+
+use super::{literal_bool, literal_i32, Caller, Function, NativeFunction, UpCast};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub start: i32,
+    pub end: i32,
+    pub step: i32,
+    pub inclusive: bool,
+}
+
+impl UpCast<OEnum> for Range {
+    fn upcast(&self) -> OEnum {
+        OEnum::Range(self.clone())
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Range(self)
+    }
+}
+
+impl Range {
+    pub fn new(start: i32, end: i32, inclusive: bool) -> Range {
+        Range {
+            start,
+            end,
+            step: 1,
+            inclusive,
+        }
+    }
+    // value in range and on step: start, start + step, ... until end.
+    pub fn contains(&self, value: i32) -> bool {
+        let (start, end, step) = (self.start as i64, self.end as i64, self.step as i64);
+        let value: i64 = value as i64;
+        let in_bound: bool = match (step > 0, self.inclusive) {
+            (true, true) => start <= value && value <= end,
+            (true, false) => start <= value && value < end,
+            (false, true) => end <= value && value <= start,
+            (false, false) => end < value && value <= start,
+        };
+        in_bound && (value - start) % step == 0
+    }
+    // next value after current, none is range end.
+    pub fn next(&self, current: Option<i32>) -> Option<i32> {
+        let value: i64 = match current {
+            Some(current) => current as i64 + self.step as i64,
+            None => self.start as i64,
+        };
+        if value < i32::MIN as i64 || value > i32::MAX as i64 {
+            return None;
+        }
+        if self.contains(value as i32) {
+            Some(value as i32)
+        } else {
+            None
+        }
+    }
+    fn len(&self) -> i32 {
+        let mut count: i32 = 0;
+        let mut current: Option<i32> = None;
+        while let Some(value) = self.next(current) {
+            count += 1;
+            current = Some(value);
+        }
+        count
+    }
+    // range bound native method: range.step(n), range.contains(x), range.len()
+    pub fn getter(&self, name: &Token) -> Result<Option<Object>, JokerError> {
+        match name.lexeme.as_str() {
+            "step" | "contains" | "len" => Ok(Some(Object::new(OEnum::Caller(Caller::Func(
+                Function::Native(NativeFunction {
+                    fun: Rc::new(RangeMethod {
+                        range: self.clone(),
+                        name: name.clone(),
+                    }),
+                }),
+            ))))),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let range: String = format!(
+            "{}{}{}",
+            self.start,
+            if self.inclusive { "..=" } else { ".." },
+            self.end
+        );
+        if self.step == 1 {
+            write!(f, "{}", range)
+        } else {
+            write!(f, "({}).step({})", range, self.step)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RangeMethod {
+    range: Range,
+    name: Token,
+}
+
+impl Callable for RangeMethod {
+    fn call(
+        &self,
+        _interpreter: &Interpreter,
+        arguments: &[Object],
+    ) -> Result<Option<Object>, JokerError> {
+        let argument = || -> Result<i32, JokerError> {
+            match &*arguments[0].get() {
+                OEnum::Literal(Literal::I32(i32_)) => Ok(*i32_),
+                other => Err(JokerError::Interpreter(InterpreterError::new(
+                    &self.name,
+                    format!("range method need i32, but this is '{}'.", other),
+                ))),
+            }
+        };
+        match self.name.lexeme.as_str() {
+            "step" => match argument()? {
+                0 => Err(JokerError::Interpreter(InterpreterError::new(
+                    &self.name,
+                    String::from("range step can't be zero."),
+                ))),
+                step => Ok(Some(Object::new(
+                    Range {
+                        step,
+                        ..self.range.clone()
+                    }
+                    .upcast_into(),
+                ))),
+            },
+            "contains" => Ok(Some(Object::new(literal_bool(
+                self.range.contains(argument()?),
+            )))),
+            "len" => Ok(Some(Object::new(literal_i32(self.range.len())))),
+            _ => unreachable!("[RangeMethod::call] range not have method '{}'.", self.name),
+        }
+    }
+    fn signature(&self) -> Signature {
+        Signature::arity(match self.name.lexeme.as_str() {
+            "step" | "contains" => 1,
+            _ => 0,
+        })
+    }
+}
+
+impl Display for RangeMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RangeMethod({})", self.name.lexeme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_next_with_step() {
+        let collect = |range: &Range| {
+            let mut values: Vec<i32> = Vec::new();
+            let mut current: Option<i32> = None;
+            while let Some(value) = range.next(current) {
+                values.push(value);
+                current = Some(value);
+            }
+            values
+        };
+        assert_eq!(collect(&Range::new(0, 4, false)), vec![0, 1, 2, 3]);
+        assert_eq!(collect(&Range::new(0, 4, true)), vec![0, 1, 2, 3, 4]);
+        let down = Range {
+            step: -2,
+            ..Range::new(5, 0, false)
+        };
+        assert_eq!(collect(&down), vec![5, 3, 1]);
+        assert!(!down.contains(4));
+        assert_eq!(Range::new(3, 3, false).len(), 0);
+    }
+}
@@ -0,0 +1,217 @@
+//! This file is struct rs
+//!
+//! - StructDecl
+//!     - runtime struct declaration: name and fields order.
+//! - Struct
+//!     - value semantics record: copy on bind, compare structurally.
+//! - Error
+//!     - builtin struct: catchable native error { message, line, kind }.
+//!
+//!
+
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::joker::{
+    error::JokerError,
+    interpreter::InterpreterError,
+    object::{literal_i32, literal_null, literal_str, Object as OEnum},
+    token::{Token, TokenType},
+    types::{DeepClone, Object},
+};
+
+// builtin error struct name and fields.
+pub const ERROR_STRUCT: &str = "Error";
+pub const ERROR_FIELDS: [&str; 3] = ["message", "line", "kind"];
+
+pub fn error_token() -> Token {
+    Token::new(
+        TokenType::Identifier,
+        String::from(ERROR_STRUCT),
+        literal_null(),
+        0,
+    )
+}
+
+use super::UpCast;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StructDecl {
+    pub name: Token,
+    pub fields: Vec<String>,
+}
+
+impl StructDecl {
+    pub fn new(name: Token, fields: Vec<String>) -> StructDecl {
+        StructDecl { name, fields }
+    }
+    pub fn error() -> StructDecl {
+        StructDecl::new(
+            error_token(),
+            ERROR_FIELDS.iter().map(|field| field.to_string()).collect(),
+        )
+    }
+}
+
+impl UpCast<OEnum> for StructDecl {
+    fn upcast(&self) -> OEnum {
+        OEnum::StructDecl(Box::new(self.clone()))
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::StructDecl(Box::new(self))
+    }
+}
+
+impl Display for StructDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "struct({})", self.name.lexeme)
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct Struct {
+    pub name: Token,
+    pub fields: Vec<(String, Object)>,
+}
+
+// structural: same struct name and fields value, not care name token position.
+impl PartialEq for Struct {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.lexeme == other.name.lexeme && self.fields == other.fields
+    }
+}
+
+impl Hash for Struct {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.lexeme.hash(state);
+        self.fields.hash(state);
+    }
+}
+
+// nested struct field copy too, other field share reference.
+impl DeepClone for Struct {
+    fn deep_clone(&self) -> Self {
+        Struct {
+            name: self.name.clone(),
+            fields: self
+                .fields
+                .iter()
+                .map(|(field, value)| (field.clone(), value.value_copy()))
+                .collect(),
+        }
+    }
+}
+
+impl UpCast<OEnum> for Struct {
+    fn upcast(&self) -> OEnum {
+        OEnum::Struct(Box::new(self.clone()))
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Struct(Box::new(self))
+    }
+}
+
+impl Struct {
+    pub fn new(name: Token, fields: Vec<(String, Object)>) -> Struct {
+        Struct { name, fields }
+    }
+    pub fn error(kind: &str, message: &str, line: usize) -> Struct {
+        Struct::new(
+            error_token(),
+            vec![
+                (
+                    String::from("message"),
+                    Object::new(literal_str(message.to_string())),
+                ),
+                (String::from("line"), Object::new(literal_i32(line as i32))),
+                (
+                    String::from("kind"),
+                    Object::new(literal_str(kind.to_string())),
+                ),
+            ],
+        )
+    }
+    pub fn getter(&self, name: &Token) -> Result<Object, JokerError> {
+        match self.fields.iter().find(|(field, _)| field.eq(&name.lexeme)) {
+            Some((_, value)) => Ok(value.clone()),
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!(
+                    "struct '{}' undefined field '{}'.",
+                    self.name.lexeme, name.lexeme
+                ),
+            ))),
+        }
+    }
+    pub fn setter(&mut self, name: &Token, value: Object) -> Result<(), JokerError> {
+        match self
+            .fields
+            .iter_mut()
+            .find(|(field, _)| field.eq(&name.lexeme))
+        {
+            Some((_, old)) => {
+                *old = value;
+                Ok(())
+            }
+            None => Err(JokerError::Interpreter(InterpreterError::new(
+                name,
+                format!(
+                    "struct '{}' undefined field '{}'.",
+                    self.name.lexeme, name.lexeme
+                ),
+            ))),
+        }
+    }
+}
+
+impl Display for Struct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {{ {} }}",
+            self.name.lexeme,
+            self.fields
+                .iter()
+                .map(|(field, value)| format!("{}: {}", field, value))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_struct_copy_and_structural_eq() -> Result<(), JokerError> {
+        let name = Token::new(
+            TokenType::Identifier,
+            String::from("Point"),
+            literal_null(),
+            0,
+        );
+        let x = Token::new(TokenType::Identifier, String::from("x"), literal_null(), 0);
+        let point = Object::new(
+            Struct::new(
+                name,
+                vec![
+                    (String::from("x"), Object::new(literal_i32(1))),
+                    (String::from("y"), Object::new(literal_i32(2))),
+                ],
+            )
+            .upcast_into(),
+        );
+        let copy = point.value_copy();
+        assert_eq!(point, copy);
+        if let OEnum::Struct(struct_) = &mut *copy.get_mut() {
+            struct_.setter(&x, Object::new(literal_i32(9)))?;
+        }
+        assert_ne!(point, copy);
+        assert_eq!(point.to_string(), "Point { x: 1, y: 2 }");
+        assert_eq!(copy.to_string(), "Point { x: 9, y: 2 }");
+        Ok(())
+    }
+}
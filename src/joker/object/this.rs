@@ -5,6 +5,12 @@
 //! - Object
 //!     - Literal
 //!     - Caller
+//!     - Instance
+//!     - List
+//!     - Map
+//!     - Tuple
+//!     - Range
+//!     - Generator
 //!
 //!
 //!   #[derive(Debug, Clone, PartialEq)]
@@ -31,7 +37,10 @@ use std::{
 
 use crate::joker::types::DeepClone;
 
-use super::{Caller, Instance, Literal};
+use super::{
+    Caller, Enum, EnumDecl, Generator, Instance, List, Literal, Map, Module, Range, Struct,
+    StructDecl, Tuple,
+};
 
 pub trait UpCast<T> {
     fn upcast(&self) -> T;
@@ -43,6 +52,16 @@ pub enum Object {
     Literal(Literal),
     Caller(Caller),
     Instance(Box<Instance>),
+    List(List),
+    Map(Map),
+    StructDecl(Box<StructDecl>),
+    Struct(Box<Struct>),
+    EnumDecl(Box<EnumDecl>),
+    Enum(Box<Enum>),
+    Module(Box<Module>),
+    Tuple(Tuple),
+    Range(Range),
+    Generator(Generator),
 }
 
 impl DeepClone for Object {
@@ -51,6 +70,16 @@ impl DeepClone for Object {
             Object::Literal(_) => self.clone(),
             Object::Caller(caller) => Object::Caller(DeepClone::deep_clone(caller)),
             Object::Instance(instance) => Object::Instance(DeepClone::deep_clone(instance)),
+            Object::List(list) => Object::List(DeepClone::deep_clone(list)),
+            Object::Map(map) => Object::Map(DeepClone::deep_clone(map)),
+            Object::StructDecl(_) => self.clone(),
+            Object::Struct(struct_) => Object::Struct(Box::new(struct_.as_ref().deep_clone())),
+            Object::EnumDecl(_) => self.clone(),
+            Object::Enum(enum_) => Object::Enum(Box::new(enum_.as_ref().deep_clone())),
+            Object::Module(_) => self.clone(),
+            Object::Tuple(tuple) => Object::Tuple(DeepClone::deep_clone(tuple)),
+            Object::Range(_) => self.clone(),
+            Object::Generator(_) => self.clone(),
         }
     }
 }
@@ -61,6 +90,16 @@ impl Display for Object {
             Object::Literal(literal) => Display::fmt(literal, f),
             Object::Caller(caller) => Display::fmt(caller, f),
             Object::Instance(instance) => Display::fmt(instance, f),
+            Object::List(list) => Display::fmt(list, f),
+            Object::Map(map) => Display::fmt(map, f),
+            Object::StructDecl(struct_decl) => Display::fmt(struct_decl, f),
+            Object::Struct(struct_) => Display::fmt(struct_, f),
+            Object::EnumDecl(enum_decl) => Display::fmt(enum_decl, f),
+            Object::Enum(enum_) => Display::fmt(enum_, f),
+            Object::Module(module) => Display::fmt(module, f),
+            Object::Tuple(tuple) => Display::fmt(tuple, f),
+            Object::Range(range) => Display::fmt(range, f),
+            Object::Generator(generator) => Display::fmt(generator, f),
         }
     }
 }
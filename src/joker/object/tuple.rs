@@ -0,0 +1,89 @@
+//! This file is tuple rs
+//!
+//! - Tuple
+//!     - fixed size immutable values: (1, "a", true), compare structurally.
+//!
+//!
+
+use std::fmt::Display;
+
+use crate::joker::{
+    error::JokerError,
+    interpreter::InterpreterError,
+    object::{Literal, Object as OEnum},
+    token::Token,
+    types::{DeepClone, Object},
+};
+
+use super::UpCast;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tuple {
+    pub elements: Vec<Object>,
+}
+
+// struct element copy too, other element share reference.
+impl DeepClone for Tuple {
+    fn deep_clone(&self) -> Self {
+        Tuple {
+            elements: self
+                .elements
+                .iter()
+                .map(|element| element.value_copy())
+                .collect(),
+        }
+    }
+}
+
+impl UpCast<OEnum> for Tuple {
+    fn upcast(&self) -> OEnum {
+        OEnum::Tuple(self.clone())
+    }
+    fn upcast_into(self) -> OEnum {
+        OEnum::Tuple(self)
+    }
+}
+
+impl Tuple {
+    pub fn new(elements: Vec<Object>) -> Tuple {
+        Tuple { elements }
+    }
+    fn len(&self) -> usize {
+        self.elements.len()
+    }
+    pub fn get(&self, bracket: &Token, index: &Object) -> Result<Object, JokerError> {
+        match &*index.get() {
+            OEnum::Literal(Literal::I32(i32_)) if *i32_ >= 0 && (*i32_ as usize) < self.len() => {
+                Ok(self.elements[*i32_ as usize].clone())
+            }
+            OEnum::Literal(Literal::I32(i32_)) => {
+                Err(JokerError::Interpreter(InterpreterError::new(
+                    bracket,
+                    format!(
+                        "tuple index out of range: the len is {} but the index is {}.",
+                        self.len(),
+                        i32_
+                    ),
+                )))
+            }
+            other => Err(JokerError::Interpreter(InterpreterError::new(
+                bracket,
+                format!("tuple index need i32, but this is '{}'.", other),
+            ))),
+        }
+    }
+}
+
+impl Display for Tuple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({})",
+            self.elements
+                .iter()
+                .map(|element| element.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
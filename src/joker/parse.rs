@@ -3,17 +3,24 @@
 //!
 //!
 
-use std::{error::Error, fmt::Display};
+use std::{collections::HashMap, error::Error, fmt::Display};
 
 use super::{
     abort::ArgLimitAbort,
     ast::{
-        Assign, Binary, BlockStmt, BreakStmt, Call, ClassStmt, ContinueStmt, Expr, ExprStmt,
-        FnStmt, ForStmt, Getter, Grouping, IfStmt, Lambda, Literal, Logical, PrintStmt, ReturnStmt,
-        Setter, Stmt, Super, This, Trinomial, Unary, VarStmt, Variable, WhileStmt,
+        Assign, AssignOp, Binary, BlockStmt, BreakStmt, Call, Cast, ClassStmt, ConstStmt,
+        ContinueStmt, DestructStmt, DoWhileStmt, EnumStmt, ExportStmt, Expr, ExprStmt, FnStmt,
+        ForInStmt, ForStmt, Getter, Grouping, IfStmt, ImportStmt, Index, IndexSet, InterfaceStmt,
+        Is, Lambda, List, Literal, Logical, Map, Match, MatchArm, Pattern, PrintStmt, Range,
+        ReturnStmt, SafeGetter, Setter, Stmt, StructInit, StructStmt, Super, Template, This,
+        ThrowStmt, Trinomial, TryStmt, Tuple, TypeStmt, Unary, VarStmt, Variable, WhileStmt,
+        YieldStmt,
     },
     error::{JokerError, ReportError},
-    object::{literal_bool, FuncType},
+    object::{
+        literal_bool, literal_f64, literal_i32, literal_i64, literal_null, FuncType,
+        Literal as ObL, Object as OEnum,
+    },
     token::{Token, TokenType},
     types::{ParamPair, Type, TypeInferrer},
 };
@@ -21,11 +28,20 @@ use super::{
 pub struct Parser {
     tokens: Vec<Token>,
     current: usize,
+    generics: Vec<Token>, // type parameters of parsing generic fn and class.
+    aliases: HashMap<String, Type>, // type alias declared before: type Id = i32 | str;
+    pipe_params: bool,    // parsing lambda params, '|' close params not union.
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Parser {
-        Parser { tokens, current: 0 }
+        Parser {
+            tokens,
+            current: 0,
+            generics: Vec::new(),
+            aliases: HashMap::new(),
+            pipe_params: false,
+        }
     }
     fn is_at_end(&self) -> bool {
         self.peek().ttype == TokenType::Eof
@@ -42,6 +58,13 @@ impl Parser {
     fn previous(&self) -> Token {
         self.tokens.get(self.current - 1).unwrap().clone()
     }
+    // look ahead: offset 0 is peek.
+    fn check_next(&self, offset: usize, ttype: &TokenType) -> bool {
+        match self.tokens.get(self.current + offset) {
+            Some(token) => &token.ttype == ttype,
+            None => false,
+        }
+    }
     fn check(&self, ttype: &TokenType) -> bool {
         if self.is_at_end() {
             false
@@ -66,6 +89,7 @@ impl Parser {
                 Ok(stmt) => stmts.push(stmt),
                 Err(err) => {
                     has_error = Some(err);
+                    self.generics.clear();
                     if self.is_at_end() {
                         break;
                     } // input: cc -> cc + Eof(now pos)
@@ -83,25 +107,95 @@ impl Parser {
     //               | var_declaration  (声明)
     //               | fn_declaration
     //               | class_declaration
+    //               | struct_declaration
+    //               | enum_declaration
+    //               | interface_declaration
+    //               | type_declaration
     fn declaration(&mut self) -> Result<Stmt, JokerError> {
+        if self.is_match(&[TokenType::Import]) {
+            return self.import_declaration();
+        }
+        if self.is_match(&[TokenType::Export]) {
+            return self.export_declaration();
+        }
         if self.is_match(&[TokenType::Class]) {
             return self.class_declaration();
         }
+        if self.is_match(&[TokenType::Struct]) {
+            return self.struct_declaration();
+        }
+        if self.is_match(&[TokenType::Enum]) {
+            return self.enum_declaration();
+        }
+        if self.is_match(&[TokenType::Interface]) {
+            return self.interface_declaration();
+        }
+        if self.is_match(&[TokenType::Type]) {
+            return self.type_declaration();
+        }
         if self.is_match(&[TokenType::Fn]) {
             return self.fn_declaration();
         }
         if self.is_match(&[TokenType::Var]) {
             return self.var_declaration();
         }
+        if self.is_match(&[TokenType::Const]) {
+            return self.const_declaration();
+        }
         self.statement()
     }
+    // import_declaration → "import" STRING "as" IDENTIFIER ";" ;
+    fn import_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
+        let path: Token = self.consume(
+            &[TokenType::Str],
+            String::from("Expect module path string after 'import'."),
+        )?;
+        self.consume(
+            &[TokenType::As],
+            String::from("Expect 'as' after module path."),
+        )?;
+        let name: Token = self.consume(
+            &[TokenType::Identifier],
+            String::from("Expect module name after 'as'."),
+        )?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after import declaration."),
+        )?;
+        Ok(ImportStmt::upcast(keyword, path, name))
+    }
+    // export_declaration → "export" ( class_decl | struct_decl | enum_decl | fn_decl ) ;
+    fn export_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
+        let stmt: Stmt = if self.is_match(&[TokenType::Class]) {
+            self.class_declaration()?
+        } else if self.is_match(&[TokenType::Struct]) {
+            self.struct_declaration()?
+        } else if self.is_match(&[TokenType::Enum]) {
+            self.enum_declaration()?
+        } else if self.is_match(&[TokenType::Fn]) {
+            self.fn_declaration()?
+        } else if self.is_match(&[TokenType::Const]) {
+            self.const_declaration()?
+        } else {
+            return Err(JokerError::Parser(ParserError::report_error(
+                &self.peek(),
+                String::from("Expect fn, class, struct, enum or const declaration after 'export'."),
+            )));
+        };
+        Ok(ExportStmt::upcast(keyword, Box::new(stmt)))
+    }
     // class_declaration      → "class" classStmt ;
-    // classStmt      → "class" IDENTIFIER (":" IDENTIFIER )? "{"
-    //                      var_decl* | fn_decl* | method_decl*
+    // classStmt      → "class" IDENTIFIER (":" IDENTIFIER )? ("impl" IDENTIFIER ("," IDENTIFIER)* )? "{"
+    //                      var_decl* | fn_decl* | method_decl* | "static" (var_decl | fn_decl)
     //                  "}" ;
     fn class_declaration(&mut self) -> Result<Stmt, JokerError> {
         let name: Token =
             self.consume(&[TokenType::Identifier], String::from("expect class name."))?;
+        let generics: Vec<Token> = self.type_params()?;
+        let depth: usize = self.generics.len();
+        self.generics.extend(generics.iter().cloned());
 
         let super_class: Option<Expr> = if self.is_match(&[TokenType::Colon]) {
             let super_class = self.consume(
@@ -112,19 +206,56 @@ impl Parser {
         } else {
             None
         };
+        let mut interfaces: Vec<Token> = Vec::new();
+        if self.is_match(&[TokenType::Impl]) {
+            loop {
+                interfaces.push(self.consume(
+                    &[TokenType::Identifier],
+                    String::from("expect interface name after 'impl'."),
+                )?);
+                if !self.is_match(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
         self.consume(
             &[TokenType::LeftBrace],
             String::from("expect '{' before class body."),
         )?;
 
-        let (fields, methods, functions) = if self.check(&TokenType::RightBrace) {
-            (None, None, None)
+        let (fields, methods, functions, statics) = if self.check(&TokenType::RightBrace) {
+            (None, None, None, None)
         } else {
             let mut fields = Vec::new();
             let mut methods = Vec::new();
             let mut functions = Vec::new();
+            let mut statics = Vec::new();
             loop {
-                if self.is_match(&[TokenType::Var]) {
+                if self.is_match(&[TokenType::Static]) {
+                    // static var live once on class, static fn is class function.
+                    if self.is_match(&[TokenType::Var]) {
+                        statics.push(self.var_declaration()?);
+                    } else if self.is_match(&[TokenType::Fn]) {
+                        match self.class_fn_declaration(&name)? {
+                            FuncType::Function(function) => functions.push(function),
+                            FuncType::Method(method) => {
+                                let name: Token = match method {
+                                    Stmt::FnStmt(fn_stmt) => fn_stmt.name,
+                                    _ => self.previous(),
+                                };
+                                return Err(JokerError::Parser(ParserError::report_error(
+                                    &name,
+                                    String::from("static fn can't have 'this' parameter."),
+                                )));
+                            }
+                        }
+                    } else {
+                        return Err(JokerError::Parser(ParserError::report_error(
+                            &self.peek(),
+                            String::from("Expect var or fn after 'static'."),
+                        )));
+                    }
+                } else if self.is_match(&[TokenType::Var]) {
                     fields.push(self.var_declaration()?);
                 } else if self.is_match(&[TokenType::Fn]) {
                     match self.class_fn_declaration(&name)? {
@@ -134,7 +265,7 @@ impl Parser {
                 } else {
                     return Err(JokerError::Parser(ParserError::report_error(
                         &self.peek(),
-                        String::from("class inside only have var and fun."),
+                        String::from("class inside only have var, fn and static."),
                     )));
                 }
                 if self.check(&TokenType::RightBrace) {
@@ -157,29 +288,202 @@ impl Parser {
                 } else {
                     Some(functions)
                 },
+                if statics.is_empty() {
+                    None
+                } else {
+                    Some(statics)
+                },
             )
         };
         self.consume(
             &[TokenType::RightBrace],
             String::from("expect '}' after class body."),
         )?;
+        self.generics.truncate(depth);
         Ok(ClassStmt::upcast(
             name,
             super_class,
             fields,
             methods,
             functions,
+            statics,
+            interfaces,
+            generics,
         ))
     }
+    // interface_declaration → "interface" IDENTIFIER "{"
+    //                              ( "fn" IDENTIFIER "(" ( "this" ( "," IDENTIFIER ":" type )* )? ")"
+    //                                  ( "->" type )? ";" )*
+    //                          "}" ;
+    fn interface_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let name: Token = self.consume(
+            &[TokenType::Identifier],
+            String::from("expect interface name."),
+        )?;
+        self.consume(
+            &[TokenType::LeftBrace],
+            String::from("expect '{' before interface body."),
+        )?;
+        let mut methods: Vec<(Token, Type)> = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            self.consume(
+                &[TokenType::Fn],
+                String::from("interface inside only have fn signature."),
+            )?;
+            let method: Token = self.consume(
+                &[TokenType::Identifier],
+                String::from("Expect interface method name."),
+            )?;
+            self.consume(
+                &[TokenType::LeftParen],
+                String::from("Expect '(' after interface method name."),
+            )?;
+            let params: Option<Vec<ParamPair>> = if self.check(&TokenType::RightParen) {
+                None
+            } else {
+                let mut params: Vec<ParamPair> = if self.check(&TokenType::This) {
+                    vec![ParamPair::this_with_parse(self, &name)?]
+                } else {
+                    vec![ParamPair::normal_with_parse(self)?]
+                };
+                while self.is_match(&[TokenType::Comma]) {
+                    params.push(ParamPair::normal_with_parse(self)?);
+                }
+                Some(params)
+            };
+            self.consume(
+                &[TokenType::RightParen],
+                String::from("Expect ')' after parameters."),
+            )?;
+            let return_type: Option<Box<Type>> = if self.is_match(&[TokenType::Arrow]) {
+                Some(Box::new(TypeInferrer::parse_type(self)?))
+            } else {
+                None
+            };
+            self.consume(
+                &[TokenType::Semicolon],
+                String::from("Expect ';' after interface method signature."),
+            )?;
+            if !params.as_ref().is_some_and(|params| params[0].is_this()) {
+                return Err(JokerError::Parser(ParserError::report_error(
+                    &method,
+                    String::from("interface method first parameter need 'this'."),
+                )));
+            }
+            methods.push((
+                method,
+                Type::Fn {
+                    params,
+                    return_type,
+                },
+            ));
+        }
+        self.consume(
+            &[TokenType::RightBrace],
+            String::from("expect '}' after interface body."),
+        )?;
+        Ok(InterfaceStmt::upcast(name, methods))
+    }
+    // struct_declaration → "struct" structStmt ;
+    // structStmt         → "struct" IDENTIFIER "{"
+    //                          ( IDENTIFIER ":" type ( "," IDENTIFIER ":" type )* ","? )?
+    //                      "}" ;
+    fn struct_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let name: Token = self.consume(
+            &[TokenType::Identifier],
+            String::from("expect struct name."),
+        )?;
+        self.consume(
+            &[TokenType::LeftBrace],
+            String::from("expect '{' before struct body."),
+        )?;
+        let mut fields: Vec<(Token, Type)> = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let field: Token = self.consume(
+                &[TokenType::Identifier],
+                String::from("expect struct field name."),
+            )?;
+            self.consume(
+                &[TokenType::Colon],
+                String::from("expect ':' after struct field name."),
+            )?;
+            fields.push((field, TypeInferrer::parse_type(self)?));
+            if !self.is_match(&[TokenType::Comma]) {
+                break;
+            }
+        }
+        self.consume(
+            &[TokenType::RightBrace],
+            String::from("expect '}' after struct body."),
+        )?;
+        Ok(StructStmt::upcast(name, fields))
+    }
+    // enum_declaration → "enum" enumStmt ;
+    // enumStmt         → "enum" IDENTIFIER "{" ( variant ( "," variant )* ","? )? "}" ;
+    // variant          → IDENTIFIER ( "(" type ( "," type )* ")" )? ;
+    fn enum_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let name: Token =
+            self.consume(&[TokenType::Identifier], String::from("expect enum name."))?;
+        self.consume(
+            &[TokenType::LeftBrace],
+            String::from("expect '{' before enum body."),
+        )?;
+        let mut variants: Vec<(Token, Vec<Type>)> = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let variant: Token = self.consume(
+                &[TokenType::Identifier],
+                String::from("expect enum variant name."),
+            )?;
+            let mut payload: Vec<Type> = Vec::new();
+            if self.is_match(&[TokenType::LeftParen]) {
+                payload.push(TypeInferrer::parse_type(self)?);
+                while self.is_match(&[TokenType::Comma]) {
+                    payload.push(TypeInferrer::parse_type(self)?);
+                }
+                self.consume(
+                    &[TokenType::RightParen],
+                    String::from("expect ')' after enum variant payload."),
+                )?;
+            }
+            variants.push((variant, payload));
+            if !self.is_match(&[TokenType::Comma]) {
+                break;
+            }
+        }
+        self.consume(
+            &[TokenType::RightBrace],
+            String::from("expect '}' after enum body."),
+        )?;
+        Ok(EnumStmt::upcast(name, variants))
+    }
+    // generator fn `fn* name() -> T` yield T, call return Gen<T>.
+    fn generator_type(
+        &self,
+        generator: bool,
+        name: &Token,
+        return_type: Option<Box<Type>>,
+    ) -> Result<Option<Box<Type>>, JokerError> {
+        match (generator, return_type) {
+            (false, return_type) => Ok(return_type),
+            (true, Some(yield_type)) => Ok(Some(Box::new(Type::Generator(yield_type)))),
+            (true, None) => Err(JokerError::Parser(ParserError::report_error(
+                name,
+                format!(
+                    "Generator function '{}' need yield type '-> T'.",
+                    name.lexeme
+                ),
+            ))),
+        }
+    }
     fn label_func(&self, fn_stmt: FnStmt) -> Result<FuncType, JokerError> {
         match &fn_stmt.params {
             Some(params) => match params[0].as_ref() {
                 ParamPair::This { param, type_: _ } if param.is_this() => {
                     Ok(FuncType::Method(Stmt::FnStmt(fn_stmt)))
                 }
-                ParamPair::Normal { param: _, type_: _ } => {
-                    Ok(FuncType::Function(Stmt::FnStmt(fn_stmt)))
-                }
+                ParamPair::Normal { param: _, type_: _ }
+                | ParamPair::Default { .. }
+                | ParamPair::Rest { .. } => Ok(FuncType::Function(Stmt::FnStmt(fn_stmt))),
                 ParamPair::This { param, type_: _ } => {
                     Err(JokerError::Parser(ParserError::report_error(
                         param,
@@ -200,6 +504,7 @@ impl Parser {
         }
     }
     fn class_fn_declaration(&mut self, class: &Token) -> Result<FuncType, JokerError> {
+        let generator: bool = self.is_match(&[TokenType::Star]);
         let name: Token = self.consume(
             &[TokenType::Identifier],
             String::from("Expect function name."),
@@ -215,12 +520,13 @@ impl Parser {
             let mut params: Vec<ParamPair> = if self.check(&TokenType::This) {
                 vec![ParamPair::this_with_parse(self, class)?]
             } else {
-                vec![ParamPair::normal_with_parse(self)?]
+                vec![self.fn_param()?]
             };
 
             while self.is_match(&[TokenType::Comma]) {
-                params.push(ParamPair::normal_with_parse(self)?);
+                params.push(self.fn_param()?);
             }
+            self.check_fn_params(&params)?;
             if params.len() >= 255 {
                 // TODO: warning
                 ArgLimitAbort::report_error(
@@ -240,6 +546,7 @@ impl Parser {
         } else {
             None
         };
+        let return_type: Option<Box<Type>> = self.generator_type(generator, &name, return_type)?;
 
         self.consume(
             &[TokenType::LeftBrace],
@@ -247,9 +554,13 @@ impl Parser {
         )?;
 
         match self.block_statement() {
-            Ok(Stmt::BlockStmt(body)) => {
-                Ok(self.label_func(FnStmt::new(name, params, return_type, body.stmts))?)
-            }
+            Ok(Stmt::BlockStmt(body)) => Ok(self.label_func(FnStmt::new(
+                name,
+                params,
+                return_type,
+                body.stmts,
+                generator,
+            ))?),
             Ok(_) => Err(JokerError::Parser(ParserError::report_error(
                 &self.peek(),
                 String::from("fn translation err!"),
@@ -258,14 +569,18 @@ impl Parser {
         }
     }
     // fn_declaration        → "fn" FnStmt ;
-    // FnStmt        → "fn" IDENTIFIER  "("
+    // FnStmt        → "fn" "*"? IDENTIFIER  "("
     //                      (IDENTIFIER ":" IDENTIFIER (, IDENTIFIER ":" IDENTIFIER )*? )?
     //                  ")" ("->" IDENTIFIER)? statement ;
     fn fn_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let generator: bool = self.is_match(&[TokenType::Star]);
         let name: Token = self.consume(
             &[TokenType::Identifier],
             String::from("Expect function name."),
         )?;
+        let depth: usize = self.generics.len();
+        let generics: Vec<Token> = self.type_params()?;
+        self.generics.extend(generics);
         self.consume(
             &[TokenType::LeftParen],
             String::from("Expect '(' after function name."),
@@ -274,10 +589,11 @@ impl Parser {
         let params: Option<Vec<ParamPair>> = if self.check(&TokenType::RightParen) {
             None
         } else {
-            let mut params: Vec<ParamPair> = vec![ParamPair::normal_with_parse(self)?];
+            let mut params: Vec<ParamPair> = vec![self.fn_param()?];
             while self.is_match(&[TokenType::Comma]) {
-                params.push(ParamPair::normal_with_parse(self)?);
+                params.push(self.fn_param()?);
             }
+            self.check_fn_params(&params)?;
             if params.len() >= 255 {
                 // TODO: warning
                 ArgLimitAbort::report_error(
@@ -297,14 +613,23 @@ impl Parser {
         } else {
             None
         };
+        let return_type: Option<Box<Type>> = self.generator_type(generator, &name, return_type)?;
 
         self.consume(
             &[TokenType::LeftBrace],
             String::from("Expect '{' before body."),
         )?;
 
-        match self.block_statement() {
-            Ok(Stmt::BlockStmt(body)) => Ok(FnStmt::upcast(name, params, return_type, body.stmts)),
+        let body: Result<Stmt, JokerError> = self.block_statement();
+        self.generics.truncate(depth);
+        match body {
+            Ok(Stmt::BlockStmt(body)) => Ok(FnStmt::upcast(
+                name,
+                params,
+                return_type,
+                body.stmts,
+                generator,
+            )),
             Ok(_) => Err(JokerError::Parser(ParserError::report_error(
                 &self.peek(),
                 String::from("fn translation err!"),
@@ -312,8 +637,88 @@ impl Parser {
             Err(err) => Err(err),
         }
     }
-    // varStmt → "var" IDENTIFIER (":" IDENTIFIER)?  ("=" expression )? ";" ;
+    // type_declaration → "type" IDENTIFIER "=" type ";" ;
+    fn type_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let name: Token = self.consume(
+            &[TokenType::Identifier],
+            String::from("Expect type alias name."),
+        )?;
+        if Type::builtin(&name.lexeme).is_some() || self.aliases.contains_key(&name.lexeme) {
+            return Err(JokerError::Parser(ParserError::report_error(
+                &name,
+                format!("Type '{}' already defined.", name.lexeme),
+            )));
+        }
+        self.consume(
+            &[TokenType::Equal],
+            String::from("Expect '=' after type alias name."),
+        )?;
+        let type_: Type = TypeInferrer::parse_type(self)?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after type alias."),
+        )?;
+        self.aliases.insert(name.lexeme.clone(), type_.clone());
+        Ok(TypeStmt::upcast(name, type_))
+    }
+    // alias type declared before, type name resolve to Alias in parse.
+    pub(crate) fn alias_type(&self, name: &Token) -> Option<Type> {
+        self.aliases.get(&name.lexeme).map(|target| Type::Alias {
+            name: name.clone(),
+            target: Box::new(target.clone()),
+        })
+    }
+    pub(crate) fn is_pipe_params(&self) -> bool {
+        self.pipe_params
+    }
+    // type_params → ( "<" IDENTIFIER ( "," IDENTIFIER )* ">" )? ;
+    fn type_params(&mut self) -> Result<Vec<Token>, JokerError> {
+        let mut generics: Vec<Token> = Vec::new();
+        if self.is_match(&[TokenType::Less]) {
+            loop {
+                generics.push(self.consume(
+                    &[TokenType::Identifier],
+                    String::from("Expect type parameter name."),
+                )?);
+                if !self.is_match(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+            self.consume(
+                &[TokenType::Greater],
+                String::from("Expect '>' after type parameters."),
+            )?;
+        }
+        Ok(generics)
+    }
+    pub(crate) fn is_type_param(&self, name: &Token) -> bool {
+        self.generics
+            .iter()
+            .any(|generic| generic.lexeme == name.lexeme)
+    }
+    // type arguments end '>', nested `Box<Box<T>>` split '>>' to two '>'.
+    pub(crate) fn consume_type_args_end(&mut self) -> Result<Token, JokerError> {
+        if self.check(&TokenType::GreaterGreater) {
+            let greater: Token = self.peek();
+            self.tokens[self.current] = Token::new(
+                TokenType::Greater,
+                String::from(">"),
+                greater.literal.clone(),
+                greater.line,
+            );
+            return Ok(greater);
+        }
+        self.consume(
+            &[TokenType::Greater],
+            String::from("Expect '>' after type arguments."),
+        )
+    }
+    // varStmt → "var" "mut"? IDENTIFIER (":" IDENTIFIER)?  ("=" expression )? ";" ;
     fn var_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let mutable: bool = self.is_match(&[TokenType::Mut]);
+        if self.is_match(&[TokenType::LeftParen]) {
+            return self.destruct_declaration(mutable);
+        }
         let name: Token = self.consume(
             &[TokenType::Identifier],
             String::from("Expect variable name."),
@@ -334,7 +739,70 @@ impl Parser {
             &[TokenType::Semicolon],
             String::from("Expect ';' after variable declaration."),
         )?;
-        Ok(VarStmt::upcast(name, type_, value))
+        Ok(VarStmt::upcast(name, mutable, type_, value))
+    }
+    // destruct → "(" ( IDENTIFIER | "_" ) ( "," ( IDENTIFIER | "_" ) )+ ")" (":" type)? "=" expression ";" ;
+    fn destruct_declaration(&mut self, mutable: bool) -> Result<Stmt, JokerError> {
+        let (paren, names) = self.destruct_names()?;
+
+        let type_: Option<Type> = if self.is_match(&[TokenType::Colon]) {
+            Some(TypeInferrer::parse_type(self)?)
+        } else {
+            None
+        };
+
+        self.consume(
+            &[TokenType::Equal],
+            String::from("Expect '=' after destructure names."),
+        )?;
+        let value: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after variable declaration."),
+        )?;
+        Ok(DestructStmt::upcast(paren, names, mutable, type_, value))
+    }
+    // destructure names after '(', '_' skip element.
+    fn destruct_names(&mut self) -> Result<(Token, Vec<Token>), JokerError> {
+        let mut names: Vec<Token> = vec![self.consume(
+            &[TokenType::Identifier],
+            String::from("Expect variable name in destructure."),
+        )?];
+        while self.is_match(&[TokenType::Comma]) {
+            names.push(self.consume(
+                &[TokenType::Identifier],
+                String::from("Expect variable name in destructure."),
+            )?);
+        }
+        let paren: Token = self.consume(
+            &[TokenType::RightParen],
+            String::from("Expect ')' after destructure names."),
+        )?;
+        Ok((paren, names))
+    }
+    // constStmt → "const" IDENTIFIER (":" IDENTIFIER)? "=" expression ";" ;
+    fn const_declaration(&mut self) -> Result<Stmt, JokerError> {
+        let name: Token = self.consume(
+            &[TokenType::Identifier],
+            String::from("Expect constant name."),
+        )?;
+
+        let type_: Option<Type> = if self.is_match(&[TokenType::Colon]) {
+            Some(TypeInferrer::parse_type(self)?)
+        } else {
+            None
+        };
+
+        self.consume(
+            &[TokenType::Equal],
+            String::from("Expect '=' after constant name."),
+        )?;
+        let value: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after constant declaration."),
+        )?;
+        Ok(ConstStmt::upcast(name, type_, value))
     }
     // stmt -> print_stmt
     //        | return_stmt
@@ -349,14 +817,20 @@ impl Parser {
         if self.is_match(&[TokenType::Return]) {
             return self.return_statement();
         }
+        if self.is_match(&[TokenType::Yield]) {
+            return self.yield_statement();
+        }
         if self.is_match(&[TokenType::Continue]) {
             return self.continue_statement();
         }
         if self.is_match(&[TokenType::Break]) {
             return self.break_statement();
         }
+        if self.check(&TokenType::Identifier) && self.check_next(1, &TokenType::Colon) {
+            return self.labeled_statement();
+        }
         if self.is_match(&[TokenType::For]) {
-            return self.for_statement();
+            return self.for_statement(None);
         }
         if self.is_match(&[TokenType::If]) {
             return self.if_statement();
@@ -365,7 +839,19 @@ impl Parser {
             return self.print_statement();
         }
         if self.is_match(&[TokenType::While]) {
-            return self.while_statement();
+            return self.while_statement(None);
+        }
+        if self.is_match(&[TokenType::Loop]) {
+            return self.loop_statement(None);
+        }
+        if self.is_match(&[TokenType::Do]) {
+            return self.do_while_statement(None);
+        }
+        if self.is_match(&[TokenType::Throw]) {
+            return self.throw_statement();
+        }
+        if self.is_match(&[TokenType::Try]) {
+            return self.try_statement();
         }
         if self.is_match(&[TokenType::LeftBrace]) {
             return self.block_statement();
@@ -386,23 +872,65 @@ impl Parser {
         )?;
         Ok(ReturnStmt::upcast(keyword, value))
     }
-    // continueStmt -> "continue" ";" ;
+    // yieldStmt -> "yield" expression ";" ;
+    fn yield_statement(&mut self) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
+        let value: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after yield value."),
+        )?;
+        Ok(YieldStmt::upcast(keyword, value))
+    }
+    // continueStmt -> "continue" IDENTIFIER? ";" ;
     fn continue_statement(&mut self) -> Result<Stmt, JokerError> {
         let name: Token = self.previous();
+        let label: Option<Token> = self.loop_label();
         self.consume(
             &[TokenType::Semicolon],
             String::from("Expect ';' after 'continue' statement."),
         )?;
-        Ok(ContinueStmt::upcast(name))
+        Ok(ContinueStmt::upcast(name, label))
     }
-    // breakStmt -> "break" ";" ;
+    // breakStmt -> "break" IDENTIFIER? ";" ;
     fn break_statement(&mut self) -> Result<Stmt, JokerError> {
         let name: Token = self.previous();
+        let label: Option<Token> = self.loop_label();
         self.consume(
             &[TokenType::Semicolon],
             String::from("Expect ';' after 'break' statement."),
         )?;
-        Ok(BreakStmt::upcast(name))
+        Ok(BreakStmt::upcast(name, label))
+    }
+    // break and continue target loop label.
+    fn loop_label(&mut self) -> Option<Token> {
+        if self.is_match(&[TokenType::Identifier]) {
+            Some(self.previous())
+        } else {
+            None
+        }
+    }
+    // labeledStmt → IDENTIFIER ":" ( whileStmt | forStmt | loopStmt | doWhileStmt ) ;
+    fn labeled_statement(&mut self) -> Result<Stmt, JokerError> {
+        let label: Token = self.advance();
+        // checked by statement: next is ':'.
+        self.advance();
+        if self.is_match(&[TokenType::While]) {
+            return self.while_statement(Some(label));
+        }
+        if self.is_match(&[TokenType::For]) {
+            return self.for_statement(Some(label));
+        }
+        if self.is_match(&[TokenType::Loop]) {
+            return self.loop_statement(Some(label));
+        }
+        if self.is_match(&[TokenType::Do]) {
+            return self.do_while_statement(Some(label));
+        }
+        Err(JokerError::Parser(ParserError::report_error(
+            &label,
+            format!("Expect loop statement after label '{}'.", label.lexeme),
+        )))
     }
     // forStmt        → "for" "(" ( varDecl | exprStmt | ";" )
     //                  expression? ";"
@@ -414,11 +942,15 @@ impl Parser {
     //     i = i + 1;
     //   }
     // }
-    fn for_statement(&mut self) -> Result<Stmt, JokerError> {
+    fn for_statement(&mut self, label: Option<Token>) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
         self.consume(
             &[TokenType::LeftParen],
             String::from("Expect '(' after 'for'."),
         )?;
+        if self.is_for_in() {
+            return self.for_in_statement(keyword, label);
+        }
 
         let initializer: Option<Box<Stmt>> = if self.is_match(&[TokenType::Semicolon]) {
             None
@@ -455,10 +987,67 @@ impl Parser {
             condition,
             increment,
             Box::new(body),
+            label,
+        ))
+    }
+    // for-in header: IDENTIFIER "in" | "(" IDENTIFIER ( "," IDENTIFIER )* ")" "in",
+    // 'in' is contextual keyword, other place is identifier.
+    fn is_for_in(&self) -> bool {
+        let mut offset: usize = 0;
+        if self.check(&TokenType::LeftParen) {
+            offset += 1;
+            while self.check_next(offset, &TokenType::Identifier)
+                && self.check_next(offset + 1, &TokenType::Comma)
+            {
+                offset += 2;
+            }
+            if !(self.check_next(offset, &TokenType::Identifier)
+                && self.check_next(offset + 1, &TokenType::RightParen))
+            {
+                return false;
+            }
+            offset += 2;
+        } else if self.check(&TokenType::Identifier) {
+            offset += 1;
+        } else {
+            return false;
+        }
+        self.tokens
+            .get(self.current + offset)
+            .is_some_and(|token| token.ttype == TokenType::Identifier && token.lexeme == "in")
+    }
+    // forInStmt → "for" "(" ( IDENTIFIER | destruct ) "in" expression ")" statement ;
+    fn for_in_statement(
+        &mut self,
+        keyword: Token,
+        label: Option<Token>,
+    ) -> Result<Stmt, JokerError> {
+        let (names, destruct): (Vec<Token>, bool) = if self.is_match(&[TokenType::LeftParen]) {
+            (self.destruct_names()?.1, true)
+        } else {
+            (vec![self.advance()], false)
+        };
+        // checked by is_for_in: next is 'in'.
+        self.advance();
+        let iterable: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::RightParen],
+            String::from("Expect ')' after for in iterable."),
+        )?;
+
+        let body: Stmt = self.statement()?;
+
+        Ok(ForInStmt::upcast(
+            keyword,
+            names,
+            destruct,
+            iterable,
+            Box::new(body),
+            label,
         ))
     }
     // whileStmt      → "while" "(" expression ")" statement ;
-    fn while_statement(&mut self) -> Result<Stmt, JokerError> {
+    fn while_statement(&mut self, label: Option<Token>) -> Result<Stmt, JokerError> {
         self.consume(
             &[TokenType::LeftParen],
             String::from("Expect '(' after 'while'."),
@@ -469,7 +1058,47 @@ impl Parser {
             String::from("Expect ')' after while condition."),
         )?;
         let body: Stmt = self.statement()?;
-        Ok(WhileStmt::upcast(condition, Box::new(body)))
+        Ok(WhileStmt::upcast(condition, Box::new(body), label))
+    }
+    // loopStmt       → "loop" statement ;
+    // {
+    //   while (true) statement
+    // }
+    fn loop_statement(&mut self, label: Option<Token>) -> Result<Stmt, JokerError> {
+        let body: Stmt = self.statement()?;
+        Ok(WhileStmt::upcast(
+            Literal::upcast(literal_bool(true)),
+            Box::new(body),
+            label,
+        ))
+    }
+    // doWhileStmt    → "do" statement "while" "(" expression ")" ";" ;
+    fn do_while_statement(&mut self, label: Option<Token>) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
+        let body: Stmt = self.statement()?;
+        self.consume(
+            &[TokenType::While],
+            String::from("Expect 'while' after do body."),
+        )?;
+        self.consume(
+            &[TokenType::LeftParen],
+            String::from("Expect '(' after 'while'."),
+        )?;
+        let condition: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::RightParen],
+            String::from("Expect ')' after while condition."),
+        )?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after do while statement."),
+        )?;
+        Ok(DoWhileStmt::upcast(
+            keyword,
+            Box::new(body),
+            condition,
+            label,
+        ))
     }
     // if stmt  -> "if" "(" expression ")" statement ( "else" statement )?
     fn if_statement(&mut self) -> Result<Stmt, JokerError> {
@@ -494,6 +1123,77 @@ impl Parser {
             else_branch,
         ))
     }
+    // throwStmt -> "throw" expression ";" ;
+    fn throw_statement(&mut self) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
+        let value: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::Semicolon],
+            String::from("Expect ';' after throw value."),
+        )?;
+        Ok(ThrowStmt::upcast(keyword, value))
+    }
+    // tryStmt -> "try" block ( "catch" "(" IDENTIFIER ( ":" type )? ")" block )? ( "finally" block )? ;
+    fn try_statement(&mut self) -> Result<Stmt, JokerError> {
+        let keyword: Token = self.previous();
+        self.consume(
+            &[TokenType::LeftBrace],
+            String::from("Expect '{' after 'try'."),
+        )?;
+        let try_branch: Stmt = self.block_statement()?;
+
+        let (catch_name, catch_type, catch_branch) = if self.is_match(&[TokenType::Catch]) {
+            self.consume(
+                &[TokenType::LeftParen],
+                String::from("Expect '(' after 'catch'."),
+            )?;
+            let name: Token = self.consume(
+                &[TokenType::Identifier],
+                String::from("Expect catch binding name."),
+            )?;
+            let type_: Option<Type> = if self.is_match(&[TokenType::Colon]) {
+                Some(TypeInferrer::parse_type(self)?)
+            } else {
+                None
+            };
+            self.consume(
+                &[TokenType::RightParen],
+                String::from("Expect ')' after catch binding."),
+            )?;
+            self.consume(
+                &[TokenType::LeftBrace],
+                String::from("Expect '{' before catch body."),
+            )?;
+            (Some(name), type_, Some(Box::new(self.block_statement()?)))
+        } else {
+            (None, None, None)
+        };
+
+        let finally_branch: Option<Box<Stmt>> = if self.is_match(&[TokenType::Finally]) {
+            self.consume(
+                &[TokenType::LeftBrace],
+                String::from("Expect '{' after 'finally'."),
+            )?;
+            Some(Box::new(self.block_statement()?))
+        } else {
+            None
+        };
+
+        if catch_branch.is_none() && finally_branch.is_none() {
+            return Err(JokerError::Parser(ParserError::report_error(
+                &keyword,
+                String::from("Expect 'catch' or 'finally' after try block."),
+            )));
+        }
+        Ok(TryStmt::upcast(
+            keyword,
+            Box::new(try_branch),
+            catch_name,
+            catch_type,
+            catch_branch,
+            finally_branch,
+        ))
+    }
     fn print_statement(&mut self) -> Result<Stmt, JokerError> {
         let expr: Expr = self.expression()?;
         self.consume(
@@ -528,6 +1228,7 @@ impl Parser {
         self.assignment()
     }
     // assignment  → (call ".")? IDENTIFIER "=" (assignment "=")*
+    //              | call "[" expression "]" "=" assignment
     //              | lambda ;
     fn assignment(&mut self) -> Result<Expr, JokerError> {
         let expr: Expr = self.lambda()?;
@@ -541,6 +1242,14 @@ impl Parser {
                 Expr::Getter(getter) => {
                     return Ok(Setter::upcast(getter.expr, getter.name, Box::new(value)));
                 }
+                Expr::Index(index) => {
+                    return Ok(IndexSet::upcast(
+                        index.l_expr,
+                        index.bracket,
+                        index.index,
+                        Box::new(value),
+                    ));
+                }
                 _ => {
                     return Err(JokerError::Parser(ParserError::report_error(
                         &equal,
@@ -549,6 +1258,33 @@ impl Parser {
                 }
             }
         }
+        if self.is_match(&[
+            TokenType::PlusEqual,
+            TokenType::MinusEqual,
+            TokenType::StarEqual,
+            TokenType::SlashEqual,
+            TokenType::PercentEqual,
+            TokenType::PlusPlus,
+            TokenType::MinusMinus,
+        ]) {
+            let compound: Token = self.previous();
+            // postfix `a++` step one of the target type, value is the old value.
+            let value: Option<Box<Expr>> = match compound.ttype {
+                TokenType::PlusPlus | TokenType::MinusMinus => None,
+                _ => Some(Box::new(self.assignment()?)),
+            };
+            return match expr {
+                Expr::Variable(_) | Expr::Getter(_) | Expr::Index(_) => Ok(AssignOp::upcast(
+                    Box::new(expr),
+                    compound.compound_operator().unwrap(),
+                    value,
+                )),
+                _ => Err(JokerError::Parser(ParserError::report_error(
+                    &compound,
+                    String::from("Invalid assignment target."),
+                ))),
+            };
+        }
         Ok(expr)
     }
     // lambda_expr -> "|" parameters? "|" ("->" IDENTIFIER)? statement "(" parameters? ")"
@@ -558,13 +1294,15 @@ impl Parser {
         if self.is_match(&[TokenType::Pipeline]) {
             let pipe: Token = self.previous();
 
+            let mut destructs: Vec<Stmt> = Vec::new();
             let params: Option<Vec<ParamPair>> = if self.check(&TokenType::Pipeline) {
                 None
             } else {
-                let mut params: Vec<ParamPair> = vec![ParamPair::normal_with_parse(self)?];
-                while self.is_match(&[TokenType::Comma]) {
-                    params.push(ParamPair::normal_with_parse(self)?);
-                }
+                // union param type need alias: |x: Id|, '|' close params.
+                self.pipe_params = true;
+                let params: Result<Vec<ParamPair>, JokerError> = self.lambda_params(&mut destructs);
+                self.pipe_params = false;
+                let params: Vec<ParamPair> = params?;
                 if params.len() >= 255 {
                     // TODO: warning
                     ArgLimitAbort::report_error(
@@ -591,7 +1329,7 @@ impl Parser {
                 ExprStmt::upcast(self.expression()?)
             };
 
-            let lambda: Expr = Lambda::upcast(pipe, params, return_type, Box::new(body));
+            let lambda: Expr = Lambda::upcast(pipe, params, return_type, Box::new(body), destructs);
             if self.is_match(&[TokenType::LeftParen]) {
                 return self.finish_call(lambda);
             } else {
@@ -600,10 +1338,105 @@ impl Parser {
         }
         self.trinomial()
     }
+    fn lambda_params(&mut self, destructs: &mut Vec<Stmt>) -> Result<Vec<ParamPair>, JokerError> {
+        let mut params: Vec<ParamPair> = vec![self.lambda_param(destructs)?];
+        while self.is_match(&[TokenType::Comma]) {
+            params.push(self.lambda_param(destructs)?);
+        }
+        Ok(params)
+    }
+    // fnParam        → "..." IDENTIFIER ":" "[" type "]"
+    //                | IDENTIFIER ":" type ( "=" expression )? ;
+    fn fn_param(&mut self) -> Result<ParamPair, JokerError> {
+        if self.is_match(&[TokenType::DotDotDot]) {
+            let param: ParamPair = ParamPair::normal_with_parse(self)?;
+            let name: Token = param.parse::<Token>()?;
+            return match param.get_type() {
+                type_ @ Type::List(_) => Ok(ParamPair::rest(name, type_.clone())),
+                type_ => Err(JokerError::Parser(ParserError::report_error(
+                    &name,
+                    format!("Rest parameter type need list '[T]', found '{}'.", type_),
+                ))),
+            };
+        }
+        let param: ParamPair = ParamPair::normal_with_parse(self)?;
+        if self.is_match(&[TokenType::Equal]) {
+            let value: Expr = self.expression()?;
+            return Ok(ParamPair::default(
+                param.parse::<Token>()?,
+                param.get_type().clone(),
+                value,
+            ));
+        }
+        Ok(param)
+    }
+    // default parameters after required parameters, rest parameter is last one.
+    fn check_fn_params(&self, params: &[ParamPair]) -> Result<(), JokerError> {
+        let mut has_default: bool = false;
+        for (index, param) in params.iter().enumerate() {
+            let error = |msg: String| -> Result<(), JokerError> {
+                Err(JokerError::Parser(ParserError::report_error(
+                    param.parse_ref::<Token>()?,
+                    msg,
+                )))
+            };
+            match param {
+                ParamPair::Rest { param, .. } if index + 1 != params.len() => {
+                    return error(format!(
+                        "Rest parameter '{}' must be the last parameter.",
+                        param.lexeme
+                    ));
+                }
+                ParamPair::Default { .. } => has_default = true,
+                ParamPair::Normal { param, .. } if has_default => {
+                    return error(format!(
+                        "Parameter '{}' without default can't follow default parameter.",
+                        param.lexeme
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+    // tuple param |(a, b): (i32, str)| bind whole tuple to hidden param '(a, b)',
+    // call destructure it before body: var (a, b) = (a, b);
+    fn lambda_param(&mut self, destructs: &mut Vec<Stmt>) -> Result<ParamPair, JokerError> {
+        if !self.is_match(&[TokenType::LeftParen]) {
+            return ParamPair::normal_with_parse(self);
+        }
+        let (paren, names) = self.destruct_names()?;
+        let param: Token = Token::new(
+            TokenType::Identifier,
+            format!(
+                "({})",
+                names
+                    .iter()
+                    .map(|name| name.lexeme.clone())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            literal_null(),
+            paren.line,
+        );
+        self.consume(
+            &[TokenType::Colon],
+            String::from("Expect ':' in parameter name after."),
+        )?;
+        let type_: Type = TypeInferrer::parse_type(self)?;
+        destructs.push(DestructStmt::upcast(
+            paren,
+            names,
+            false,
+            None,
+            Variable::upcast(param.clone()),
+        ));
+        Ok(ParamPair::normal(param, type_))
+    }
     // Trinomial    → expression "?" expression ":" expression ";"
-    //              | logic_or ;
+    //              | coalesce ;
     fn trinomial(&mut self) -> Result<Expr, JokerError> {
-        let mut expr: Expr = self.logic_or()?;
+        let mut expr: Expr = self.coalesce()?;
         if self.is_match(&[TokenType::Question]) && !self.is_at_end() {
             let question: Token = self.previous();
             let l_expr: Expr = self.expression()?;
@@ -619,6 +1452,16 @@ impl Parser {
         }
         Ok(expr)
     }
+    // coalesce   → logic_or ( "??" coalesce )?
+    fn coalesce(&mut self) -> Result<Expr, JokerError> {
+        let mut expr: Expr = self.logic_or()?;
+        if self.is_match(&[TokenType::QuestionQuestion]) {
+            let m_opera: Token = self.previous();
+            let r_expr: Expr = self.coalesce()?;
+            expr = Logical::upcast(Box::new(expr), m_opera, Box::new(r_expr));
+        }
+        Ok(expr)
+    }
     // logic_or   → logic_and ( "or" logic_and )*
     fn logic_or(&mut self) -> Result<Expr, JokerError> {
         let mut expr: Expr = self.logic_and()?;
@@ -649,15 +1492,55 @@ impl Parser {
         }
         Ok(expr)
     }
-    // comparison -> term ( ( ">" | ">=" | "<" | "<=") term )?;
+    // comparison -> bit_xor ( ( ">" | ">=" | "<" | "<=") bit_xor )?;
     fn comparison(&mut self) -> Result<Expr, JokerError> {
-        let mut expr: Expr = self.term()?;
+        let mut expr: Expr = self.range()?;
         while self.is_match(&[
             TokenType::Greater,
             TokenType::GreaterEqual,
             TokenType::Less,
             TokenType::LessEqual,
         ]) {
+            let m_opera: Token = self.previous();
+            let r_expr: Expr = self.range()?;
+            expr = Binary::upcast(Box::new(expr), m_opera, Box::new(r_expr));
+        }
+        Ok(expr)
+    }
+    // range -> bit_xor ( ( ".." | "..=" ) bit_xor )? ;
+    fn range(&mut self) -> Result<Expr, JokerError> {
+        let start: Expr = self.bit_xor()?;
+        if self.is_match(&[TokenType::DotDot, TokenType::DotDotEqual]) {
+            let opera: Token = self.previous();
+            let end: Expr = self.bit_xor()?;
+            return Ok(Range::upcast(Box::new(start), opera, Box::new(end)));
+        }
+        Ok(start)
+    }
+    // bit_xor -> bit_and ( "^" bit_and )* ;
+    fn bit_xor(&mut self) -> Result<Expr, JokerError> {
+        let mut expr: Expr = self.bit_and()?;
+        while self.is_match(&[TokenType::Caret]) {
+            let m_opera: Token = self.previous();
+            let r_expr: Expr = self.bit_and()?;
+            expr = Binary::upcast(Box::new(expr), m_opera, Box::new(r_expr));
+        }
+        Ok(expr)
+    }
+    // bit_and -> shift ( "&" shift )* ;
+    fn bit_and(&mut self) -> Result<Expr, JokerError> {
+        let mut expr: Expr = self.shift()?;
+        while self.is_match(&[TokenType::Amper]) {
+            let m_opera: Token = self.previous();
+            let r_expr: Expr = self.shift()?;
+            expr = Binary::upcast(Box::new(expr), m_opera, Box::new(r_expr));
+        }
+        Ok(expr)
+    }
+    // shift -> term ( ( "<<" | ">>" ) term )* ;
+    fn shift(&mut self) -> Result<Expr, JokerError> {
+        let mut expr: Expr = self.term()?;
+        while self.is_match(&[TokenType::LessLess, TokenType::GreaterGreater]) {
             let m_opera: Token = self.previous();
             let r_expr: Expr = self.term()?;
             expr = Binary::upcast(Box::new(expr), m_opera, Box::new(r_expr));
@@ -674,27 +1557,56 @@ impl Parser {
         }
         Ok(expr)
     }
-    // factor -> unary ( ( "/" | "*" ) unary )* ;
+    // factor -> cast ( ( "/" | "*" | "%" | "~/" ) cast )* ;
     fn factor(&mut self) -> Result<Expr, JokerError> {
-        let mut expr: Expr = self.unary()?;
-        while self.is_match(&[TokenType::Slash, TokenType::Star]) {
+        let mut expr: Expr = self.cast()?;
+        while self.is_match(&[
+            TokenType::Slash,
+            TokenType::Star,
+            TokenType::Percent,
+            TokenType::TildeSlash,
+        ]) {
             let m_opera: Token = self.previous();
-            let r_expr: Expr = self.unary()?;
+            let r_expr: Expr = self.cast()?;
             expr = Binary::upcast(Box::new(expr), m_opera, Box::new(r_expr));
         }
         Ok(expr)
     }
-    // unary -> ( "!" | "-" ) unary
-    //          | call  ;
+    // cast -> unary ( ( "as" | "is" ) type )* ;    -x as u8 is (-x) as u8.
+    fn cast(&mut self) -> Result<Expr, JokerError> {
+        let mut expr: Expr = self.unary()?;
+        while self.is_match(&[TokenType::As, TokenType::Is]) {
+            let keyword: Token = self.previous();
+            let target: Type = TypeInferrer::parse_type(self)?;
+            expr = if keyword.ttype == TokenType::As {
+                Cast::upcast(Box::new(expr), keyword, Box::new(target))
+            } else {
+                Is::upcast(Box::new(expr), keyword, Box::new(target))
+            };
+        }
+        Ok(expr)
+    }
+    // unary -> ( "!" | "-" | "~" ) unary
+    //          | power  ;
     fn unary(&mut self) -> Result<Expr, JokerError> {
-        if self.is_match(&[TokenType::Bang, TokenType::Minus]) {
+        if self.is_match(&[TokenType::Bang, TokenType::Minus, TokenType::Tilde]) {
             let l_opera: Token = self.previous();
             let r_expr: Expr = self.unary()?;
             return Ok(Unary::upcast(l_opera, Box::new(r_expr)));
         }
-        self.call()
+        self.power()
+    }
+    // power -> call ( "**" unary )? ;    right associative, -2 ** 2 is -(2 ** 2).
+    fn power(&mut self) -> Result<Expr, JokerError> {
+        let expr: Expr = self.call()?;
+        if self.is_match(&[TokenType::StarStar]) {
+            let m_opera: Token = self.previous();
+            let r_expr: Expr = self.unary()?;
+            return Ok(Binary::upcast(Box::new(expr), m_opera, Box::new(r_expr)));
+        }
+        Ok(expr)
     }
-    // call           → grouping ( "(" arguments? ")" | "." IDENTIFIER )* ;
+    // call           → grouping ( "(" arguments? ")" | ( "." | "?." ) IDENTIFIER | "[" expression "]" )* ;
     // arguments      → expression ( "," expression )* ;
     fn call(&mut self) -> Result<Expr, JokerError> {
         let mut expr: Expr = self.grouping()?;
@@ -707,6 +1619,19 @@ impl Parser {
                     String::from("expect attribute name after '.'."),
                 )?;
                 expr = Getter::upcast(Box::new(expr), name);
+            } else if self.is_match(&[TokenType::QuestionDot]) {
+                let name: Token = self.consume(
+                    &[TokenType::Identifier],
+                    String::from("expect attribute name after '?.'."),
+                )?;
+                expr = SafeGetter::upcast(Box::new(expr), name);
+            } else if self.is_match(&[TokenType::LeftBracket]) {
+                let index: Expr = self.expression()?;
+                let bracket: Token = self.consume(
+                    &[TokenType::RightBracket],
+                    String::from("Expect ']' after index."),
+                )?;
+                expr = Index::upcast(Box::new(expr), bracket, Box::new(index));
             } else {
                 break;
             }
@@ -715,30 +1640,271 @@ impl Parser {
     }
     fn finish_call(&mut self, callee: Expr) -> Result<Expr, JokerError> {
         let mut arguments: Vec<Expr> = Vec::new();
+        let mut named: Vec<(Token, Expr)> = Vec::new();
         if !self.check(&TokenType::RightParen) {
-            arguments.push(self.expression()?);
-            while self.is_match(&[TokenType::Comma]) {
-                if arguments.len() >= 255 {
+            loop {
+                if arguments.len() + named.len() >= 255 {
                     // waring:?
                     ArgLimitAbort::report_error(
                         &self.peek(),
                         String::from("Can't have more than 255 arguments."),
                     );
                 }
-                arguments.push(self.expression()?);
+                // named argument: IDENTIFIER ":" expression, after positional arguments.
+                if self.check(&TokenType::Identifier) && self.check_next(1, &TokenType::Colon) {
+                    let name: Token = self.advance();
+                    self.advance();
+                    named.push((name, self.expression()?));
+                } else if let Some((name, _)) = named.last() {
+                    return Err(JokerError::Parser(ParserError::report_error(
+                        name,
+                        String::from("Positional argument can't follow named argument."),
+                    )));
+                } else {
+                    arguments.push(self.expression()?);
+                }
+                if !self.is_match(&[TokenType::Comma]) {
+                    break;
+                }
             }
         }
         let paren = self.consume(
             &[TokenType::RightParen],
             String::from("Expect ')' after arguments."),
         )?;
-        Ok(Call::upcast(Box::new(callee), paren, arguments))
+        Ok(Call::upcast(Box::new(callee), paren, arguments, named))
+    }
+    fn finish_list(&mut self) -> Result<Expr, JokerError> {
+        let mut elements: Vec<Expr> = Vec::new();
+        if !self.check(&TokenType::RightBracket) {
+            elements.push(self.expression()?);
+            while self.is_match(&[TokenType::Comma]) {
+                elements.push(self.expression()?);
+            }
+        }
+        let bracket: Token = self.consume(
+            &[TokenType::RightBracket],
+            String::from("Expect ']' after list elements."),
+        )?;
+        Ok(List::upcast(bracket, elements))
+    }
+    // template -> Template ( STRING | "${" expression "}" )* TemplateEnd
+    fn finish_template(&mut self, token: Token) -> Result<Expr, JokerError> {
+        let mut parts: Vec<Expr> = Vec::new();
+        while !self.check(&TokenType::TemplateEnd) && !self.is_at_end() {
+            if self.is_match(&[TokenType::DollarLeftBrace]) {
+                parts.push(self.expression()?);
+                self.consume(
+                    &[TokenType::RightBrace],
+                    String::from("Expect '}' after string interpolation expression."),
+                )?;
+            } else {
+                let text: Token = self.consume(
+                    &[TokenType::Str],
+                    String::from("Expect string template text."),
+                )?;
+                parts.push(Literal::upcast(text.literal));
+            }
+        }
+        self.consume(
+            &[TokenType::TemplateEnd],
+            String::from("Expect '\"' after string template."),
+        )?;
+        Ok(Template::upcast(token, parts))
+    }
+    fn finish_map(&mut self) -> Result<Expr, JokerError> {
+        let mut pairs: Vec<(Expr, Expr)> = Vec::new();
+        if !self.check(&TokenType::RightBrace) {
+            loop {
+                let key: Expr = self.expression()?;
+                self.consume(
+                    &[TokenType::Colon],
+                    String::from("Expect ':' after map key."),
+                )?;
+                pairs.push((key, self.expression()?));
+                if !self.is_match(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        let brace: Token = self.consume(
+            &[TokenType::RightBrace],
+            String::from("Expect '}' after map pairs."),
+        )?;
+        Ok(Map::upcast(brace, pairs))
+    }
+    // struct init: IDENTIFIER "{" ( IDENTIFIER ":" expression ( "," IDENTIFIER ":" expression )* ","? )? "}"
+    fn is_struct_init(&self) -> bool {
+        self.check_next(1, &TokenType::LeftBrace)
+            && (self.check_next(2, &TokenType::RightBrace)
+                || (self.check_next(2, &TokenType::Identifier)
+                    && self.check_next(3, &TokenType::Colon)))
+    }
+    fn finish_struct_init(&mut self, name: Token) -> Result<Expr, JokerError> {
+        self.consume(
+            &[TokenType::LeftBrace],
+            String::from("Expect '{' after struct name."),
+        )?;
+        let mut fields: Vec<(Token, Expr)> = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let field: Token = self.consume(
+                &[TokenType::Identifier],
+                String::from("Expect struct field name."),
+            )?;
+            self.consume(
+                &[TokenType::Colon],
+                String::from("Expect ':' after struct field name."),
+            )?;
+            fields.push((field, self.expression()?));
+            if !self.is_match(&[TokenType::Comma]) {
+                break;
+            }
+        }
+        self.consume(
+            &[TokenType::RightBrace],
+            String::from("Expect '}' after struct fields."),
+        )?;
+        Ok(StructInit::upcast(name, fields))
+    }
+    // match -> "match" expression "{" ( arm ( "," arm )* ","? )? "}" ;
+    // arm   -> pattern "=>" expression ;
+    fn match_expr(&mut self) -> Result<Expr, JokerError> {
+        let keyword: Token = self.advance();
+        let value: Expr = self.expression()?;
+        self.consume(
+            &[TokenType::LeftBrace],
+            String::from("Expect '{' after match value."),
+        )?;
+        let mut arms: Vec<MatchArm> = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let pattern: Pattern = self.pattern()?;
+            self.consume(
+                &[TokenType::FatArrow],
+                String::from("Expect '=>' after match pattern."),
+            )?;
+            arms.push(MatchArm::new(pattern, self.expression()?));
+            if !self.is_match(&[TokenType::Comma]) {
+                break;
+            }
+        }
+        self.consume(
+            &[TokenType::RightBrace],
+            String::from("Expect '}' after match arms."),
+        )?;
+        Ok(Match::upcast(keyword, Box::new(value), arms))
+    }
+    // pattern -> "_"
+    //          | "-"? literal ( ( ".." | "..=" ) "-"? literal )?
+    //          | IDENTIFIER "." IDENTIFIER ( "(" IDENTIFIER ( "," IDENTIFIER )* ")" )?
+    //          | IDENTIFIER ( "(" IDENTIFIER ")" )? ;    class, struct or builtin type: i32(n)
+    fn pattern(&mut self) -> Result<Pattern, JokerError> {
+        if self.is_match(&[TokenType::Identifier]) {
+            let name: Token = self.previous();
+            if name.lexeme.eq("_") {
+                return Ok(Pattern::Wildcard { token: name });
+            }
+            if self.is_match(&[TokenType::Dot]) {
+                let variant: Token = self.consume(
+                    &[TokenType::Identifier],
+                    String::from("Expect variant name after '.' in enum pattern."),
+                )?;
+                let mut bindings: Vec<Token> = Vec::new();
+                if self.is_match(&[TokenType::LeftParen]) {
+                    loop {
+                        bindings.push(self.consume(
+                            &[TokenType::Identifier],
+                            String::from("Expect binding name in enum pattern."),
+                        )?);
+                        if !self.is_match(&[TokenType::Comma]) {
+                            break;
+                        }
+                    }
+                    self.consume(
+                        &[TokenType::RightParen],
+                        String::from("Expect ')' after enum pattern bindings."),
+                    )?;
+                }
+                return Ok(Pattern::Variant {
+                    name,
+                    variant,
+                    bindings,
+                });
+            }
+            let binding: Option<Token> = if self.is_match(&[TokenType::LeftParen]) {
+                let binding: Token = self.consume(
+                    &[TokenType::Identifier],
+                    String::from("Expect binding name in class pattern."),
+                )?;
+                self.consume(
+                    &[TokenType::RightParen],
+                    String::from("Expect ')' after class pattern binding."),
+                )?;
+                Some(binding)
+            } else {
+                None
+            };
+            return Ok(Pattern::Class { name, binding });
+        }
+        let token: Token = self.peek();
+        let start: OEnum = self.pattern_literal()?;
+        if self.is_match(&[TokenType::DotDot, TokenType::DotDotEqual]) {
+            let inclusive: bool = self.previous().ttype == TokenType::DotDotEqual;
+            let end: OEnum = self.pattern_literal()?;
+            return Ok(Pattern::Range {
+                token,
+                start,
+                end,
+                inclusive,
+            });
+        }
+        Ok(Pattern::Literal {
+            token,
+            value: start,
+        })
+    }
+    fn pattern_literal(&mut self) -> Result<OEnum, JokerError> {
+        let negative: bool = self.is_match(&[TokenType::Minus]);
+        let literal: Token = self.consume(
+            &[
+                TokenType::I32,
+                TokenType::I64,
+                TokenType::U8,
+                TokenType::F64,
+                TokenType::Str,
+                TokenType::True,
+                TokenType::False,
+                TokenType::Null,
+            ],
+            String::from("Expect literal pattern."),
+        )?;
+        match (negative, &literal.literal) {
+            (false, value) => Ok(value.clone()),
+            (true, OEnum::Literal(ObL::I32(i32_))) => Ok(literal_i32(-i32_)),
+            (true, OEnum::Literal(ObL::I64(i64_))) => Ok(literal_i64(-i64_)),
+            (true, OEnum::Literal(ObL::F64(f64_))) => Ok(literal_f64(-f64_)),
+            _ => Err(JokerError::Parser(ParserError::report_error(
+                &literal,
+                String::from("Expect number after '-' in pattern."),
+            ))),
+        }
     }
     // grouping -> "(" expression ")" ;
     //              | “super” "." IDENTIFIER
     fn grouping(&mut self) -> Result<Expr, JokerError> {
         if self.is_match(&[TokenType::LeftParen]) {
             let expr: Expr = self.expression()?;
+            // tuple: (expr, expr, ...)
+            if self.is_match(&[TokenType::Comma]) {
+                let mut elements: Vec<Expr> = vec![expr, self.expression()?];
+                while self.is_match(&[TokenType::Comma]) {
+                    elements.push(self.expression()?);
+                }
+                let paren: Token = self.consume(
+                    &[TokenType::RightParen],
+                    String::from("Expect ')' after tuple elements."),
+                )?;
+                return Ok(Tuple::upcast(paren, elements));
+            }
             self.consume(
                 &[TokenType::RightParen],
                 String::from("Expect ')' after expression."),
@@ -762,7 +1928,12 @@ impl Parser {
         self.primary()
     }
 
-    // primary -> I32| F64 | STRING | "true" | "false" | "null"
+    // primary -> match
+    //          | I32| F64 | STRING | "true" | "false" | "null"
+    //          | template
+    //          | "[" arguments? "]"
+    //          | "{" ( expression ":" expression ( "," expression ":" expression )* )? "}"
+    //          | IDENTIFIER "{" ( IDENTIFIER ":" expression ( "," IDENTIFIER ":" expression )* )? "}"
     //          | IDENTIFIER ;
     fn primary(&mut self) -> Result<Expr, JokerError> {
         if self.is_at_end() {
@@ -776,10 +1947,29 @@ impl Parser {
             TokenType::True => Ok(Literal::upcast(self.advance().literal)),
             TokenType::Null => Ok(Literal::upcast(self.advance().literal)),
             TokenType::I32 => Ok(Literal::upcast(self.advance().literal)),
+            TokenType::I64 => Ok(Literal::upcast(self.advance().literal)),
+            TokenType::U8 => Ok(Literal::upcast(self.advance().literal)),
             TokenType::F64 => Ok(Literal::upcast(self.advance().literal)),
             TokenType::Str => Ok(Literal::upcast(self.advance().literal)),
+            TokenType::Template => {
+                let token: Token = self.advance();
+                self.finish_template(token)
+            }
             TokenType::This => Ok(This::upcast(self.advance())),
+            TokenType::Identifier if self.is_struct_init() => {
+                let name: Token = self.advance();
+                self.finish_struct_init(name)
+            }
             TokenType::Identifier => Ok(Variable::upcast(self.advance())),
+            TokenType::LeftBracket => {
+                self.advance();
+                self.finish_list()
+            }
+            TokenType::LeftBrace => {
+                self.advance();
+                self.finish_map()
+            }
+            TokenType::Match => self.match_expr(),
             _ => Err(JokerError::Parser(ParserError::report_error(
                 &self.advance(),
                 String::from("parse not impl!"),
@@ -809,12 +1999,23 @@ impl Parser {
             }
             match self.peek().ttype {
                 TokenType::Class
+                | TokenType::Struct
+                | TokenType::Enum
+                | TokenType::Interface
                 | TokenType::Fn
                 | TokenType::Var
+                | TokenType::Const
                 | TokenType::For
                 | TokenType::If
                 | TokenType::While
+                | TokenType::Loop
+                | TokenType::Do
+                | TokenType::Yield
                 | TokenType::Print
+                | TokenType::Throw
+                | TokenType::Try
+                | TokenType::Import
+                | TokenType::Export
                 | TokenType::Return => return,
                 _ => {}
             }
@@ -6,26 +6,37 @@
 //!
 
 use std::{
-    cell::RefCell,
-    collections::{hash_map::Entry, HashMap},
+    cell::{Cell, RefCell},
+    collections::{hash_map::Entry, HashMap, HashSet},
     fmt::Display,
+    fs,
     hash::Hash,
+    path::PathBuf,
     rc::Rc,
 };
 
 use super::{
     ast::{
-        Assign, Binary, BlockStmt, BreakStmt, Call, ClassStmt, ContinueStmt, Expr, ExprAcceptor,
-        ExprStmt, ExprVisitor, FnStmt, ForStmt, Getter, Grouping, IfStmt, Lambda, Literal, Logical,
-        PrintStmt, ReturnStmt, Setter, Stmt, StmtAcceptor, StmtVisitor, Super, This, Trinomial,
-        Unary, VarStmt, Variable, WhileStmt,
+        Assign, AssignOp, Binary, BlockStmt, BreakStmt, Call, Cast, ClassStmt, ConstStmt,
+        ContinueStmt, DestructStmt, DoWhileStmt, EnumStmt, ExportStmt, Expr, ExprAcceptor,
+        ExprStmt, ExprVisitor, FnStmt, ForInStmt, ForStmt, Getter, Grouping, IfStmt, ImportStmt,
+        Index, IndexSet, InterfaceStmt, Is, Lambda, List, Literal, Logical, Map, Match, Pattern,
+        PrintStmt, Range, ReturnStmt, SafeGetter, Setter, Stmt, StmtAcceptor, StmtVisitor,
+        StructInit, StructStmt, Super, Template, This, ThrowStmt, Trinomial, TryStmt, Tuple,
+        TypeStmt, Unary, VarStmt, Variable, WhileStmt, YieldStmt,
     },
     callable::StructError,
-    env::EnvError,
+    env::{Capture, EnvError},
     error::{JokerError, ReportError},
-    interpreter::Interpreter,
+    interpreter::{Closure, Interpreter},
+    object::{
+        error_token, literal_bool, literal_f64, literal_i32, literal_i64, literal_null, literal_u8,
+        Module, UpCast,
+    },
+    parse::Parser,
+    scanner::Scanner,
     token::{Token, TokenType},
-    types::{IsInstance, ParamPair, Type, TypeEnv, TypeInferrer},
+    types::{IsInstance, Object, ParamPair, Type, TypeEnv, TypeInferrer},
 };
 
 pub trait StmtResolver<T> {
@@ -50,6 +61,7 @@ pub enum ContextStatus {
     Class(ClassStatus),
     Fn(ReturnType),
     Loop,
+    Label(String),
 }
 
 impl ContextStatus {
@@ -73,6 +85,7 @@ pub enum ClassStatus {
 pub enum ReturnType<T = Option<Box<Type>>> {
     Any,
     Specific(T),
+    Generator(Box<Type>),
 }
 
 impl PartialEq for ReturnType {
@@ -80,6 +93,8 @@ impl PartialEq for ReturnType {
         match (self, other) {
             (ReturnType::Any, _) | (_, ReturnType::Any) => true,
             (ReturnType::Specific(s1), ReturnType::Specific(s2)) => s1.eq(s2),
+            (ReturnType::Generator(g1), ReturnType::Generator(g2)) => g1.eq(g2),
+            _ => false,
         }
     }
 }
@@ -90,7 +105,17 @@ impl ReturnType {
     pub fn eq_type(&self, other: &Type) -> bool {
         match self {
             ReturnType::Any => true,
-            ReturnType::Specific(s) => s.as_ref().map_or(false, |t| t.eq_type(other)),
+            ReturnType::Specific(s) => s.as_ref().map_or(false, |t| t.is_assignable(other)),
+            ReturnType::Generator(_) => false,
+        }
+    }
+    // fn* body: yield type T, lambda and fn return Gen<T> not generator.
+    pub fn of(stmt: &FnStmt) -> ReturnType {
+        match stmt.return_type.as_deref() {
+            Some(Type::Generator(yield_type)) if stmt.generator => {
+                ReturnType::Generator(yield_type.clone())
+            }
+            _ => ReturnType::Specific(stmt.return_type.clone()),
         }
     }
 }
@@ -99,6 +124,7 @@ impl Display for ReturnType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ReturnType::Any => write!(f, "Any"),
+            ReturnType::Generator(yield_type) => write!(f, "Gen<{}>", yield_type),
             ReturnType::Specific(spec) => match spec {
                 Some(type_) => Display::fmt(type_, f),
                 None => write!(f, "None"),
@@ -137,20 +163,64 @@ pub enum VarStatus {
     Used,
 }
 
+// binding mutability, `var` is immutable, `var mut` is mutable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutability {
+    Immutable,
+    Mutable,
+    Captured, // `var mut` captured by closure, closure maybe assign it any time.
+}
+
+// local scope entry: variable status and binding mutability.
+type Scope = HashMap<Key, (VarStatus, Mutability)>;
+
+// late binding `var x: T;` assign state, follow the if else branches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Assigned {
+    No,
+    Maybe, // assigned in some branch only
+    Yes,
+}
+
+// late binding (scope index, name): assign state and loop depth of declaration.
+type LateInits = HashMap<(usize, String), (Assigned, usize)>;
+
 pub struct Resolver {
     interpreter: Rc<Interpreter>,
-    scopes_stack: RefCell<Vec<RefCell<HashMap<Key, VarStatus>>>>,
+    scopes_stack: RefCell<Vec<RefCell<Scope>>>,
+    consts: RefCell<HashMap<String, Object>>, // top level folded constant
+    immutable_statics: RefCell<HashSet<(String, String)>>, // (class, static) declared without mut
+    immutable_fields: RefCell<HashSet<(String, String)>>, // (class, field) declared without mut
+    in_init: Cell<bool>,                      // resolving class 'init' method body
+    generic_classes: RefCell<HashMap<String, Vec<Token>>>, // generic class type parameters
     context_status_stack: RefCell<Vec<ContextStatus>>,
+    capture_stack: RefCell<Vec<(usize, HashSet<Capture>)>>, // closure base scope, captured upvalues
+    late_inits: RefCell<LateInits>, // late binding definite assignment state
     pub type_env: RefCell<TypeEnv>,
+    module: usize, // module id of resolving source, main is 0.
 }
 
 impl Resolver {
     pub fn new(interpreter: Rc<Interpreter>) -> Resolver {
+        Resolver::new_module(interpreter, 0)
+    }
+    pub fn new_module(interpreter: Rc<Interpreter>, module: usize) -> Resolver {
+        let mut type_env: TypeEnv = TypeEnv::new_global();
+        // builtin error struct, infallible in global scope.
+        let _ = type_env.declare_type(&error_token(), Type::error_struct());
         Resolver {
             interpreter,
             scopes_stack: RefCell::new(Vec::new()),
+            consts: RefCell::new(HashMap::new()),
+            immutable_statics: RefCell::new(HashSet::new()),
+            immutable_fields: RefCell::new(HashSet::new()),
+            in_init: Cell::new(false),
+            generic_classes: RefCell::new(HashMap::new()),
             context_status_stack: RefCell::new(Vec::new()),
-            type_env: RefCell::new(TypeEnv::new_global()),
+            capture_stack: RefCell::new(Vec::new()),
+            late_inits: RefCell::new(HashMap::new()),
+            type_env: RefCell::new(type_env),
+            module,
         }
     }
     pub fn resolve(&self, stmts: &[Stmt]) -> Result<(), JokerError> {
@@ -166,8 +236,18 @@ impl Resolver {
     fn end_scope(&self) {
         self.type_env.borrow_mut().end_scope();
         self.scopes_stack.borrow_mut().pop();
+        let scopes: usize = self.scopes_stack.borrow().len();
+        self.late_inits
+            .borrow_mut()
+            .retain(|(index, _), _| *index < scopes);
     }
     fn declare(&self, name: &Token) -> Result<(), JokerError> {
+        self.declare_with(name, Mutability::Immutable)
+    }
+    fn declare_mut(&self, name: &Token) -> Result<(), JokerError> {
+        self.declare_with(name, Mutability::Mutable)
+    }
+    fn declare_with(&self, name: &Token, mutability: Mutability) -> Result<(), JokerError> {
         if let Some(scope) = self.scopes_stack.borrow().last() {
             match scope.borrow_mut().entry(Key(name.clone())) {
                 Entry::Occupied(_) => {
@@ -182,7 +262,7 @@ impl Resolver {
                     ))));
                 }
                 Entry::Vacant(entry) => {
-                    entry.insert(VarStatus::Declare);
+                    entry.insert((VarStatus::Declare, mutability));
                     return Ok(());
                 }
             }
@@ -193,7 +273,9 @@ impl Resolver {
         if let Some(scope) = self.scopes_stack.borrow().last() {
             scope
                 .borrow_mut()
-                .insert(Key(name.clone()), VarStatus::Define);
+                .entry(Key(name.clone()))
+                .and_modify(|(status, _)| *status = VarStatus::Define)
+                .or_insert((VarStatus::Define, Mutability::Immutable));
         }
         Ok(())
     }
@@ -201,7 +283,9 @@ impl Resolver {
         if let Some(scope) = self.scopes_stack.borrow().last() {
             scope
                 .borrow_mut()
-                .insert(Key(name.clone()), VarStatus::Used);
+                .entry(Key(name.clone()))
+                .and_modify(|(status, _)| *status = VarStatus::Used)
+                .or_insert((VarStatus::Used, Mutability::Immutable));
         }
         Ok(())
     }
@@ -219,18 +303,55 @@ impl Resolver {
         }
         Ok(())
     }
+    // closure scope base is params scope index, upvalue depth count from closure creation env.
+    fn begin_capture(&self) {
+        let base: usize = self.scopes_stack.borrow().len();
+        self.capture_stack.borrow_mut().push((base, HashSet::new()));
+    }
+    fn end_capture(&self, closure: Closure) {
+        if let Some((_, upvalues)) = self.capture_stack.borrow_mut().pop() {
+            self.interpreter
+                .capture(self.module, closure, upvalues.into_iter().collect());
+        }
+    }
+    // variable in scope index outside closure base is upvalue of every closure between.
+    fn capture(&self, index: usize, name: &str) -> bool {
+        let mut captured: bool = false;
+        for (base, upvalues) in self.capture_stack.borrow_mut().iter_mut() {
+            if index < *base {
+                upvalues.insert((*base - 1 - index, name.to_string()));
+                captured = true;
+            }
+        }
+        captured
+    }
     fn check_vars_status(&self) -> Result<(), JokerError> {
         match self.scopes_stack.borrow().last() {
-            Some(current_scope) => current_scope
-                .borrow()
-                .iter()
-                .try_for_each(|(name, value_status)| self.check_var_status(name, value_status)),
+            Some(current_scope) => {
+                current_scope
+                    .borrow()
+                    .iter()
+                    .try_for_each(|(name, (value_status, _))| {
+                        self.check_var_status(name, value_status)
+                    })
+            }
             None => Err(JokerError::Resolver(Error::Env(EnvError::report_error(
                 &Token::eof(0),
                 String::from("No current environment to check variables"),
             )))),
         }
     }
+    // var declaration place: fn, loop or class body.
+    fn in_block(&self) -> bool {
+        self.contains_any(&[
+            ContextStatus::Fn(ReturnType::Any),
+            ContextStatus::Loop,
+            ContextStatus::Class(ClassStatus::Class),
+            ContextStatus::Class(ClassStatus::SuperClass),
+            ContextStatus::Class(ClassStatus::Method(ReturnType::Any)),
+            ContextStatus::Class(ClassStatus::Fn(ReturnType::Any)),
+        ])
+    }
     fn contains_any(&self, items: &[ContextStatus]) -> bool {
         items
             .iter()
@@ -256,6 +377,65 @@ impl Resolver {
                 .any(|item| self.context_status_stack.borrow().last().eq(&Some(item)))
         }
     }
+    // labeled loop name visible: search enclosing loops until fn or lambda boundary.
+    fn check_label(&self, label: &Token) -> Result<(), JokerError> {
+        let stack = self.context_status_stack.borrow();
+        let mut crossed_fn: bool = false;
+        for status in stack.iter().rev() {
+            match status {
+                ContextStatus::Label(name) if name.eq(&label.lexeme) => {
+                    if crossed_fn {
+                        return Err(JokerError::Resolver(Error::KeyWord(KeyWordError::Pos(
+                            PosError::report_error(
+                                label,
+                                format!(
+                                    "Cannot use label '{}' across function boundary.",
+                                    label.lexeme
+                                ),
+                            ),
+                        ))));
+                    }
+                    return Ok(());
+                }
+                status if status.is_fn() => crossed_fn = true,
+                _ => {}
+            }
+        }
+        Err(JokerError::Resolver(Error::KeyWord(KeyWordError::Pos(
+            PosError::report_error(label, format!("Undefined loop label '{}'.", label.lexeme)),
+        ))))
+    }
+    // loop body in loop context, labeled loop push label first.
+    fn resolve_loop_body(&self, label: &Option<Token>, body: &Stmt) -> Result<(), JokerError> {
+        if let Some(label) = label {
+            let used: bool = self
+                .context_status_stack
+                .borrow()
+                .contains(&ContextStatus::Label(label.lexeme.clone()));
+            if used {
+                return Err(JokerError::Resolver(Error::KeyWord(KeyWordError::Pos(
+                    PosError::report_error(
+                        label,
+                        format!("Label '{}' already used by enclosing loop.", label.lexeme),
+                    ),
+                ))));
+            }
+            self.context_status_stack
+                .borrow_mut()
+                .push(ContextStatus::Label(label.lexeme.clone()));
+        }
+        self.context_status_stack
+            .borrow_mut()
+            .push(ContextStatus::Loop);
+
+        let result: Result<(), JokerError> = StmtResolver::resolve(self, body);
+
+        self.context_status_stack.borrow_mut().pop();
+        if label.is_some() {
+            self.context_status_stack.borrow_mut().pop();
+        }
+        result
+    }
     // wait type keyword
     // type name = expression;
     pub fn declare_type(&self, name: &Token, ty: Type) -> Result<(), JokerError> {
@@ -267,10 +447,843 @@ impl Resolver {
     pub fn assign_type(&self, name: &Token, ty: Type) -> Result<(), JokerError> {
         self.type_env.borrow_mut().assign_type(name, ty)
     }
+    // struct value type of variable or field chain, other None(not report error).
+    fn struct_type(&self, expr: &Expr) -> Option<Type> {
+        self.declared_type(expr).filter(|type_| type_.is_struct())
+    }
+    // inferred type, user defined name translate to declared type, other None(not report error).
+    fn inferred_type(&self, expr: &Expr) -> Option<Type> {
+        match TypeInferrer::infer_type(self, expr) {
+            Ok(Type::UserDefined(name)) => self.get_type(&name).ok(),
+            type_ => type_.ok(),
+        }
+    }
+    // inferred operand type is class instance, other false(not report error).
+    fn is_instance_operand(&self, expr: &Expr) -> bool {
+        match TypeInferrer::infer_type(self, expr) {
+            Ok(Type::UserDefined(name)) => self.get_type(&name).is_ok_and(|type_| type_.is_class()),
+            Ok(type_) => type_.is_instance(),
+            Err(_) => false,
+        }
+    }
+    // declared type of variable or struct field chain, other None(not report error).
+    fn declared_type(&self, expr: &Expr) -> Option<Type> {
+        let lookup = |name: &Token| -> Option<Type> {
+            self.type_env
+                .borrow()
+                .symbol
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(&name.lexeme).cloned())
+        };
+        let type_: Option<Type> = match expr {
+            Expr::Variable(Variable { name }) => lookup(name),
+            Expr::This(This { keyword }) => lookup(keyword),
+            Expr::Getter(Getter { expr, name }) => self
+                .struct_type(expr)
+                .and_then(|struct_type| struct_type.get_type(name).ok().flatten().cloned()),
+            _ => None,
+        };
+        match type_ {
+            Some(Type::UserDefined(name)) => lookup(&name),
+            type_ => type_,
+        }
+    }
+    // struct field declared type: user defined type need translate to real type.
+    fn struct_field_type(&self, type_: &Type) -> Result<Type, JokerError> {
+        match type_ {
+            Type::UserDefined(token) => self.get_type(token),
+            Type::List(element_type) => {
+                Ok(Type::List(Box::new(self.struct_field_type(element_type)?)))
+            }
+            Type::Map(key_type, value_type) => Ok(Type::Map(
+                key_type.clone(),
+                Box::new(self.struct_field_type(value_type)?),
+            )),
+            _ => Ok(type_.clone()),
+        }
+    }
+    // struct field value: same type or class instance.
+    fn is_struct_field_type(
+        &self,
+        field_type: &Type,
+        value_type: &Type,
+    ) -> Result<bool, JokerError> {
+        Ok(field_type.eq_type(value_type)
+            || (value_type.is_instance()
+                && field_type.is_class()
+                && IsInstance::is_instance(value_type, field_type)?))
+    }
+    // null check condition: `x != null` is (x, T, true), `x == null` is (x, T, false), x type is T?.
+    fn null_check(&self, condition: &Expr) -> Option<(Token, Type, bool)> {
+        let is_null = |expr: &Expr| matches!(expr, Expr::Literal(Literal { value }) if *value == literal_null());
+        if let Expr::Binary(Binary {
+            l_expr,
+            m_opera,
+            r_expr,
+        }) = condition
+        {
+            let name: &Token = match (l_expr.as_ref(), r_expr.as_ref()) {
+                (Expr::Variable(Variable { name }), other) if is_null(other) => name,
+                (other, Expr::Variable(Variable { name })) if is_null(other) => name,
+                _ => return None,
+            };
+            let not_null: bool = match m_opera.ttype {
+                TokenType::BangEqual => true,
+                TokenType::EqualEqual => false,
+                _ => return None,
+            };
+            if let Ok(Type::Nullable(type_)) = self.get_type(name) {
+                return Some((name.clone(), *type_, not_null));
+            }
+        }
+        None
+    }
+    // resolve with narrow variable type, narrow type only live in this scope.
+    // narrow type only for read, assign check declared type and drop narrow.
+    // mutable variable captured by closure not narrow.
+    fn resolve_narrow(
+        &self,
+        narrow: Option<(&Token, &Type)>,
+        resolve: impl FnOnce(&Resolver) -> Result<(), JokerError>,
+    ) -> Result<(), JokerError> {
+        match narrow.filter(|(name, _)| self.mutability(name) != Some(Mutability::Captured)) {
+            Some((name, type_)) => {
+                self.type_env.borrow_mut().begin_scope();
+                let narrowed: Result<(), JokerError> =
+                    self.type_env.borrow_mut().narrow_type(name, type_.clone());
+                let result: Result<(), JokerError> = narrowed.and_then(|_| resolve(self));
+                self.type_env.borrow_mut().end_scope();
+                result
+            }
+            None => resolve(self),
+        }
+    }
+    // declared nullable type: T? user defined T need declared, keep name same as return type.
+    // destructured names type: tuple type, one name each element.
+    fn destruct_types(
+        &self,
+        paren: &Token,
+        names: &[Token],
+        tuple_type: &Type,
+    ) -> Result<Vec<Type>, JokerError> {
+        match tuple_type.unalias() {
+            Type::Tuple(types) if types.len() == names.len() => Ok(types.clone()),
+            Type::Tuple(types) => Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    paren,
+                    format!(
+                        "Destructure {} names, but tuple '{}' has {} elements.",
+                        names.len(),
+                        tuple_type,
+                        types.len()
+                    ),
+                ),
+            ))),
+            other => Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    paren,
+                    format!("Can't destructure type '{}', need tuple.", other),
+                ),
+            ))),
+        }
+    }
+    // argument type match parameter type, class name parameter is class instance.
+    fn is_argument_match(&self, arg_type: &Type, type_: &Type) -> Result<bool, JokerError> {
+        let param_type: Type = if let Type::UserDefined(token) = type_ {
+            TypeInferrer::infer_type(
+                self,
+                &Expr::Variable(Variable {
+                    name: token.clone(),
+                }),
+            )?
+        } else {
+            self.nullable_type(type_)?
+        };
+        if param_type.is_nullable()
+            || param_type.is_union()
+            || arg_type.is_nullable()
+            || arg_type == &Type::Null
+        {
+            self.is_nullable_assignable(&param_type, arg_type)
+        } else {
+            Ok(arg_type.eq_type(&param_type) || Resolver::is_instance_type(arg_type, &param_type)?)
+        }
+    }
+    // default parameter value: resolve before parameter declare, earlier parameters visible.
+    fn resolve_default(&self, param_pair: &ParamPair) -> Result<(), JokerError> {
+        if let ParamPair::Default {
+            param,
+            type_,
+            value,
+        } = param_pair
+        {
+            ExprResolver::resolve(self, value)?;
+            let value_type: Type = TypeInferrer::infer_type(self, value)?;
+            if !self.is_argument_match(&value_type, type_)? {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        param,
+                        format!(
+                            "Default value type '{}' not match parameter '{}' type '{}'.",
+                            value_type, param.lexeme, type_
+                        ),
+                    ),
+                )));
+            }
+        }
+        Ok(())
+    }
+    // declared class name is instance of class, other keep.
+    fn instance_type(&self, type_: &Type) -> Result<Type, JokerError> {
+        if let Type::UserDefined(name) = type_ {
+            let class: Box<Type> = match self.get_type(name)? {
+                class @ Type::Class { .. } => Box::new(class),
+                Type::This(class) => class,
+                _ => return Ok(type_.clone()),
+            };
+            return Ok(Type::Instance {
+                class,
+                fields: None,
+                methods: None,
+            });
+        }
+        Ok(type_.clone())
+    }
+    // for in element type: range is i32, generator is yield type, list is element, map is (key, value),
+    // class instance is T of 'next' return T?, 'iter' give iterator first.
+    fn iter_element_type(&self, keyword: &Token, iterable: &Type) -> Result<Type, JokerError> {
+        let error = |msg: String| -> Result<Type, JokerError> {
+            Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(keyword, msg),
+            )))
+        };
+        match iterable.unalias() {
+            Type::Range => Ok(Type::I32),
+            Type::Generator(yield_type) => Ok(*yield_type.clone()),
+            Type::List(element_type) => Ok(*element_type.clone()),
+            Type::Map(key_type, value_type) => {
+                Ok(Type::Tuple(vec![*key_type.clone(), *value_type.clone()]))
+            }
+            Type::Instance { .. } | Type::This(_) => {
+                let iterator: Type = match iterable.get_method("iter") {
+                    Some(Type::Fn {
+                        params: _,
+                        return_type: Some(return_type),
+                    }) => match &**return_type {
+                        Type::Generator(yield_type) => return Ok(*yield_type.clone()),
+                        _ => self.instance_type(return_type)?,
+                    },
+                    Some(_) => {
+                        return error(String::from(
+                            "Iterable method 'iter' need return iterator instance.",
+                        ))
+                    }
+                    None => iterable.clone(),
+                };
+                match iterator.get_method("next") {
+                    Some(Type::Fn {
+                        params: _,
+                        return_type: Some(return_type),
+                    }) if return_type.is_nullable() => self.instance_type(return_type.non_null()),
+                    Some(_) => error(format!(
+                        "Iterator '{}' method 'next' need return nullable type 'T?', null is end.",
+                        iterator
+                    )),
+                    None => error(format!(
+                        "Type '{}' is not iterable, need method 'iter' or 'next'.",
+                        iterator
+                    )),
+                }
+            }
+            other => error(format!(
+                "Type '{}' is not iterable, need range, list, map or class instance.",
+                other
+            )),
+        }
+    }
+    fn nullable_type(&self, type_: &Type) -> Result<Type, JokerError> {
+        if let Type::Nullable(inner) = type_ {
+            self.struct_field_type(inner)?;
+        }
+        Ok(type_.clone())
+    }
+    // nullable or union declared type accept value: T, null, T?, member or class instance of T.
+    fn is_nullable_assignable(
+        &self,
+        declared_type: &Type,
+        value_type: &Type,
+    ) -> Result<bool, JokerError> {
+        if declared_type.is_assignable(value_type) {
+            return Ok(true);
+        }
+        if value_type.is_nullable() && !declared_type.is_nullable() {
+            return Ok(false);
+        }
+        // union member class accept instance.
+        if declared_type.is_union() {
+            for member in declared_type.members() {
+                if self.is_nullable_assignable(&member, value_type)? {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+        let declared_type: Type = self.struct_field_type(declared_type.non_null())?;
+        Resolver::is_instance_type(value_type.non_null(), &declared_type)
+    }
+    // list or map literal of declared union element: every element need union member.
+    // [i32 | str] accept [1, "a"], literal infer need same element type.
+    fn union_literal_type(
+        &self,
+        name: &Token,
+        declared_type: &Type,
+        expr: &Expr,
+    ) -> Result<Option<Type>, JokerError> {
+        let mismatch = |expected: &Type, found: &Type| {
+            JokerError::Resolver(Error::Struct(StructError::report_error(
+                name,
+                format!("Type mismatch: expected {}, found {}", expected, found),
+            )))
+        };
+        match (declared_type.unalias(), expr) {
+            (
+                Type::List(element_type),
+                Expr::List(List {
+                    bracket: _,
+                    elements,
+                }),
+            ) if element_type.is_union() => {
+                for element in elements {
+                    let value_type: Type = TypeInferrer::infer_type(self, element)?;
+                    if !self.is_nullable_assignable(element_type, &value_type)? {
+                        return Err(mismatch(element_type, &value_type));
+                    }
+                }
+                Ok(Some(declared_type.clone()))
+            }
+            (Type::Map(key_type, value_type), Expr::Map(Map { brace: _, pairs }))
+                if value_type.is_union() =>
+            {
+                for (key, value) in pairs {
+                    let found_key: Type = TypeInferrer::infer_type(self, key)?;
+                    if !key_type.eq_type(&found_key) {
+                        return Err(mismatch(key_type, &found_key));
+                    }
+                    let found_value: Type = TypeInferrer::infer_type(self, value)?;
+                    if !self.is_nullable_assignable(value_type, &found_value)? {
+                        return Err(mismatch(value_type, &found_value));
+                    }
+                }
+                Ok(Some(declared_type.clone()))
+            }
+            _ => Ok(None),
+        }
+    }
+    // type test condition: `x is T`, x type is union, (x, then branch type, else branch type).
+    fn type_check(&self, condition: &Expr) -> Option<(Token, Type, Type)> {
+        if let Expr::Is(Is {
+            expr,
+            keyword: _,
+            target,
+        }) = condition
+        {
+            if let Expr::Variable(Variable { name }) = expr.as_ref() {
+                let type_: Type = self.get_type(name).ok().filter(|type_| type_.is_union())?;
+                let then_type: Type = match target.as_ref() {
+                    // class or struct name is instance value type.
+                    Type::UserDefined(class) => TypeInferrer::infer_pattern_type(
+                        self,
+                        &Pattern::Class {
+                            name: class.clone(),
+                            binding: None,
+                        },
+                    )
+                    .ok()
+                    .flatten()?,
+                    target => target.clone(),
+                };
+                return Some((name.clone(), then_type, type_.exclude(target)));
+            }
+        }
+        None
+    }
+    // value is instance of declared class or interface, other type not instance.
+    fn is_instance_type(value_type: &Type, declared_type: &Type) -> Result<bool, JokerError> {
+        Ok(value_type.is_instance()
+            && (declared_type.is_class() || declared_type.is_interface())
+            && IsInstance::is_instance(value_type, declared_type)?)
+    }
+    // type parameters of generic class, other None.
+    pub fn type_params(&self, class: &Token) -> Option<Vec<Token>> {
+        self.generic_classes.borrow().get(&class.lexeme).cloned()
+    }
+    // generic class type arguments: Box<i32> bind T to i32, class need generic and same count.
+    pub fn type_arguments(
+        &self,
+        class: &Token,
+        args: &[Type],
+    ) -> Result<HashMap<String, Type>, JokerError> {
+        let Some(generics) = self.type_params(class) else {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    class,
+                    format!("Class '{}' is not generic.", class.lexeme),
+                ),
+            )));
+        };
+        if generics.len() != args.len() {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    class,
+                    format!(
+                        "Class '{}' expected {} type arguments, found {}.",
+                        class.lexeme,
+                        generics.len(),
+                        args.len()
+                    ),
+                ),
+            )));
+        }
+        Ok(generics
+            .iter()
+            .map(|generic| generic.lexeme.clone())
+            .zip(args.iter().cloned())
+            .collect())
+    }
+    // exported type: struct and enum user defined name translate to real type, importer don't know it.
+    fn export_type(&self, type_: &Type) -> Result<Type, JokerError> {
+        match type_ {
+            Type::Fn {
+                params,
+                return_type,
+            } => Ok(Type::Fn {
+                params: match params {
+                    Some(params) => Some(
+                        params
+                            .iter()
+                            .map(|pair| -> Result<ParamPair, JokerError> {
+                                Ok(match pair {
+                                    ParamPair::This { param, type_ } => {
+                                        ParamPair::this(param.clone(), type_.clone())
+                                    }
+                                    ParamPair::Normal { param, type_ } => {
+                                        ParamPair::normal(param.clone(), self.export_type(type_)?)
+                                    }
+                                    ParamPair::Label { type_ } => {
+                                        ParamPair::label(self.export_type(type_)?)
+                                    }
+                                    ParamPair::Default {
+                                        param,
+                                        type_,
+                                        value,
+                                    } => ParamPair::default(
+                                        param.clone(),
+                                        self.export_type(type_)?,
+                                        value.clone(),
+                                    ),
+                                    ParamPair::Rest { param, type_ } => {
+                                        ParamPair::rest(param.clone(), self.export_type(type_)?)
+                                    }
+                                })
+                            })
+                            .collect::<Result<Vec<ParamPair>, JokerError>>()?,
+                    ),
+                    None => None,
+                },
+                return_type: match return_type {
+                    Some(return_type) => Some(Box::new(self.export_type(return_type)?)),
+                    None => None,
+                },
+            }),
+            Type::UserDefined(name) => match self.get_type(name)? {
+                real_type if real_type.is_struct() || real_type.is_enum() => Ok(real_type),
+                _ => Ok(type_.clone()),
+            },
+            Type::List(element_type) => Ok(Type::List(Box::new(self.export_type(element_type)?))),
+            Type::Map(key_type, value_type) => Ok(Type::Map(
+                key_type.clone(),
+                Box::new(self.export_type(value_type)?),
+            )),
+            _ => Ok(type_.clone()),
+        }
+    }
+    // load module once: scan, parse, resolve and run in self module env, then cache exports.
+    fn import_module(&self, stmt: &ImportStmt) -> Result<Type, JokerError> {
+        let path: PathBuf = self.interpreter.module_path(&stmt.path)?;
+        if let Some((_, module_type)) = self.interpreter.get_module(&path) {
+            return Ok(module_type);
+        }
+        if let Some(cycle) = self.interpreter.module_cycle(&path) {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &stmt.path,
+                    format!(
+                        "Import cycle detected: {}.",
+                        cycle
+                            .iter()
+                            .chain([&path])
+                            .map(|path| path.display().to_string())
+                            .collect::<Vec<String>>()
+                            .join(" -> ")
+                    ),
+                ),
+            )));
+        }
+        let source: String = fs::read_to_string(&path).map_err(|err| {
+            JokerError::Resolver(Error::Struct(StructError::report_error(
+                &stmt.path,
+                format!("Could not read module '{}': {}.", path.display(), err),
+            )))
+        })?;
+        let stmts: Vec<Stmt> = Parser::new(Scanner::new(source).scan_tokens()?).parse()?;
+
+        let (module, global) = self.interpreter.new_module();
+        let resolver: Resolver = Resolver::new_module(Rc::clone(&self.interpreter), module);
+        self.interpreter.push_module_path(path.clone());
+        let result: Result<(), JokerError> = resolver.resolve(&stmts).and_then(|_| {
+            self.interpreter
+                .interpreter_module(&stmts, Rc::clone(&global))
+        });
+        self.interpreter.pop_module_path();
+        result?;
+
+        let mut exports: Vec<(String, Object)> = Vec::new();
+        let mut export_types: Vec<(String, Type)> = Vec::new();
+        for stmt in &stmts {
+            if let Stmt::ExportStmt(export) = stmt {
+                let name: &Token = export.name();
+                let value: Object = match global.borrow().get(name)? {
+                    Some(value) => value,
+                    None => Object::new(literal_null()),
+                };
+                exports.push((name.lexeme.clone(), value));
+                export_types.push((
+                    name.lexeme.clone(),
+                    resolver.export_type(&resolver.get_type(name)?)?,
+                ));
+            }
+        }
+        let module_type: Type = Type::Module {
+            name: stmt.path.clone(),
+            exports: export_types,
+            module,
+        };
+        self.interpreter.cache_module(
+            path,
+            Object::new(Module::new(stmt.path.clone(), exports).upcast_into()),
+            module_type.clone(),
+        );
+        Ok(module_type)
+    }
+    // class impl interface: every interface method exist in class or super class with same signature.
+    fn check_interfaces(&self, stmt: &ClassStmt, class_type: &Type) -> Result<(), JokerError> {
+        for interface in &stmt.interfaces {
+            let methods: Vec<(String, Type)> = match self.get_type(interface)? {
+                Type::Interface {
+                    name: _,
+                    methods,
+                    module: _,
+                } => methods,
+                other => {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            interface,
+                            format!(
+                                "Class '{}' can only impl interface, found '{}'.",
+                                stmt.name.lexeme, other
+                            ),
+                        ),
+                    )))
+                }
+            };
+            for (method, method_type) in &methods {
+                match class_type.get_method(method) {
+                    Some(type_) if type_.is_impl_method(method_type) => {}
+                    Some(type_) => {
+                        return Err(JokerError::Resolver(Error::Struct(
+                            StructError::report_error(
+                                interface,
+                                format!(
+                                    "Class '{}' method '{}' type '{}' not match interface '{}' type '{}'.",
+                                    stmt.name.lexeme, method, type_, interface.lexeme, method_type
+                                ),
+                            ),
+                        )))
+                    }
+                    None => {
+                        return Err(JokerError::Resolver(Error::Struct(
+                            StructError::report_error(
+                                interface,
+                                format!(
+                                    "Class '{}' missing interface '{}' method '{}'.",
+                                    stmt.name.lexeme, interface.lexeme, method
+                                ),
+                            ),
+                        )))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    // local binding mutability, global is none.
+    fn mutability(&self, name: &Token) -> Option<Mutability> {
+        self.scopes_stack.borrow().iter().rev().find_map(|scope| {
+            scope
+                .borrow()
+                .get(&Key(name.clone()))
+                .map(|(_, mutability)| *mutability)
+        })
+    }
+    // enclosing loop count, body of them run many times.
+    fn loop_depth(&self) -> usize {
+        self.context_status_stack
+            .borrow()
+            .iter()
+            .filter(|context| **context == ContextStatus::Loop)
+            .count()
+    }
+    // late binding `var x: T;` declared in current scope, assign it once later.
+    fn declare_late(&self, name: &Token) {
+        if let Some(index) = self.scopes_stack.borrow().len().checked_sub(1) {
+            self.late_inits.borrow_mut().insert(
+                (index, name.lexeme.clone()),
+                (Assigned::No, self.loop_depth()),
+            );
+        }
+    }
+    // innermost scope index declare the name.
+    fn scope_index(&self, name: &Token) -> Option<usize> {
+        let key: Key = Key(name.clone());
+        self.scopes_stack
+            .borrow()
+            .iter()
+            .rposition(|scope| scope.borrow().contains_key(&key))
+    }
+    // late binding of innermost declaration: assign state and loop depth of declaration.
+    fn late_init(&self, name: &Token) -> Option<(Assigned, usize)> {
+        let index: usize = self.scope_index(name)?;
+        self.late_inits
+            .borrow()
+            .get(&(index, name.lexeme.clone()))
+            .copied()
+    }
+    // late binding declared outside innermost closure or nested fn, it may call many times.
+    fn is_outer_late(&self, name: &Token) -> bool {
+        match (self.scope_index(name), self.capture_stack.borrow().last()) {
+            (Some(index), Some((base, _))) => index < *base,
+            _ => false,
+        }
+    }
+    fn init_late(&self, name: &Token) {
+        if let Some(index) = self.scope_index(name) {
+            if let Some((assigned, _)) = self
+                .late_inits
+                .borrow_mut()
+                .get_mut(&(index, name.lexeme.clone()))
+            {
+                *assigned = Assigned::Yes;
+            }
+        }
+    }
+    // if else merge: assigned in both branch is assigned, in one branch is maybe assigned.
+    // branch end with return, throw, break or continue not reach after the if.
+    fn merge_late(then_inits: Option<LateInits>, else_inits: Option<LateInits>) -> LateInits {
+        match (then_inits, else_inits) {
+            (Some(then_inits), Some(else_inits)) => then_inits
+                .into_iter()
+                .map(|(key, (assigned, depth))| {
+                    let merged: Assigned = match else_inits.get(&key) {
+                        Some((other, _)) if *other == assigned => assigned,
+                        _ => Assigned::Maybe,
+                    };
+                    (key, (merged, depth))
+                })
+                .collect(),
+            (Some(inits), None) | (None, Some(inits)) => inits,
+            (None, None) => HashMap::new(),
+        }
+    }
+    // statement never complete normally: jump out at end.
+    fn is_diverge(stmt: &Stmt) -> bool {
+        match stmt {
+            Stmt::ReturnStmt(_)
+            | Stmt::ThrowStmt(_)
+            | Stmt::BreakStmt(_)
+            | Stmt::ContinueStmt(_) => true,
+            Stmt::BlockStmt(BlockStmt { stmts }) => stmts.last().is_some_and(Resolver::is_diverge),
+            Stmt::IfStmt(IfStmt {
+                then_branch,
+                else_branch: Some(else_branch),
+                ..
+            }) => Resolver::is_diverge(then_branch) && Resolver::is_diverge(else_branch),
+            _ => false,
+        }
+    }
+    // immutable binding already have value: local `var` or top level `const`.
+    // late binding assign once, not in loop body run many times.
+    fn is_immutable(&self, name: &Token) -> bool {
+        if let Some((assigned, depth)) = self.late_init(name) {
+            return assigned != Assigned::No
+                || depth < self.loop_depth()
+                || self.is_outer_late(name);
+        }
+        for scope in self.scopes_stack.borrow().iter().rev() {
+            if let Some((status, mutability)) = scope.borrow().get(&Key(name.clone())) {
+                return *mutability == Mutability::Immutable && *status != VarStatus::Declare;
+            }
+        }
+        self.consts.borrow().contains_key(&name.lexeme)
+    }
+    // assign: name = value, field setter: name.field = value
+    fn check_mutable(&self, name: &Token, field: Option<&Token>) -> Result<(), JokerError> {
+        if !self.is_immutable(name) {
+            return Ok(());
+        }
+        let msg: String = match field {
+            Some(field) => format!(
+                "Can't assign to field '{}' of immutable '{}', declare it with 'var mut'.",
+                field.lexeme, name.lexeme
+            ),
+            None if self.consts.borrow().contains_key(&name.lexeme) => {
+                format!("Can't assign to constant '{}'.", name.lexeme)
+            }
+            None if self.late_init(name).is_some() && self.is_outer_late(name) => {
+                format!(
+                    "Can't assign outer immutable variable '{}' inside a closure, declare it with 'var mut'.",
+                    name.lexeme
+                )
+            }
+            None if self
+                .late_init(name)
+                .is_some_and(|(assigned, _)| assigned == Assigned::No) =>
+            {
+                format!(
+                    "Can't assign immutable variable '{}' inside a loop, declare it with 'var mut'.",
+                    name.lexeme
+                )
+            }
+            None if self
+                .late_init(name)
+                .is_some_and(|(assigned, _)| assigned == Assigned::Maybe) =>
+            {
+                format!(
+                    "Immutable variable '{}' may already be assigned, declare it with 'var mut'.",
+                    name.lexeme
+                )
+            }
+            None => format!(
+                "Can't assign twice to immutable variable '{}', declare it with 'var mut'.",
+                name.lexeme
+            ),
+        };
+        Err(JokerError::Resolver(Error::Var(VarError::Immutable(
+            ImmutableError::report_error(field.unwrap_or(name), msg),
+        ))))
+    }
+    // struct field setter place: a.b.c = value => mutable binding a, or mut class field b.
+    // list and map element struct set in place like index set, no binding check.
+    fn check_struct_place(&self, place: &Expr, field: &Token) -> Result<(), JokerError> {
+        match place {
+            Expr::Variable(Variable { name }) => self.check_mutable(name, Some(field)),
+            Expr::Getter(Getter { expr, name }) if self.is_instance_operand(expr) => {
+                self.check_field_mutable(expr, name)
+            }
+            Expr::Getter(Getter { expr, name: _ }) => self.check_struct_place(expr, field),
+            _ => Ok(()),
+        }
+    }
+    // class field declared without mut: only 'init' method set it by 'this'.
+    fn check_field_mutable(&self, object: &Expr, name: &Token) -> Result<(), JokerError> {
+        let immutable_field: Option<String> = self
+            .inferred_type(object)
+            .and_then(|object_type| {
+                object_type
+                    .get_field(&name.lexeme)
+                    .map(|(class, _)| class.lexeme.clone())
+            })
+            .filter(|class| {
+                self.immutable_fields
+                    .borrow()
+                    .contains(&(class.clone(), name.lexeme.clone()))
+            });
+        match immutable_field {
+            Some(class) if !(self.in_init.get() && matches!(object, Expr::This(_))) => {
+                Err(JokerError::Resolver(Error::Var(VarError::Immutable(
+                    ImmutableError::report_error(
+                        name,
+                        format!(
+                            "Can't assign to immutable field '{}.{}' outside 'init', declare it with 'var mut'.",
+                            class, name.lexeme
+                        ),
+                    ),
+                ))))
+            }
+            _ => Ok(()),
+        }
+    }
+    // const value: literal, constant and operator of them, constant replace by folded value.
+    fn fold_const(&self, name: &Token, expr: &Expr) -> Result<Expr, JokerError> {
+        match expr {
+            Expr::Literal(_) => Ok(expr.clone()),
+            Expr::Variable(Variable { name: var_name }) => {
+                match self.consts.borrow().get(&var_name.lexeme) {
+                    Some(value) => Ok(Literal::upcast(value.get().clone())),
+                    None => Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            var_name,
+                            format!(
+                                "Constant '{}' value need constant, '{}' is not constant.",
+                                name.lexeme, var_name.lexeme
+                            ),
+                        ),
+                    ))),
+                }
+            }
+            Expr::Grouping(Grouping { expr }) => {
+                Ok(Grouping::upcast(Box::new(self.fold_const(name, expr)?)))
+            }
+            Expr::Unary(Unary { l_opera, r_expr }) => Ok(Unary::upcast(
+                l_opera.clone(),
+                Box::new(self.fold_const(name, r_expr)?),
+            )),
+            Expr::Binary(Binary {
+                l_expr,
+                m_opera,
+                r_expr,
+            }) => Ok(Binary::upcast(
+                Box::new(self.fold_const(name, l_expr)?),
+                m_opera.clone(),
+                Box::new(self.fold_const(name, r_expr)?),
+            )),
+            Expr::Logical(Logical {
+                l_expr,
+                m_opera,
+                r_expr,
+            }) => Ok(Logical::upcast(
+                Box::new(self.fold_const(name, l_expr)?),
+                m_opera.clone(),
+                Box::new(self.fold_const(name, r_expr)?),
+            )),
+            _ => Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    name,
+                    format!(
+                        "Constant '{}' value need compile-time constant expression.",
+                        name.lexeme
+                    ),
+                ),
+            ))),
+        }
+    }
+    // innermost fn return type, loop and label inside fn body skip.
     pub fn last_fn_return_type(&self) -> Option<ReturnType> {
         self.context_status_stack
             .borrow()
-            .last()
+            .iter()
+            .rev()
+            .find(|context| !matches!(context, ContextStatus::Loop | ContextStatus::Label(_)))
             .and_then(|context| match context {
                 ContextStatus::Fn(v)
                 | ContextStatus::Class(ClassStatus::Fn(v))
@@ -278,6 +1291,13 @@ impl Resolver {
                 _ => None,
             })
     }
+    // innermost fn is generator: yield type T of 'fn* -> T'.
+    fn generator_yield_type(&self) -> Option<Type> {
+        match self.last_fn_return_type() {
+            Some(ReturnType::Generator(yield_type)) => Some(*yield_type),
+            _ => None,
+        }
+    }
 }
 
 // Resolver
@@ -298,23 +1318,23 @@ impl StmtResolver<()> for Resolver {
         ]) {
             self.context_status_stack
                 .borrow_mut()
-                .push(ContextStatus::Class(ClassStatus::Fn(ReturnType::Specific(
-                    stmt.return_type.clone(),
-                ))));
+                .push(ContextStatus::Class(ClassStatus::Fn(ReturnType::of(stmt))));
         } else {
             self.context_status_stack
                 .borrow_mut()
-                .push(ContextStatus::Fn(ReturnType::Specific(
-                    stmt.return_type.clone(),
-                )));
+                .push(ContextStatus::Fn(ReturnType::of(stmt)));
         }
 
         self.begin_scope();
         if let Some(tokens) = stmt.params.as_ref() {
-            for param in tokens {
-                if let ParamPair::Normal { param, type_ } = param {
+            for param_pair in tokens {
+                if let ParamPair::Normal { param, type_ }
+                | ParamPair::Default { param, type_, .. }
+                | ParamPair::Rest { param, type_ } = param_pair
+                {
+                    self.resolve_default(param_pair)?;
                     // value check
-                    self.declare(param)?;
+                    self.declare_mut(param)?;
                     self.define(param)?;
                     // type check
                     let type_: Type = if let Type::UserDefined(token) = type_ {
@@ -325,7 +1345,7 @@ impl StmtResolver<()> for Resolver {
                             }),
                         )?
                     } else {
-                        type_.clone()
+                        self.nullable_type(type_)?
                     };
                     self.declare_type(param, type_)?;
                 } else {
@@ -391,11 +1411,28 @@ impl StmtResolver<()> for Resolver {
             ContextStatus::Class(ClassStatus::Class)
         });
 
+        // static value evaluate in class outside env, live once on class.
+        if let Some(stmts) = stmt.statics.as_ref() {
+            for stmt_ in stmts {
+                if let Stmt::VarStmt(var_stmt) = stmt_ {
+                    if let Some(value) = var_stmt.value.as_ref() {
+                        ExprResolver::resolve(self, value)?;
+                    }
+                    if !var_stmt.mutable {
+                        self.immutable_statics
+                            .borrow_mut()
+                            .insert((stmt.name.lexeme.clone(), var_stmt.name.lexeme.clone()));
+                    }
+                }
+            }
+        }
+
         // this(instance env)
         self.begin_scope();
         // type check
         // used This && class name do Type name.
         let class_type: Type = TypeInferrer::infer_class_stmt(self, stmt)?;
+        self.check_interfaces(stmt, &class_type)?;
         self.declare_type(&stmt.name, Type::This(Box::new(class_type)))?;
 
         // TODO: add This Type ?
@@ -405,8 +1442,15 @@ impl StmtResolver<()> for Resolver {
         // )?;
 
         if let Some(stmts) = stmt.fields.as_ref() {
-            for stmt in stmts {
-                StmtResolver::resolve(self, stmt)?;
+            for stmt_ in stmts {
+                StmtResolver::resolve(self, stmt_)?;
+                if let Stmt::VarStmt(var_stmt) = stmt_ {
+                    if !var_stmt.mutable {
+                        self.immutable_fields
+                            .borrow_mut()
+                            .insert((stmt.name.lexeme.clone(), var_stmt.name.lexeme.clone()));
+                    }
+                }
             }
         }
         if let Some(stmts) = stmt.methods.as_ref() {
@@ -454,9 +1498,9 @@ impl StmtResolver<()> for Resolver {
         ]) {
             self.context_status_stack
                 .borrow_mut()
-                .push(ContextStatus::Class(ClassStatus::Method(
-                    ReturnType::Specific(stmt.return_type.clone()),
-                )));
+                .push(ContextStatus::Class(ClassStatus::Method(ReturnType::of(
+                    stmt,
+                ))));
 
             self.begin_scope();
             if let Some(params) = stmt.params.as_ref() {
@@ -473,19 +1517,27 @@ impl StmtResolver<()> for Resolver {
                         ),
                     )));
                 }
-                for param in params[1..].iter() {
-                    if let ParamPair::Normal { param, type_ } = param {
+                for param_pair in params[1..].iter() {
+                    if let ParamPair::Normal { param, type_ }
+                    | ParamPair::Default { param, type_, .. }
+                    | ParamPair::Rest { param, type_ } = param_pair
+                    {
+                        self.resolve_default(param_pair)?;
                         // value check
-                        self.declare(param)?;
+                        self.declare_mut(param)?;
                         self.define(param)?;
                         // type check
                         let type_: Type = if let Type::UserDefined(token) = type_ {
-                            TypeInferrer::infer_type(
+                            match TypeInferrer::infer_type(
                                 self,
                                 &Expr::Variable(Variable {
                                     name: token.clone(),
                                 }),
-                            )?
+                            )? {
+                                // self class parameter is instance, same as 'this'.
+                                Type::This(_) => self.get_type(&Token::this(param.line))?,
+                                type_ => type_,
+                            }
                         } else {
                             type_.clone()
                         };
@@ -500,7 +1552,9 @@ impl StmtResolver<()> for Resolver {
                     }
                 }
             }
+            let outer_init: bool = self.in_init.replace(stmt.name.lexeme.eq("init"));
             StmtResolver::resolve_block(self, &stmt.body)?;
+            self.in_init.set(outer_init);
 
             // check local var used status
             self.check_vars_status()?;
@@ -519,29 +1573,57 @@ impl StmtResolver<()> for Resolver {
     // var b: declared_type = caller.value;
     // var c: declared_type = caller.callable();
     fn resolve_var(&self, stmt: &VarStmt) -> Result<(), JokerError> {
+        let union_literal: Option<Type> = match (stmt.type_.as_ref(), stmt.value.as_ref()) {
+            (Some(declared_type), Some(expr)) => {
+                self.union_literal_type(&stmt.name, declared_type, expr)?
+            }
+            _ => None,
+        };
         let value_type: Option<Type> = if let Some(expr) = stmt.value.as_ref() {
-            if let Expr::Call(call) = expr {
+            if union_literal.is_some() {
+                union_literal
+            } else if let Expr::Call(call) = expr {
                 let type_: Type = TypeInferrer::infer_type(self, &call.callee)?;
-                match type_ {
-                    // function return type
-                    Type::Fn {
-                        params: _,
-                        return_type,
-                    } => return_type.map(|return_type| *return_type),
-                    // class return instance base, dynamic (methods && fields => {None})
-                    Type::Class {
-                        name: _,
-                        super_class: _,
-                        fields: _,
-                        methods: _,
-                        functions: _,
-                    } => Some(Type::Instance {
-                        class: Box::new(type_),
-                        methods: None,
-                        fields: None,
-                    }),
-                    _ => Some(type_),
+                // generic fn and class: instantiate type parameters by arguments and declared type.
+                if let Some(type_) = TypeInferrer::generic_call_type(
+                    self,
+                    &type_,
+                    &call.arguments,
+                    &call.paren,
+                    stmt.type_.as_ref(),
+                )? {
+                    Some(type_)
+                } else {
+                    match type_ {
+                        // function return type
+                        Type::Fn {
+                            params: _,
+                            return_type,
+                        } => return_type.map(|return_type| *return_type),
+                        // class return instance base, dynamic (methods && fields => {None})
+                        Type::Class {
+                            name: _,
+                            super_class: _,
+                            fields: _,
+                            methods: _,
+                            functions: _,
+                            statics: _,
+                            interfaces: _,
+                        } => Some(Type::Instance {
+                            class: Box::new(type_),
+                            methods: None,
+                            fields: None,
+                        }),
+                        // a?.m(): nullable return type
+                        Type::Nullable(_) => Some(TypeInferrer::infer_type(self, expr)?),
+                        _ => Some(type_),
+                    }
                 }
+            } else if matches!(expr, Expr::List(List { bracket: _, elements }) if elements.is_empty())
+                || matches!(expr, Expr::Map(Map { brace: _, pairs }) if pairs.is_empty())
+            {
+                // empty list or map: element type from declared type.
+                None
             } else {
                 Some(TypeInferrer::infer_type(self, expr)?)
             }
@@ -568,6 +1650,32 @@ impl StmtResolver<()> for Resolver {
                     }
                 }
                 Some(declared_type.clone())
+            } else if let Type::List(element_type) = declared_type {
+                if let Type::UserDefined(token) = element_type.as_ref() {
+                    **element_type = self.get_type(token)?;
+                }
+                Some(declared_type.clone())
+            } else if let Type::Map(key_type, value_type) = declared_type {
+                if !key_type.is_map_key() {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            &stmt.name,
+                            format!(
+                                "Map key type need i32, f64, str or bool, found '{}'.",
+                                key_type
+                            ),
+                        ),
+                    )));
+                }
+                if let Type::UserDefined(token) = value_type.as_ref() {
+                    **value_type = self.get_type(token)?;
+                }
+                Some(declared_type.clone())
+            } else if declared_type.is_nullable() {
+                Some(self.nullable_type(declared_type)?)
+            } else if let Type::Generic { name, args } = declared_type {
+                self.type_arguments(name, args)?;
+                Some(declared_type.clone())
             } else {
                 Some(declared_type.clone())
             }
@@ -577,9 +1685,17 @@ impl StmtResolver<()> for Resolver {
 
         if let Some(value_type) = value_type {
             if let Some(declared_type) = declared_type {
-                if !declared_type.eq_type(&value_type)
-                    && !IsInstance::is_instance(&value_type, &declared_type)?
+                let is_match: bool = if declared_type.is_nullable()
+                    || declared_type.is_union()
+                    || value_type.is_nullable()
+                    || value_type == Type::Null
                 {
+                    self.is_nullable_assignable(&declared_type, &value_type)?
+                } else {
+                    declared_type.eq_type(&value_type)
+                        || Resolver::is_instance_type(&value_type, &declared_type)?
+                };
+                if !is_match {
                     return Err(JokerError::Resolver(Error::Struct(
                         StructError::report_error(
                             &stmt.name,
@@ -590,6 +1706,11 @@ impl StmtResolver<()> for Resolver {
                         ),
                     )));
                 }
+                // nullable and union variable keep declared type, it can assign other member later.
+                if declared_type.is_nullable() || declared_type.is_union() {
+                    self.declare_type(&stmt.name, declared_type)?;
+                    return Ok(());
+                }
             }
             self.declare_type(&stmt.name, value_type)?;
             return Ok(());
@@ -610,12 +1731,24 @@ impl ExprResolver<()> for Resolver {
     // getter: expr.name
     // setter| assign: name = expr
     fn resolve_local(&self, expr: Expr, name: &Token) -> Result<(), JokerError> {
+        let scopes: usize = self.scopes_stack.borrow().len();
         for (layer, scope) in self.scopes_stack.borrow().iter().rev().enumerate() {
             if let Entry::Occupied(mut entry) = scope.borrow_mut().entry(Key(name.clone())) {
-                self.interpreter.resolve(expr, layer);
-                match entry.get() {
-                    VarStatus::Declare => entry.insert(VarStatus::Define),
-                    VarStatus::Define | VarStatus::Used => entry.insert(VarStatus::Used),
+                self.interpreter.resolve(self.module, expr, layer);
+                let captured: bool = self.capture(scopes - 1 - layer, &name.lexeme);
+                // super method bind this, this scope is inner of super scope.
+                if name.ttype == TokenType::Super {
+                    self.capture(scopes - layer, "this");
+                }
+                let (status, mutability) = entry.get_mut();
+                // closure maybe assign captured mutable variable, narrow type not safe.
+                if captured && *mutability != Mutability::Immutable {
+                    *mutability = Mutability::Captured;
+                    self.type_env.borrow_mut().drop_narrow(name);
+                }
+                *status = match status {
+                    VarStatus::Declare => VarStatus::Define,
+                    VarStatus::Define | VarStatus::Used => VarStatus::Used,
                 };
                 return Ok(());
             }
@@ -629,12 +1762,13 @@ impl ExprResolver<()> for Resolver {
                 expr.return_type.clone(),
             )));
 
+        self.begin_capture();
         self.begin_scope();
         if let Some(tokens) = expr.params.as_ref() {
             for param in tokens {
                 if let ParamPair::Normal { param, type_ } = param {
                     // value check
-                    self.declare(param)?;
+                    self.declare_mut(param)?;
                     self.define(param)?;
                     // type check
                     let type_: Type = if let Type::UserDefined(token) = type_ {
@@ -645,7 +1779,7 @@ impl ExprResolver<()> for Resolver {
                             }),
                         )?
                     } else {
-                        type_.clone()
+                        self.nullable_type(type_)?
                     };
                     self.declare_type(param, type_)?;
                 } else {
@@ -658,11 +1792,16 @@ impl ExprResolver<()> for Resolver {
                 }
             }
         }
+        // tuple params: declare destructured names beside params.
+        for stmt in &expr.destructs {
+            StmtResolver::resolve(self, stmt)?;
+        }
         StmtResolver::resolve_lambda(self, &expr.pipe, &expr.body)?;
 
         // check local var used status
         self.check_vars_status()?;
         self.end_scope();
+        self.end_capture(Closure::Lambda(expr.clone()));
 
         self.context_status_stack.borrow_mut().pop();
         Ok(())
@@ -671,107 +1810,82 @@ impl ExprResolver<()> for Resolver {
         ExprResolver::resolve(self, &expr.callee)?;
         expr.arguments
             .iter()
+            .chain(expr.named.iter().map(|(_, arg)| arg))
             .try_for_each(|arg| ExprResolver::resolve(self, arg))?;
 
-        let callee_type: Type = TypeInferrer::infer_type(self, &expr.callee)?;
-        match callee_type {
+        let callee_type: Type = match TypeInferrer::infer_type(self, &expr.callee)? {
+            // a?.m(): null object skip call, method check same as a.m().
+            Type::Nullable(callee_type) if matches!(*expr.callee, Expr::SafeGetter(_)) => {
+                *callee_type
+            }
+            callee_type => callee_type,
+        };
+        // native method bind positional arguments only, parameter name not part of signature.
+        if let (
+            Some((name, _)),
+            Expr::Getter(Getter {
+                expr: object,
+                name: method,
+            })
+            | Expr::SafeGetter(SafeGetter {
+                expr: object,
+                name: method,
+            }),
+        ) = (expr.named.first(), &*expr.callee)
+        {
+            if TypeInferrer::infer_type(self, object)?
+                .non_null()
+                .is_native()
+            {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        name,
+                        format!(
+                            "Native method '{}' can't take named argument '{}'.",
+                            method.lexeme, name.lexeme
+                        ),
+                    ),
+                )));
+            }
+        }
+        // generic fn: type parameters bind by arguments, arguments check with bound types.
+        let callee_type: Type = match &callee_type {
+            Type::Fn {
+                params: Some(params),
+                return_type: _,
+            } if callee_type.contains_param() => {
+                let params: &[ParamPair] = match params.first() {
+                    Some(param) if param.is_this() => &params[1..],
+                    _ => params,
+                };
+                let bounds: HashMap<String, Type> =
+                    TypeInferrer::instantiate(self, params, &expr.arguments, &expr.paren)?;
+                callee_type.substitute(&bounds)
+            }
+            _ => callee_type,
+        };
+        match &callee_type {
             Type::Fn {
                 params,
                 return_type: _,
             } => {
-                if params.as_ref().map_or(false, |p| {
-                    if p[0].is_this() {
-                        p.len() - 1 != expr.arguments.len()
-                    } else {
-                        p.len() != expr.arguments.len()
-                    }
-                }) {
-                    return Err(JokerError::Resolver(Error::Struct(
-                        StructError::report_error(
-                            &expr.paren,
-                            format!(
-                                "Expected {} arguments but got {}.",
-                                params.as_ref().unwrap().len(),
-                                expr.arguments.len()
+                let params: &[ParamPair] = match params.as_deref() {
+                    Some([this, params @ ..]) if this.is_this() => params,
+                    Some(params) => params,
+                    None => &[],
+                };
+                for (arg, type_) in TypeInferrer::bind_arguments(params, expr)? {
+                    let arg_type: Type = TypeInferrer::infer_type(self, arg)?;
+                    if !self.is_argument_match(&arg_type, &type_)? {
+                        return Err(JokerError::Resolver(Error::Struct(
+                            StructError::report_error(
+                                &expr.paren,
+                                format!(
+                                    "Expected argument of type '{}' but got '{}'.",
+                                    type_, arg_type
+                                ),
                             ),
-                        ),
-                    )));
-                }
-
-                if let Some(param_types) = params {
-                    if param_types[0].is_this() {
-                        for (arg, param_pair) in expr.arguments.iter().zip(param_types[1..].iter())
-                        {
-                            if let ParamPair::Normal { param: _, type_ } = param_pair {
-                                let arg_type: Type = TypeInferrer::infer_type(self, arg)?;
-                                let param_type: Type = if let Type::UserDefined(token) = type_ {
-                                    TypeInferrer::infer_type(
-                                        self,
-                                        &Expr::Variable(Variable {
-                                            name: token.clone(),
-                                        }),
-                                    )?
-                                } else {
-                                    type_.clone()
-                                };
-                                if !arg_type.eq_type(&param_type)
-                                    && !IsInstance::is_instance(&arg_type, &param_type)?
-                                {
-                                    return Err(JokerError::Resolver(Error::Struct(
-                                        StructError::report_error(
-                                            &expr.paren,
-                                            format!(
-                                                "Expected argument of type '{}' but got '{}'.",
-                                                type_, arg_type
-                                            ),
-                                        ),
-                                    )));
-                                }
-                            } else {
-                                return Err(JokerError::Resolver(Error::Struct(
-                                    StructError::report_error(
-                                        &expr.paren,
-                                        String::from("Invalid parameter type in method call."),
-                                    ),
-                                )));
-                            }
-                        }
-                    } else {
-                        for (arg, param_pair) in expr.arguments.iter().zip(param_types.iter()) {
-                            if let ParamPair::Normal { param: _, type_ } = param_pair {
-                                let arg_type: Type = TypeInferrer::infer_type(self, arg)?;
-                                let param_type: Type = if let Type::UserDefined(token) = type_ {
-                                    TypeInferrer::infer_type(
-                                        self,
-                                        &Expr::Variable(Variable {
-                                            name: token.clone(),
-                                        }),
-                                    )?
-                                } else {
-                                    type_.clone()
-                                };
-                                if !arg_type.eq_type(&param_type)
-                                    && !IsInstance::is_instance(&arg_type, &param_type)?
-                                {
-                                    return Err(JokerError::Resolver(Error::Struct(
-                                        StructError::report_error(
-                                            &expr.paren,
-                                            format!(
-                                                "Expected argument of type '{}' but got '{}'.",
-                                                type_, arg_type
-                                            ),
-                                        ),
-                                    )));
-                                }
-                            } else {
-                                return Err(JokerError::Resolver(Error::Struct(
-                                    StructError::report_error(
-                                        &expr.paren,
-                                        String::from("Invalid parameter type in function call."),
-                                    ),
-                                )));
-                            }
-                        }
+                        )));
                     }
                 }
                 Ok(())
@@ -782,10 +1896,28 @@ impl ExprResolver<()> for Resolver {
                 fields: _,
                 methods: _,
                 functions: _,
+                statics: _,
+                interfaces: _,
             } => {
-                let _class_type: Type = self.get_type(&name)?;
+                // generic class: init arguments bind type parameters same.
+                if self.type_params(name).is_some() {
+                    if let Some(Type::Fn {
+                        params: Some(params),
+                        return_type: _,
+                    }) = callee_type.get_method("init")
+                    {
+                        TypeInferrer::instantiate(
+                            self,
+                            &params[1..],
+                            &expr.arguments,
+                            &expr.paren,
+                        )?;
+                    }
+                }
                 Ok(())
             }
+            // class name call inside self class body.
+            Type::This(_) => Ok(()),
             _ => Err(JokerError::Resolver(Error::Struct(
                 StructError::report_error(
                     &expr.paren,
@@ -800,27 +1932,57 @@ impl ExprResolver<()> for Resolver {
 impl StmtVisitor<()> for Resolver {
     fn visit_if(&self, stmt: &IfStmt) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &stmt.condition)?;
-        StmtResolver::resolve(self, &stmt.then_branch)?;
+        // null check narrow: `x != null` then branch and `x == null` else branch x is non null.
+        // type test narrow: `x is T` then branch x is T, else branch x is other union member.
+        let null_check: Option<(Token, Type, bool)> = self.null_check(&stmt.condition);
+        let type_check: Option<(Token, Type, Type)> = self.type_check(&stmt.condition);
+        let narrow = |not_null: bool| {
+            null_check
+                .as_ref()
+                .filter(|(_, _, is_not_null)| *is_not_null == not_null)
+                .map(|(name, type_, _)| (name, type_))
+                .or_else(|| {
+                    type_check.as_ref().map(|(name, then_type, else_type)| {
+                        (name, if not_null { then_type } else { else_type })
+                    })
+                })
+        };
+        // late binding definite assignment: every branch start from state before if.
+        let before: LateInits = self.late_inits.borrow().clone();
+        self.resolve_narrow(narrow(true), |resolver| {
+            StmtResolver::resolve(resolver, &stmt.then_branch)
+        })?;
+        let then_inits: LateInits = self.late_inits.replace(before);
         if let Some(else_branch) = stmt.else_branch.as_ref() {
-            StmtResolver::resolve(self, else_branch)?;
+            self.resolve_narrow(narrow(false), |resolver| {
+                StmtResolver::resolve(resolver, else_branch)
+            })?;
         }
+        let else_inits: LateInits = self.late_inits.take();
+        let else_diverge: bool = stmt
+            .else_branch
+            .as_ref()
+            .is_some_and(|else_branch| Resolver::is_diverge(else_branch));
+        self.late_inits.replace(Resolver::merge_late(
+            (!Resolver::is_diverge(&stmt.then_branch)).then_some(then_inits),
+            (!else_diverge).then_some(else_inits),
+        ));
         Ok(())
     }
     fn visit_var(&self, stmt: &VarStmt) -> Result<(), JokerError> {
-        if self.contains_any(&[
-            ContextStatus::Fn(ReturnType::Any),
-            ContextStatus::Loop,
-            ContextStatus::Class(ClassStatus::Class),
-            ContextStatus::Class(ClassStatus::SuperClass),
-            ContextStatus::Class(ClassStatus::Method(ReturnType::Any)),
-            ContextStatus::Class(ClassStatus::Fn(ReturnType::Any)),
-        ]) {
+        if self.in_block() {
             StmtResolver::resolve_var(self, stmt)?;
 
-            self.declare(&stmt.name)?;
+            if stmt.mutable {
+                self.declare_mut(&stmt.name)?;
+            } else {
+                self.declare(&stmt.name)?;
+            }
             if let Some(expr) = stmt.value.as_ref() {
                 ExprResolver::resolve(self, expr)?;
                 self.define(&stmt.name)?;
+            } else if !stmt.mutable {
+                self.declare_late(&stmt.name);
             }
             Ok(())
         } else {
@@ -831,6 +1993,8 @@ impl StmtVisitor<()> for Resolver {
         }
     }
     fn visit_for(&self, stmt: &ForStmt) -> Result<(), JokerError> {
+        // initializer variable live in for scope, not leak to enclosing block.
+        self.begin_scope();
         if let Some(initializer) = stmt.initializer.as_ref() {
             StmtResolver::resolve(self, initializer)?;
         }
@@ -839,20 +2003,43 @@ impl StmtVisitor<()> for Resolver {
             ExprResolver::resolve(self, increment)?;
         }
 
-        self.context_status_stack
-            .borrow_mut()
-            .push(ContextStatus::Loop);
+        self.resolve_loop_body(&stmt.label, &stmt.body)?;
+
+        self.check_vars_status()?;
+        self.end_scope();
+        Ok(())
+    }
+    fn visit_for_in(&self, stmt: &ForInStmt) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &stmt.iterable)?;
+        let iterable_type: Type = TypeInferrer::infer_type(self, &stmt.iterable)?;
+        let element_type: Type = self.iter_element_type(&stmt.keyword, &iterable_type)?;
+        let types: Vec<Type> = if stmt.destruct {
+            self.destruct_types(&stmt.keyword, &stmt.names, &element_type)?
+        } else {
+            vec![element_type]
+        };
 
-        StmtResolver::resolve(self, &stmt.body)?;
+        // loop variable live in every iteration scope, '_' skip.
+        self.begin_scope();
+        for (name, type_) in stmt.names.iter().zip(types) {
+            if name.lexeme.eq("_") {
+                continue;
+            }
+            self.declare_type(name, type_)?;
+            self.declare(name)?;
+            self.define(name)?;
+        }
 
-        self.context_status_stack.borrow_mut().pop();
+        self.resolve_loop_body(&stmt.label, &stmt.body)?;
 
+        self.check_vars_status()?;
+        self.end_scope();
         Ok(())
     }
     fn visit_fn(&self, stmt: &FnStmt) -> Result<(), JokerError> {
         self.declare(&stmt.name)?;
         self.define(&stmt.name)?;
-        StmtResolver::resolve_function(self, stmt)?;
+        // fn type declare before body, local recursive fn can call itself.
         self.declare_type(
             &stmt.name,
             Type::Fn {
@@ -860,15 +2047,305 @@ impl StmtVisitor<()> for Resolver {
                 return_type: stmt.return_type.clone(),
             },
         )?;
+        self.begin_capture();
+        StmtResolver::resolve_function(self, stmt)?;
+        self.end_capture(Closure::Fn(stmt.clone()));
         Ok(())
     }
     fn visit_class(&self, stmt: &ClassStmt) -> Result<(), JokerError> {
         self.declare(&stmt.name)?;
         self.define(&stmt.name)?;
+        if !stmt.generics.is_empty() {
+            self.generic_classes
+                .borrow_mut()
+                .insert(stmt.name.lexeme.clone(), stmt.generics.clone());
+        }
         StmtResolver::resolve_class(self, stmt)?;
         self.declare_type(&stmt.name, TypeInferrer::infer_class_stmt(self, stmt)?)?;
         Ok(())
     }
+    fn visit_struct(&self, stmt: &StructStmt) -> Result<(), JokerError> {
+        // value check
+        self.declare(&stmt.name)?;
+        self.define(&stmt.name)?;
+        // type check
+        let mut fields: Vec<(String, Type)> = Vec::with_capacity(stmt.fields.len());
+        for (field, type_) in &stmt.fields {
+            if fields.iter().any(|(name, _)| name.eq(&field.lexeme)) {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        field,
+                        format!(
+                            "Struct '{}' field '{}' is already declared.",
+                            stmt.name.lexeme, field.lexeme
+                        ),
+                    ),
+                )));
+            }
+            fields.push((field.lexeme.clone(), self.struct_field_type(type_)?));
+        }
+        self.declare_type(
+            &stmt.name,
+            Type::Struct {
+                name: stmt.name.clone(),
+                fields,
+                module: self.module,
+            },
+        )?;
+        Ok(())
+    }
+    fn visit_enum(&self, stmt: &EnumStmt) -> Result<(), JokerError> {
+        // value check
+        self.declare(&stmt.name)?;
+        self.define(&stmt.name)?;
+        // type check: recursive payload keep user defined type.
+        let mut variants: Vec<(String, Vec<Type>)> = Vec::with_capacity(stmt.variants.len());
+        for (variant, payload) in &stmt.variants {
+            if variants.iter().any(|(name, _)| name.eq(&variant.lexeme)) {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        variant,
+                        format!(
+                            "Enum '{}' variant '{}' is already declared.",
+                            stmt.name.lexeme, variant.lexeme
+                        ),
+                    ),
+                )));
+            }
+            let mut payload_types: Vec<Type> = Vec::with_capacity(payload.len());
+            for type_ in payload {
+                payload_types.push(match type_ {
+                    Type::UserDefined(name) if name.lexeme.eq(&stmt.name.lexeme) => type_.clone(),
+                    _ => self.struct_field_type(type_)?,
+                });
+            }
+            variants.push((variant.lexeme.clone(), payload_types));
+        }
+        self.declare_type(
+            &stmt.name,
+            Type::Enum {
+                name: stmt.name.clone(),
+                variants,
+                module: self.module,
+            },
+        )?;
+        Ok(())
+    }
+    fn visit_throw(&self, stmt: &ThrowStmt) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &stmt.value)?;
+        TypeInferrer::infer_type(self, &stmt.value)?;
+        Ok(())
+    }
+    fn visit_try(&self, stmt: &TryStmt) -> Result<(), JokerError> {
+        // generator frame can't keep try handler between resume.
+        if stmt.try_branch.has_yield()
+            || stmt
+                .catch_branch
+                .as_ref()
+                .is_some_and(|branch| branch.has_yield())
+            || stmt
+                .finally_branch
+                .as_ref()
+                .is_some_and(|branch| branch.has_yield())
+        {
+            return Err(JokerError::Resolver(Error::KeyWord(KeyWordError::Pos(
+                PosError::report_error(
+                    &stmt.keyword,
+                    String::from("Cannot use 'yield' inside 'try' statement."),
+                ),
+            ))));
+        }
+        StmtResolver::resolve(self, &stmt.try_branch)?;
+        // catch binding in catch scope, default type is builtin error struct.
+        if let (Some(name), Some(catch_branch)) = (&stmt.catch_name, &stmt.catch_branch) {
+            let catch_type: Type = match &stmt.catch_type {
+                Some(type_) => self.struct_field_type(type_)?,
+                None => Type::error_struct(),
+            };
+            self.begin_scope();
+            self.declare(name)?;
+            self.define(name)?;
+            self.declare_type(name, catch_type)?;
+            StmtResolver::resolve(self, catch_branch)?;
+            self.check_vars_status()?;
+            self.end_scope();
+        }
+        if let Some(finally_branch) = &stmt.finally_branch {
+            StmtResolver::resolve(self, finally_branch)?;
+        }
+        Ok(())
+    }
+    fn visit_import(&self, stmt: &ImportStmt) -> Result<(), JokerError> {
+        if !self.scopes_stack.borrow().is_empty() {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &stmt.keyword,
+                    String::from("Import only at module top level."),
+                ),
+            )));
+        }
+        let module_type: Type = self.import_module(stmt)?;
+        self.declare(&stmt.name)?;
+        self.define(&stmt.name)?;
+        // exported type visible only qualified by module name: alias.Type
+        if let Type::Module {
+            name: _,
+            exports,
+            module: _,
+        } = &module_type
+        {
+            for (_, export_type) in exports {
+                match export_type {
+                    Type::Class { name, .. }
+                    | Type::Struct { name, .. }
+                    | Type::Enum { name, .. } => {
+                        let qualified: Token = Token::new(
+                            TokenType::Identifier,
+                            format!("{}.{}", stmt.name.lexeme, name.lexeme),
+                            literal_null(),
+                            name.line,
+                        );
+                        self.declare_type(&qualified, export_type.clone())?
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.declare_type(&stmt.name, module_type)?;
+        Ok(())
+    }
+    fn visit_export(&self, stmt: &ExportStmt) -> Result<(), JokerError> {
+        if !self.scopes_stack.borrow().is_empty() {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &stmt.keyword,
+                    String::from("Export only at module top level."),
+                ),
+            )));
+        }
+        StmtResolver::resolve(self, &stmt.stmt)
+    }
+    fn visit_const(&self, stmt: &ConstStmt) -> Result<(), JokerError> {
+        if !self.scopes_stack.borrow().is_empty() {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &stmt.name,
+                    String::from("Const only at module top level."),
+                ),
+            )));
+        }
+        if self.consts.borrow().contains_key(&stmt.name.lexeme) {
+            return Err(JokerError::Resolver(Error::Var(VarError::Redefine(
+                RedefineError::report_error(
+                    &stmt.name,
+                    format!("Constant '{}' is already declared.", stmt.name.lexeme),
+                ),
+            ))));
+        }
+        // fold value at compile time, type check before evaluate.
+        let folded: Expr = self.fold_const(&stmt.name, &stmt.value)?;
+        let value_type: Type = TypeInferrer::infer_type(self, &folded)?;
+        if let Some(declared_type) = stmt.type_.as_ref() {
+            if !declared_type.eq_type(&value_type) {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        &stmt.name,
+                        format!(
+                            "Type mismatch: expected {}, found {}",
+                            declared_type, value_type
+                        ),
+                    ),
+                )));
+            }
+        }
+        let value: Object = match self.interpreter.evaluate(&folded)? {
+            Some(value) => value,
+            None => Object::new(literal_null()),
+        };
+        self.interpreter
+            .fold(self.module, stmt.value.clone(), value.clone());
+        self.consts
+            .borrow_mut()
+            .insert(stmt.name.lexeme.clone(), value);
+        self.declare_type(&stmt.name, value_type)?;
+        Ok(())
+    }
+    fn visit_interface(&self, stmt: &InterfaceStmt) -> Result<(), JokerError> {
+        // interface only is type, don't have value.
+        let mut methods: Vec<(String, Type)> = Vec::with_capacity(stmt.methods.len());
+        for (method, type_) in &stmt.methods {
+            if methods.iter().any(|(name, _)| name.eq(&method.lexeme)) {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        method,
+                        format!(
+                            "Interface '{}' method '{}' is already declared.",
+                            stmt.name.lexeme, method.lexeme
+                        ),
+                    ),
+                )));
+            }
+            methods.push((method.lexeme.clone(), type_.clone()));
+        }
+        self.declare_type(
+            &stmt.name,
+            Type::Interface {
+                name: stmt.name.clone(),
+                methods,
+                module: self.module,
+            },
+        )?;
+        Ok(())
+    }
+    fn visit_type(&self, stmt: &TypeStmt) -> Result<(), JokerError> {
+        // alias is resolved at parse time, member user defined type need declared.
+        for member in stmt.type_.members() {
+            self.struct_field_type(member.non_null())?;
+        }
+        Ok(())
+    }
+    fn visit_destruct(&self, stmt: &DestructStmt) -> Result<(), JokerError> {
+        if !self.in_block() {
+            return Err(JokerError::Resolver(Error::Env(EnvError::report_error(
+                &stmt.paren,
+                String::from("var need in block."),
+            ))));
+        }
+        // value check
+        ExprResolver::resolve(self, &stmt.value)?;
+        // type check: value is tuple, declared type accept it, one name each element.
+        let value_type: Type = TypeInferrer::infer_type(self, &stmt.value)?;
+        let tuple_type: Type = match stmt.type_.as_ref() {
+            Some(declared_type) if !declared_type.is_assignable(&value_type) => {
+                return Err(JokerError::Resolver(Error::Struct(
+                    StructError::report_error(
+                        &stmt.paren,
+                        format!(
+                            "Type mismatch: expected {}, found {}",
+                            declared_type, value_type
+                        ),
+                    ),
+                )));
+            }
+            Some(declared_type) => declared_type.clone(),
+            None => value_type,
+        };
+        let types: Vec<Type> = self.destruct_types(&stmt.paren, &stmt.names, &tuple_type)?;
+        for (name, type_) in stmt.names.iter().zip(types) {
+            // '_' skip element, not declare.
+            if name.lexeme.eq("_") {
+                continue;
+            }
+            self.declare_type(name, type_)?;
+            if stmt.mutable {
+                self.declare_mut(name)?;
+            } else {
+                self.declare(name)?;
+            }
+            self.define(name)?;
+        }
+        Ok(())
+    }
     fn visit_expr(&self, stmt: &ExprStmt) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &stmt.expr)?;
         Ok(())
@@ -901,16 +2378,18 @@ impl StmtVisitor<()> for Resolver {
     fn visit_while(&self, stmt: &WhileStmt) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &stmt.condition)?;
 
-        self.context_status_stack
-            .borrow_mut()
-            .push(ContextStatus::Loop);
-
-        StmtResolver::resolve(self, &stmt.body)?;
-
-        self.context_status_stack.borrow_mut().pop();
+        self.resolve_loop_body(&stmt.label, &stmt.body)?;
+        Ok(())
+    }
+    fn visit_do_while(&self, stmt: &DoWhileStmt) -> Result<(), JokerError> {
+        self.resolve_loop_body(&stmt.label, &stmt.body)?;
+        ExprResolver::resolve(self, &stmt.condition)?;
         Ok(())
     }
     fn visit_break(&self, stmt: &BreakStmt) -> Result<(), JokerError> {
+        if let Some(label) = &stmt.label {
+            return self.check_label(label);
+        }
         if self.contains_any(&[ContextStatus::Loop]) {
             return Ok(());
         }
@@ -927,7 +2406,23 @@ impl StmtVisitor<()> for Resolver {
             ContextStatus::Class(ClassStatus::Method(ReturnType::Any)),
             ContextStatus::Class(ClassStatus::Fn(ReturnType::Any)),
         ]) || self.contains_any(&[ContextStatus::Fn(ReturnType::Any)])
+            || self.last_fn_return_type().is_some()
         {
+            // generator give value by yield, 'return;' only finish it.
+            if self.generator_yield_type().is_some() {
+                if let Some(expr) = &stmt.value {
+                    ExprResolver::resolve(self, expr)?;
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            &stmt.keyword,
+                            String::from(
+                                "Cannot return value from generator function, use 'yield'.",
+                            ),
+                        ),
+                    )));
+                }
+                return Ok(());
+            }
             match (stmt.value.as_ref(), self.last_fn_return_type()) {
                 (Some(expr), Some(expected_type)) => {
                     // value check
@@ -978,12 +2473,39 @@ impl StmtVisitor<()> for Resolver {
             Err(JokerError::Resolver(Error::KeyWord(KeyWordError::Pos(
                 PosError::report_error(
                     &stmt.keyword,
-                    String::from("Cannot use 'return' outside of a function statement."),
+                    String::from("Cannot use 'return' outside of a function statement."),
+                ),
+            ))))
+        }
+    }
+    fn visit_yield(&self, stmt: &YieldStmt) -> Result<(), JokerError> {
+        let Some(yield_type) = self.generator_yield_type() else {
+            return Err(JokerError::Resolver(Error::KeyWord(KeyWordError::Pos(
+                PosError::report_error(
+                    &stmt.keyword,
+                    String::from("Cannot use 'yield' outside of a generator function."),
+                ),
+            ))));
+        };
+        ExprResolver::resolve(self, &stmt.value)?;
+        let found_type: Type = TypeInferrer::infer_type(self, &stmt.value)?;
+        if !yield_type.is_assignable(&found_type) {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &stmt.keyword,
+                    format!(
+                        "Yield type mismatch: Expected type '{}', Found type '{}'.",
+                        yield_type, found_type
+                    ),
                 ),
-            ))))
+            )));
         }
+        Ok(())
     }
     fn visit_continue(&self, stmt: &ContinueStmt) -> Result<(), JokerError> {
+        if let Some(label) = &stmt.label {
+            return self.check_label(label);
+        }
         if self.last_any(&[ContextStatus::Loop]) {
             Ok(())
         } else {
@@ -1004,10 +2526,113 @@ impl ExprVisitor<()> for Resolver {
     }
     fn visit_getter(&self, expr: &Getter) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &expr.expr)?;
+        // nullable need null check or '?.' before get.
+        if let Some(type_) = self
+            .declared_type(&expr.expr)
+            .filter(|type_| type_.is_nullable())
+        {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &expr.name,
+                    format!(
+                        "Can't get '{}' on nullable type '{}', check null first or use '?.'.",
+                        expr.name.lexeme, type_
+                    ),
+                ),
+            )));
+        }
+        // type check: struct field and enum variant need declared.
+        if self
+            .declared_type(&expr.expr)
+            .is_some_and(|type_| type_.is_struct() || type_.is_enum() || type_.is_module())
+        {
+            TypeInferrer::infer_type(self, &Expr::Getter(expr.clone()))?;
+        }
         ExprResolver::resolve_local(self, Expr::Getter(expr.clone()), &expr.name)?;
         Ok(())
     }
     fn visit_setter(&self, expr: &Setter) -> Result<(), JokerError> {
+        // nullable need null check before set.
+        if let Some(type_) = self
+            .declared_type(&expr.l_expr)
+            .filter(|type_| type_.is_nullable())
+        {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &expr.name,
+                    format!(
+                        "Can't set '{}' on nullable type '{}', check null first.",
+                        expr.name.lexeme, type_
+                    ),
+                ),
+            )));
+        }
+        // struct field setter: field need declared, value type need same field type.
+        // struct in list, map element or class field is set in place.
+        let struct_type: Option<Type> = self.struct_type(&expr.l_expr).or_else(|| {
+            self.inferred_type(&expr.l_expr)
+                .filter(|type_| type_.is_struct())
+        });
+        if let Some(struct_type) = struct_type {
+            // struct is value, field setter need mutable place.
+            self.check_struct_place(&expr.l_expr, &expr.name)?;
+            ExprResolver::resolve(self, &expr.r_expr)?;
+            ExprResolver::resolve(self, &expr.l_expr)?;
+            let field_type: Type = TypeInferrer::infer_type(
+                self,
+                &Expr::Getter(Getter::new(expr.l_expr.clone(), expr.name.clone())),
+            )?;
+            let value_type: Type = TypeInferrer::infer_type(self, &expr.r_expr)?;
+            if !self.is_struct_field_type(&field_type, &value_type)? {
+                return Err(JokerError::Resolver(Error::Struct(StructError::report_error(
+                    &expr.name,
+                    format!(
+                        "Struct '{}' field '{}' type mismatch: Expected type '{}', Found type '{}'.",
+                        struct_type, expr.name.lexeme, field_type, value_type,
+                    ),
+                ))));
+            }
+            ExprResolver::resolve_local(self, Expr::Setter(expr.clone()), &expr.name)?;
+            return Ok(());
+        }
+        // class static setter: Class.name, instance.name or this.name, one value shared.
+        let static_type: Option<(Token, Type)> =
+            self.declared_type(&expr.l_expr).and_then(|caller_type| {
+                caller_type
+                    .get_static(&expr.name.lexeme)
+                    .map(|(class, type_)| (class.clone(), type_.clone()))
+            });
+        if let Some((class, static_type)) = static_type {
+            if self
+                .immutable_statics
+                .borrow()
+                .contains(&(class.lexeme.clone(), expr.name.lexeme.clone()))
+            {
+                return Err(JokerError::Resolver(Error::Var(VarError::Immutable(
+                    ImmutableError::report_error(
+                        &expr.name,
+                        format!(
+                            "Can't assign to immutable static '{}.{}', declare it with 'static var mut'.",
+                            class.lexeme, expr.name.lexeme
+                        ),
+                    ),
+                ))));
+            }
+            ExprResolver::resolve(self, &expr.r_expr)?;
+            ExprResolver::resolve(self, &expr.l_expr)?;
+            let value_type: Type = TypeInferrer::infer_type(self, &expr.r_expr)?;
+            if !static_type.eq_type(&value_type) {
+                return Err(JokerError::Resolver(Error::Struct(StructError::report_error(
+                    &expr.name,
+                    format!(
+                        "Class '{}' static '{}' type mismatch: Expected type '{}', Found type '{}'.",
+                        class.lexeme, expr.name.lexeme, static_type, value_type,
+                    ),
+                ))));
+            }
+            return Ok(());
+        }
+        self.check_field_mutable(&expr.l_expr, &expr.name)?;
         if self.last_any(&[ContextStatus::Class(ClassStatus::Method(ReturnType::Any))])
             || matches!(*expr.l_expr, Expr::Variable(_))
         {
@@ -1023,6 +2648,22 @@ impl ExprVisitor<()> for Resolver {
                     let key: String = expr.name.lexeme.clone();
                     let value_type: Type = TypeInferrer::infer_type(self, &expr.r_expr)?;
 
+                    // generic class instance: field type bind type arguments.
+                    if let Type::Generic { name: _, args: _ } = caller_type {
+                        let field_type: Type = TypeInferrer::infer_type(
+                            self,
+                            &Expr::Getter(Getter::new(expr.l_expr.clone(), expr.name.clone())),
+                        )?;
+                        if !field_type.is_assignable(&value_type) {
+                            return Err(JokerError::Resolver(Error::Struct(StructError::report_error(
+                                &expr.name,
+                                format!("Setter type mismatch: Expected type '{}', Found type '{}'.",
+                                    field_type, value_type,
+                                )
+                            ))));
+                        }
+                        return Ok(());
+                    }
                     if caller_type.is_instance() {
                         // find instance parameter exit?
                         match caller_type.get_type(&expr.name)? {
@@ -1255,20 +2896,35 @@ impl ExprVisitor<()> for Resolver {
     }
     fn visit_unary(&self, expr: &Unary) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &expr.r_expr)?;
+        // operator overload: instance operand need class operator method.
+        if self.is_instance_operand(&expr.r_expr) {
+            TypeInferrer::infer_type(self, &Expr::Unary(expr.clone()))?;
+        }
         Ok(())
     }
     fn visit_binary(&self, expr: &Binary) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &expr.l_expr)?;
         ExprResolver::resolve(self, &expr.r_expr)?;
+        // operator overload: instance operand need class operator method.
+        if self.is_instance_operand(&expr.l_expr) || self.is_instance_operand(&expr.r_expr) {
+            TypeInferrer::infer_type(self, &Expr::Binary(expr.clone()))?;
+        }
         Ok(())
     }
     fn visit_assign(&self, expr: &Assign) -> Result<(), JokerError> {
+        // mutable check: immutable binding only init once.
+        self.check_mutable(&expr.name, None)?;
         // value check
         ExprResolver::resolve(self, &expr.value)?;
-        // type check
-        let assign_type: Type = self.get_type(&expr.name)?;
+        // type check: declared type, not narrow type.
+        let assign_type: Type = self.type_env.borrow().declared_type(&expr.name)?;
         let value_type: Type = TypeInferrer::infer_type(self, &expr.value)?;
-        if !assign_type.eq(&value_type) {
+        let is_match: bool = if assign_type.is_nullable() || assign_type.is_union() {
+            self.is_nullable_assignable(&assign_type, &value_type)?
+        } else {
+            assign_type.eq(&value_type)
+        };
+        if !is_match {
             return Err(JokerError::Resolver(Error::Struct(
                 StructError::report_error(
                     &expr.name,
@@ -1279,6 +2935,8 @@ impl ExprVisitor<()> for Resolver {
                 ),
             )));
         }
+        self.type_env.borrow_mut().drop_narrow(&expr.name);
+        self.init_late(&expr.name);
 
         ExprResolver::resolve_local(self, Expr::Assign(expr.clone()), &expr.name)?;
         Ok(())
@@ -1292,7 +2950,20 @@ impl ExprVisitor<()> for Resolver {
     }
     fn visit_logical(&self, expr: &Logical) -> Result<(), JokerError> {
         ExprResolver::resolve(self, &expr.l_expr)?;
-        ExprResolver::resolve(self, &expr.r_expr)?;
+        // `x != null and ...` right x is non null, `x is T and ...` right x is T.
+        let narrow: Option<(Token, Type)> = match self.null_check(&expr.l_expr) {
+            Some((name, type_, true)) if expr.m_opera.ttype == TokenType::And => {
+                Some((name, type_))
+            }
+            _ if expr.m_opera.ttype == TokenType::And => self
+                .type_check(&expr.l_expr)
+                .map(|(name, then_type, _)| (name, then_type)),
+            _ => None,
+        };
+        self.resolve_narrow(
+            narrow.as_ref().map(|(name, type_)| (name, type_)),
+            |resolver| ExprResolver::resolve(resolver, &expr.r_expr),
+        )?;
         Ok(())
     }
     fn visit_grouping(&self, expr: &Grouping) -> Result<(), JokerError> {
@@ -1301,7 +2972,7 @@ impl ExprVisitor<()> for Resolver {
     }
     fn visit_variable(&self, expr: &Variable) -> Result<(), JokerError> {
         if let Some(scope) = self.scopes_stack.borrow().last() {
-            if let Some(VarStatus::Declare) = scope.borrow().get(&Key(expr.name.clone())) {
+            if let Some((VarStatus::Declare, _)) = scope.borrow().get(&Key(expr.name.clone())) {
                 return Err(JokerError::Resolver(Error::Var(VarError::Init(
                     InitError::report_error(
                         &expr.name,
@@ -1332,6 +3003,368 @@ impl ExprVisitor<()> for Resolver {
             ))))
         }
     }
+    fn visit_list(&self, expr: &List) -> Result<(), JokerError> {
+        for element in &expr.elements {
+            ExprResolver::resolve(self, element)?;
+        }
+        Ok(())
+    }
+    fn visit_match(&self, expr: &Match) -> Result<(), JokerError> {
+        // value check
+        ExprResolver::resolve(self, &expr.value)?;
+        let value_type: Type = TypeInferrer::infer_type(self, &expr.value)?;
+        for arm in &expr.arms {
+            // pattern type check: class pattern can downcast, so only check literal and range.
+            // union value: every pattern type need union member.
+            if let Some(pattern_type) = TypeInferrer::infer_pattern_type(self, &arm.pattern)? {
+                if (value_type.is_union() || !matches!(arm.pattern, Pattern::Class { .. }))
+                    && !self.is_nullable_assignable(&value_type, &pattern_type)?
+                {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            arm.pattern.token(),
+                            format!(
+                                "Match pattern type mismatch: Expected type '{}', Found type '{}'.",
+                                value_type, pattern_type
+                            ),
+                        ),
+                    )));
+                }
+            }
+            // arm body check, class pattern binding in arm scope.
+            if let Pattern::Class {
+                name: _,
+                binding: Some(binding),
+            } = &arm.pattern
+            {
+                self.begin_scope();
+                self.declare(binding)?;
+                self.define(binding)?;
+                if let Some(binding_type) = TypeInferrer::infer_pattern_type(self, &arm.pattern)? {
+                    self.declare_type(binding, binding_type)?;
+                }
+                ExprResolver::resolve(self, &arm.body)?;
+                self.check_vars_status()?;
+                self.end_scope();
+            } else if let Pattern::Variant { bindings, .. } = &arm.pattern {
+                let payload: Vec<Type> = TypeInferrer::infer_variant_payload(self, &arm.pattern)?;
+                self.begin_scope();
+                for (binding, binding_type) in bindings.iter().zip(payload) {
+                    if binding.lexeme.eq("_") {
+                        continue;
+                    }
+                    self.declare(binding)?;
+                    self.define(binding)?;
+                    self.declare_type(binding, binding_type)?;
+                }
+                ExprResolver::resolve(self, &arm.body)?;
+                self.check_vars_status()?;
+                self.end_scope();
+            } else {
+                ExprResolver::resolve(self, &arm.body)?;
+            }
+        }
+        // exhaustive check: enum need all variant arm or wildcard.
+        let enum_type: Type = match &value_type {
+            Type::UserDefined(name) => self.get_type(name)?,
+            type_ => type_.clone(),
+        };
+        if let Type::Enum {
+            name: _,
+            variants,
+            module: _,
+        } = &enum_type
+        {
+            if !expr.arms.iter().any(|arm| arm.pattern.is_wildcard()) {
+                for (variant, _) in variants {
+                    if !expr.arms.iter().any(|arm| {
+                        matches!(&arm.pattern, Pattern::Variant { variant: other, .. } if other.lexeme.eq(variant))
+                    }) {
+                        return Err(JokerError::Resolver(Error::Struct(
+                            StructError::report_error(
+                                &expr.keyword,
+                                format!(
+                                    "Match is not exhaustive: enum '{}' missing '{}' arm.",
+                                    enum_type, variant
+                                ),
+                            ),
+                        )));
+                    }
+                }
+            }
+        }
+        // exhaustive check: union need every member type arm or wildcard.
+        if value_type.is_union() && !expr.arms.iter().any(|arm| arm.pattern.is_wildcard()) {
+            for member in value_type.members() {
+                let mut is_covered: bool = false;
+                for arm in &expr.arms {
+                    // type pattern cover member, literal pattern only null cover null.
+                    let is_type_pattern: bool = match &arm.pattern {
+                        Pattern::Class { .. } => true,
+                        Pattern::Literal { token: _, value } => *value == literal_null(),
+                        _ => false,
+                    };
+                    if let (true, Some(pattern_type)) = (
+                        is_type_pattern,
+                        TypeInferrer::infer_pattern_type(self, &arm.pattern)?,
+                    ) {
+                        is_covered |= self.is_nullable_assignable(&member, &pattern_type)?;
+                    }
+                }
+                if !is_covered {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            &expr.keyword,
+                            format!(
+                                "Match is not exhaustive: union '{}' missing '{}' arm.",
+                                value_type, member
+                            ),
+                        ),
+                    )));
+                }
+            }
+        }
+        // exhaustive check: bool need true and false arm or wildcard.
+        if Type::Bool.eq_type(&value_type) && !expr.arms.iter().any(|arm| arm.pattern.is_wildcard())
+        {
+            for bool_ in [true, false] {
+                if !expr.arms.iter().any(|arm| {
+                    matches!(&arm.pattern, Pattern::Literal { token: _, value } if value.eq(&literal_bool(bool_)))
+                }) {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            &expr.keyword,
+                            format!(
+                                "Match is not exhaustive: bool value missing '{}' arm.",
+                                bool_
+                            ),
+                        ),
+                    )));
+                }
+            }
+        }
+        // arms type check
+        TypeInferrer::infer_type(self, &Expr::Match(expr.clone()))?;
+        Ok(())
+    }
+    fn visit_map(&self, expr: &Map) -> Result<(), JokerError> {
+        for (key, value) in &expr.pairs {
+            ExprResolver::resolve(self, key)?;
+            ExprResolver::resolve(self, value)?;
+        }
+        Ok(())
+    }
+    fn visit_index(&self, expr: &Index) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &expr.l_expr)?;
+        ExprResolver::resolve(self, &expr.index)?;
+        // type check: list[i32], map[key]
+        TypeInferrer::infer_type(self, &Expr::Index(expr.clone()))?;
+        Ok(())
+    }
+    fn visit_index_set(&self, expr: &IndexSet) -> Result<(), JokerError> {
+        // value check
+        ExprResolver::resolve(self, &expr.r_expr)?;
+        ExprResolver::resolve(self, &expr.l_expr)?;
+        ExprResolver::resolve(self, &expr.index)?;
+        // type check: tuple is immutable.
+        if let Type::Tuple(_) = TypeInferrer::infer_type(self, &expr.l_expr)?.unalias() {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &expr.bracket,
+                    String::from("Tuple is immutable, can't set element."),
+                ),
+            )));
+        }
+        // operator overload only index get, instance not support index set.
+        if self.is_instance_operand(&expr.l_expr) {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &expr.bracket,
+                    format!(
+                        "Instance '{}' not support index set.",
+                        TypeInferrer::infer_type(self, &expr.l_expr)?
+                    ),
+                ),
+            )));
+        }
+        let element_type: Type = TypeInferrer::infer_type(
+            self,
+            &Expr::Index(Index::new(
+                expr.l_expr.clone(),
+                expr.bracket.clone(),
+                expr.index.clone(),
+            )),
+        )?;
+        let value_type: Type = TypeInferrer::infer_type(self, &expr.r_expr)?;
+        if !element_type.eq_type(&value_type) {
+            return Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(
+                    &expr.bracket,
+                    format!(
+                        "IndexSet type mismatch: Expected type '{}', Found type '{}'.",
+                        element_type, value_type,
+                    ),
+                ),
+            )));
+        }
+        Ok(())
+    }
+    fn visit_struct_init(&self, expr: &StructInit) -> Result<(), JokerError> {
+        // value check
+        for (_, value) in &expr.fields {
+            ExprResolver::resolve(self, value)?;
+        }
+        self.resolve_local(Expr::StructInit(expr.clone()), &expr.name)?;
+        // type check: every declared field once, no unknown field, field type same.
+        let struct_type: Type = TypeInferrer::infer_type(self, &Expr::StructInit(expr.clone()))?;
+        if let Type::Struct {
+            name,
+            fields,
+            module: _,
+        } = &struct_type
+        {
+            for (field, value) in &expr.fields {
+                let field_type: &Type = match fields
+                    .iter()
+                    .find(|(field_name, _)| field_name.eq(&field.lexeme))
+                {
+                    Some((_, field_type)) => field_type,
+                    None => {
+                        return Err(JokerError::Resolver(Error::Struct(
+                            StructError::report_error(
+                                field,
+                                format!(
+                                    "Struct '{}' don't have field '{}'.",
+                                    name.lexeme, field.lexeme
+                                ),
+                            ),
+                        )))
+                    }
+                };
+                if expr
+                    .fields
+                    .iter()
+                    .filter(|(other, _)| other.lexeme.eq(&field.lexeme))
+                    .count()
+                    > 1
+                {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            field,
+                            format!(
+                                "Struct '{}' field '{}' is initialized more than once.",
+                                name.lexeme, field.lexeme
+                            ),
+                        ),
+                    )));
+                }
+                let value_type: Type = TypeInferrer::infer_type(self, value)?;
+                if !self.is_struct_field_type(field_type, &value_type)? {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            field,
+                            format!(
+                                "Struct '{}' field '{}' type mismatch: Expected type '{}', Found type '{}'.",
+                                name.lexeme, field.lexeme, field_type, value_type,
+                            ),
+                        ),
+                    )));
+                }
+            }
+            for (field_name, _) in fields {
+                if !expr
+                    .fields
+                    .iter()
+                    .any(|(field, _)| field.lexeme.eq(field_name))
+                {
+                    return Err(JokerError::Resolver(Error::Struct(
+                        StructError::report_error(
+                            &expr.name,
+                            format!("Struct '{}' missing field '{}'.", name.lexeme, field_name),
+                        ),
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+    fn visit_assign_op(&self, expr: &AssignOp) -> Result<(), JokerError> {
+        // postfix `a++` step one of the target type: i64 a++ => a = a + 1i64.
+        let step: Box<Expr> = match &expr.value {
+            Some(value) => value.clone(),
+            None => {
+                ExprResolver::resolve(self, &expr.target)?;
+                Box::new(Literal::upcast(
+                    match TypeInferrer::infer_type(self, &expr.target)? {
+                        Type::I64 => literal_i64(1),
+                        Type::U8 => literal_u8(1),
+                        Type::F64 => literal_f64(1.0),
+                        _ => literal_i32(1),
+                    },
+                ))
+            }
+        };
+        // same check as expanded form: a += b => a = a + b.
+        let value: Box<Expr> = Box::new(Binary::upcast(
+            expr.target.clone(),
+            expr.opera.clone(),
+            step,
+        ));
+        match &*expr.target {
+            Expr::Variable(variable) => {
+                self.visit_assign(&Assign::new(variable.name.clone(), value))?;
+                self.resolve_local(Expr::AssignOp(expr.clone()), &variable.name)
+            }
+            Expr::Getter(getter) => self.visit_setter(&Setter::new(
+                getter.expr.clone(),
+                getter.name.clone(),
+                value,
+            )),
+            Expr::Index(index) => self.visit_index_set(&IndexSet::new(
+                index.l_expr.clone(),
+                index.bracket.clone(),
+                index.index.clone(),
+                value,
+            )),
+            _ => Err(JokerError::Resolver(Error::Struct(
+                StructError::report_error(&expr.opera, String::from("Invalid assignment target.")),
+            ))),
+        }
+    }
+    fn visit_template(&self, expr: &Template) -> Result<(), JokerError> {
+        for part in &expr.parts {
+            ExprResolver::resolve(self, part)?;
+        }
+        Ok(())
+    }
+    fn visit_cast(&self, expr: &Cast) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &expr.expr)?;
+        // cast table: impossible cast is static error, lossy cast is runtime error.
+        TypeInferrer::infer_type(self, &Expr::Cast(expr.clone()))?;
+        Ok(())
+    }
+    fn visit_tuple(&self, expr: &Tuple) -> Result<(), JokerError> {
+        for element in &expr.elements {
+            ExprResolver::resolve(self, element)?;
+        }
+        Ok(())
+    }
+    fn visit_range(&self, expr: &Range) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &expr.start)?;
+        ExprResolver::resolve(self, &expr.end)?;
+        // type check: range bound is i32.
+        TypeInferrer::infer_type(self, &Expr::Range(expr.clone()))?;
+        Ok(())
+    }
+    fn visit_is(&self, expr: &Is) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &expr.expr)?;
+        TypeInferrer::infer_type(self, &Expr::Is(expr.clone()))?;
+        Ok(())
+    }
+    fn visit_safe_getter(&self, expr: &SafeGetter) -> Result<(), JokerError> {
+        ExprResolver::resolve(self, &expr.expr)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -1370,6 +3403,7 @@ impl ReportError for Error {
 pub enum VarError {
     Init(InitError),
     Redefine(RedefineError),
+    Immutable(ImmutableError),
 }
 
 impl Display for VarError {
@@ -1377,6 +3411,7 @@ impl Display for VarError {
         match self {
             VarError::Init(init) => Display::fmt(init, f),
             VarError::Redefine(redefine) => Display::fmt(redefine, f),
+            VarError::Immutable(immutable) => Display::fmt(immutable, f),
         }
     }
 }
@@ -1388,6 +3423,7 @@ impl ReportError for VarError {
         match self {
             VarError::Init(init) => ReportError::report(init),
             VarError::Redefine(redefine) => ReportError::report(redefine),
+            VarError::Immutable(immutable) => ReportError::report(immutable),
         }
     }
 }
@@ -1488,6 +3524,54 @@ impl ReportError for RedefineError {
     }
 }
 
+#[derive(Debug)]
+pub struct ImmutableError {
+    line: usize,
+    where_: String,
+    msg: String,
+}
+
+impl ImmutableError {
+    pub fn new(token: &Token, msg: String) -> ImmutableError {
+        let where_: String = if token.ttype == TokenType::Eof {
+            String::from(" at end")
+        } else {
+            format!(" at '{}'", token.lexeme)
+        };
+        ImmutableError {
+            line: token.line,
+            where_,
+            msg,
+        }
+    }
+    pub fn report_error(token: &Token, msg: String) -> ImmutableError {
+        let immutable = ImmutableError::new(token, msg);
+        immutable.report();
+        immutable
+    }
+}
+
+impl Display for ImmutableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ImmutableError(line: {}, where: {}, msg: {})",
+            self.line, self.where_, self.msg
+        )
+    }
+}
+
+impl std::error::Error for ImmutableError {}
+
+impl ReportError for ImmutableError {
+    fn report(&self) {
+        eprintln!(
+            "[line {}] where: '{}', \n\tmsg: {}\n",
+            self.line, self.where_, self.msg
+        );
+    }
+}
+
 #[derive(Debug)]
 pub enum KeyWordError {
     Pos(PosError),
@@ -5,7 +5,10 @@ use std::{error::Error, fmt::Display};
 
 use super::{
     error::{JokerError, ReportError},
-    object::{literal_bool, literal_f64, literal_i32, literal_null, literal_str, Object},
+    object::{
+        literal_bool, literal_f64, literal_i32, literal_i64, literal_null, literal_str, literal_u8,
+        Object,
+    },
     token::{Token, TokenType},
 };
 
@@ -67,19 +70,82 @@ impl Scanner {
             '{' => self.add_token(TokenType::LeftBrace),
             '}' => self.add_token(TokenType::RightBrace),
             ',' => self.add_token(TokenType::Comma),
-            '.' => self.add_token(TokenType::Dot),
+            '.' => {
+                let t_type = if self.is_match('.') {
+                    if self.is_match('=') {
+                        TokenType::DotDotEqual
+                    } else if self.is_match('.') {
+                        TokenType::DotDotDot
+                    } else {
+                        TokenType::DotDot
+                    }
+                } else {
+                    TokenType::Dot
+                };
+                self.add_token(t_type);
+            }
             '-' => {
                 let t_type = if self.is_match('>') {
                     TokenType::Arrow
+                } else if self.is_match('=') {
+                    TokenType::MinusEqual
+                } else if self.is_match('-') {
+                    TokenType::MinusMinus
                 } else {
                     TokenType::Minus
                 };
                 self.add_token(t_type);
             }
-            '+' => self.add_token(TokenType::Plus),
+            '+' => {
+                let t_type = if self.is_match('=') {
+                    TokenType::PlusEqual
+                } else if self.is_match('+') {
+                    TokenType::PlusPlus
+                } else {
+                    TokenType::Plus
+                };
+                self.add_token(t_type);
+            }
             ';' => self.add_token(TokenType::Semicolon),
-            '*' => self.add_token(TokenType::Star),
-            '?' => self.add_token(TokenType::Question),
+            '*' => {
+                let t_type = if self.is_match('*') {
+                    TokenType::StarStar
+                } else if self.is_match('=') {
+                    TokenType::StarEqual
+                } else {
+                    TokenType::Star
+                };
+                self.add_token(t_type);
+            }
+            '%' => {
+                let t_type = if self.is_match('=') {
+                    TokenType::PercentEqual
+                } else {
+                    TokenType::Percent
+                };
+                self.add_token(t_type);
+            }
+            '&' => self.add_token(TokenType::Amper),
+            '^' => self.add_token(TokenType::Caret),
+            // `//` is line comment, integer floor division is `~/`.
+            '~' => {
+                let t_type = if self.is_match('/') {
+                    TokenType::TildeSlash
+                } else {
+                    TokenType::Tilde
+                };
+                self.add_token(t_type);
+            }
+            '?' => {
+                let t_type = if self.is_match('.') {
+                    TokenType::QuestionDot
+                } else if self.is_match('?') {
+                    TokenType::QuestionQuestion
+                } else {
+                    TokenType::Question
+                };
+                self.add_token(t_type);
+            }
             ':' => self.add_token(TokenType::Colon),
             '|' => self.add_token(TokenType::Pipeline),
             '!' => {
@@ -93,6 +159,8 @@ impl Scanner {
             '=' => {
                 let t_type = if self.is_match('=') {
                     TokenType::EqualEqual
+                } else if self.is_match('>') {
+                    TokenType::FatArrow
                 } else {
                     TokenType::Equal
                 };
@@ -101,6 +169,8 @@ impl Scanner {
             '<' => {
                 let t_type = if self.is_match('=') {
                     TokenType::LessEqual
+                } else if self.is_match('<') {
+                    TokenType::LessLess
                 } else {
                     TokenType::Less
                 };
@@ -109,6 +179,8 @@ impl Scanner {
             '>' => {
                 let t_type = if self.is_match('=') {
                     TokenType::GreaterEqual
+                } else if self.is_match('>') {
+                    TokenType::GreaterGreater
                 } else {
                     TokenType::Greater
                 };
@@ -124,6 +196,8 @@ impl Scanner {
                     }
                 } else if self.is_match('*') {
                     self.scan_comment()?;
+                } else if self.is_match('=') {
+                    self.add_token(TokenType::SlashEqual);
                 } else {
                     self.add_token(TokenType::Slash);
                 }
@@ -187,13 +261,40 @@ impl Scanner {
         Ok(())
     }
 
+    // string template: "a${expr}b" => Template Str(a) ${ expr } Str(b) TemplateEnd.
     fn scan_string(&mut self) -> Result<(), JokerError> {
+        let mut parts: Vec<Token> = Vec::new();
+        let mut segment: usize = self.current;
         while let Some(ch) = self.peek() {
             match ch {
                 '"' => break,
                 '\\' if self.next_peek() == Some(&'"') => {
                     self.advance();
                 }
+                // `\${` is text `${`, not interpolation.
+                '\\' if self.next_peek() == Some(&'$') => {
+                    self.advance();
+                }
+                '$' if self.next_peek() == Some(&'{') => {
+                    self.add_template_text(&mut parts, segment, self.current);
+                    parts.push(Token::new(
+                        TokenType::DollarLeftBrace,
+                        String::from("${"),
+                        literal_null(),
+                        self.line,
+                    ));
+                    self.advance();
+                    self.advance();
+                    parts.extend(self.scan_interpolation()?);
+                    parts.push(Token::new(
+                        TokenType::RightBrace,
+                        String::from("}"),
+                        literal_null(),
+                        self.line,
+                    ));
+                    segment = self.current;
+                    continue;
+                }
                 '\n' => self.line += 1,
                 _ => {}
             }
@@ -208,14 +309,96 @@ impl Scanner {
         }
         self.advance();
 
-        // TODO: handle escape sequence
-        let text: String = self.source[self.start + 1..self.current - 1]
-            .iter()
-            .collect();
-        self.add_token_object(TokenType::Str, literal_str(text));
+        if parts.is_empty() {
+            // TODO: handle escape sequence
+            let text: String = self.source[self.start + 1..self.current - 1]
+                .iter()
+                .collect::<String>()
+                .replace("\\${", "${");
+            self.add_token_object(TokenType::Str, literal_str(text));
+            return Ok(());
+        }
+        self.add_template_text(&mut parts, segment, self.current - 1);
+        self.add_token(TokenType::Template);
+        if let Some(ref mut tokens) = self.tokens {
+            tokens.extend(parts);
+            tokens.push(Token::new(
+                TokenType::TemplateEnd,
+                String::from("\""),
+                literal_null(),
+                self.line,
+            ));
+        }
         Ok(())
     }
 
+    // template text before `${` or closing quote, empty text skip.
+    fn add_template_text(&self, parts: &mut Vec<Token>, start: usize, end: usize) {
+        if start < end {
+            let text: String = self.source[start..end]
+                .iter()
+                .collect::<String>()
+                .replace("\\${", "${");
+            parts.push(Token::new(
+                TokenType::Str,
+                text.clone(),
+                literal_str(text),
+                self.line,
+            ));
+        }
+    }
+
+    // interpolation source until matching `}`, scan by a sub scanner.
+    fn scan_interpolation(&mut self) -> Result<Vec<Token>, JokerError> {
+        let (start, line): (usize, usize) = (self.current, self.line);
+        let mut depth: usize = 1;
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(JokerError::Scanner(ScannerError::error(
+                        line,
+                        String::from("Unterminated string interpolation, expect '}'."),
+                    )))
+                }
+                Some('{') => depth += 1,
+                Some('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                Some('"') => {
+                    self.advance();
+                    while let Some(ch) = self.peek() {
+                        if *ch == '"' {
+                            break;
+                        }
+                        self.advance();
+                    }
+                }
+                Some('\n') => self.line += 1,
+                _ => {}
+            }
+            if !self.is_at_end() {
+                self.advance();
+            }
+        }
+        let source: String = self.source[start..self.current].iter().collect();
+        self.advance();
+
+        let mut scanner: Scanner = Scanner::new(source);
+        scanner.line = line;
+        let mut tokens: Vec<Token> = scanner.scan_tokens()?;
+        tokens.pop();
+        if tokens.is_empty() {
+            return Err(JokerError::Scanner(ScannerError::error(
+                line,
+                String::from("Empty string interpolation '${}'."),
+            )));
+        }
+        Ok(tokens)
+    }
+
     fn is_digit(op_ch: Option<&char>) -> bool {
         match op_ch {
             Some(ch) => ch.is_ascii_digit(),
@@ -223,21 +406,42 @@ impl Scanner {
         }
     }
 
+    // number: 0x 0o 0b radix prefix, `_` digit separator, fraction, exponent and
+    // type suffix i32 i64 u8 f64, default is i32 or f64.
     fn scan_number(&mut self) -> Result<(), JokerError> {
-        while Scanner::is_digit(self.peek()) {
+        let radix: u32 = match (self.source[self.start], self.peek()) {
+            ('0', Some('x' | 'X')) => 16,
+            ('0', Some('o' | 'O')) => 8,
+            ('0', Some('b' | 'B')) => 2,
+            _ => 10,
+        };
+        let mut digits: String = String::new();
+        if radix == 10 {
+            digits.push(self.source[self.start]);
+        } else {
             self.advance();
         }
+        self.scan_digits(radix, &mut digits);
+        if digits.is_empty() {
+            return Err(JokerError::Scanner(ScannerError::error(
+                self.line,
+                String::from("Expect digits after number radix prefix."),
+            )));
+        }
 
-        if self.peek() == Some(&'.') {
+        let mut is_float: bool = false;
+        // range: 1..5, 1..=5, method: 1.to_i64()
+        if radix == 10
+            && self.peek() == Some(&'.')
+            && self.next_peek() != Some(&'.')
+            && !self
+                .next_peek()
+                .is_some_and(|next| next.is_ascii_alphabetic())
+        {
             if Scanner::is_digit(self.next_peek()) {
-                self.advance();
-                while Scanner::is_digit(self.peek()) {
-                    self.advance();
-                }
-                let f64_text: String = self.source[self.start..self.current].iter().collect();
-                let f64_: f64 = f64_text.parse::<f64>().unwrap();
-                self.add_token_object(TokenType::F64, literal_f64(f64_));
-                return Ok(());
+                digits.push(self.advance());
+                self.scan_digits(radix, &mut digits);
+                is_float = true;
             } else {
                 return Err(JokerError::Scanner(ScannerError::error(
                     self.line,
@@ -245,13 +449,138 @@ impl Scanner {
                 )));
             }
         }
+        // exponent: 1e9, 2.5E-3
+        if radix == 10 && matches!(self.peek(), Some('e' | 'E')) {
+            let sign: bool = matches!(self.next_peek(), Some('+' | '-'));
+            let exponent: Option<&char> = self.source.get(self.current + 1 + sign as usize);
+            if Scanner::is_digit(exponent) {
+                digits.push(self.advance());
+                if sign {
+                    digits.push(self.advance());
+                }
+                self.scan_digits(radix, &mut digits);
+                is_float = true;
+            }
+        }
 
-        let i32_text: String = self.source[self.start..self.current].iter().collect();
-        let i32_: i32 = i32_text.parse::<i32>().unwrap();
-        self.add_token_object(TokenType::I32, literal_i32(i32_));
+        let suffix_start: usize = self.current;
+        while Scanner::is_alpha_numeric(self.peek()) {
+            self.advance();
+        }
+        let suffix: String = self.source[suffix_start..self.current].iter().collect();
+        let suffix: &str = match suffix.as_str() {
+            "" if is_float => "f64",
+            "" => "i32",
+            suffix => suffix,
+        };
+        let lexeme: String = self.source[self.start..self.current].iter().collect();
+        let line: usize = self.line;
+        let out_of_range = |_| -> JokerError {
+            JokerError::Scanner(ScannerError::error(
+                line,
+                format!("Number literal '{}' out of range for {}.", lexeme, suffix),
+            ))
+        };
+        match suffix {
+            "f64" => {
+                let f64_: f64 = if radix == 10 {
+                    digits.parse::<f64>().unwrap()
+                } else {
+                    i64::from_str_radix(&digits, radix).map_err(out_of_range)? as f64
+                };
+                self.add_token_object(TokenType::F64, literal_f64(f64_));
+            }
+            "i32" | "i64" | "u8" if is_float => {
+                return Err(JokerError::Scanner(ScannerError::error(
+                    self.line,
+                    format!(
+                        "Float literal '{}' can't have integer suffix '{}'.",
+                        lexeme, suffix
+                    ),
+                )))
+            }
+            "i32" => match i32::from_str_radix(&digits, radix) {
+                Ok(i32_) => self.add_token_object(TokenType::I32, literal_i32(i32_)),
+                // i32::MIN magnitude is out of range, fold the unary minus into literal.
+                Err(err) => match i32::from_str_radix(&format!("-{digits}"), radix) {
+                    Ok(i32_) if self.pop_unary_minus() => {
+                        self.add_token_object(TokenType::I32, literal_i32(i32_))
+                    }
+                    _ => return Err(out_of_range(err)),
+                },
+            },
+            "i64" => match i64::from_str_radix(&digits, radix) {
+                Ok(i64_) => self.add_token_object(TokenType::I64, literal_i64(i64_)),
+                Err(err) => match i64::from_str_radix(&format!("-{digits}"), radix) {
+                    Ok(i64_) if self.pop_unary_minus() => {
+                        self.add_token_object(TokenType::I64, literal_i64(i64_))
+                    }
+                    _ => return Err(out_of_range(err)),
+                },
+            },
+            "u8" => {
+                let u8_: u8 = u8::from_str_radix(&digits, radix).map_err(out_of_range)?;
+                self.add_token_object(TokenType::U8, literal_u8(u8_));
+            }
+            _ => {
+                return Err(JokerError::Scanner(ScannerError::error(
+                    self.line,
+                    format!(
+                        "Invalid number literal suffix '{}', expect i32, i64, u8 or f64.",
+                        suffix
+                    ),
+                )))
+            }
+        }
         Ok(())
     }
 
+    // last token `-` is unary minus when not after a value, remove it for negative literal.
+    fn pop_unary_minus(&mut self) -> bool {
+        let Some(tokens) = self.tokens.as_mut() else {
+            return false;
+        };
+        let is_unary: bool = match tokens.as_slice() {
+            [.., before, minus] if minus.ttype == TokenType::Minus => !matches!(
+                before.ttype,
+                TokenType::Identifier
+                    | TokenType::Str
+                    | TokenType::I32
+                    | TokenType::I64
+                    | TokenType::U8
+                    | TokenType::F64
+                    | TokenType::TemplateEnd
+                    | TokenType::RightParen
+                    | TokenType::RightBracket
+                    | TokenType::PlusPlus
+                    | TokenType::MinusMinus
+                    | TokenType::True
+                    | TokenType::False
+                    | TokenType::Null
+                    | TokenType::This
+            ),
+            [minus] => minus.ttype == TokenType::Minus,
+            _ => false,
+        };
+        if is_unary {
+            tokens.pop();
+        }
+        is_unary
+    }
+
+    // radix digits, `_` separator need followed by digit.
+    fn scan_digits(&mut self, radix: u32, digits: &mut String) {
+        while let Some(ch) = self.peek() {
+            if ch.is_digit(radix) {
+                digits.push(self.advance());
+            } else if *ch == '_' && self.next_peek().is_some_and(|next| next.is_digit(radix)) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+    }
+
     fn is_alpha_numeric(op_ch: Option<&char>) -> bool {
         match op_ch {
             Some(ch) => ch.is_ascii_alphanumeric() || *ch == '_',
@@ -298,6 +627,24 @@ impl Scanner {
             "continue" => Some(TokenType::Continue),
             "match" => Some(TokenType::Match),
             "struct" => Some(TokenType::Struct),
+            "enum" => Some(TokenType::Enum),
+            "throw" => Some(TokenType::Throw),
+            "try" => Some(TokenType::Try),
+            "catch" => Some(TokenType::Catch),
+            "finally" => Some(TokenType::Finally),
+            "import" => Some(TokenType::Import),
+            "export" => Some(TokenType::Export),
+            "as" => Some(TokenType::As),
+            "mut" => Some(TokenType::Mut),
+            "const" => Some(TokenType::Const),
+            "static" => Some(TokenType::Static),
+            "interface" => Some(TokenType::Interface),
+            "impl" => Some(TokenType::Impl),
+            "type" => Some(TokenType::Type),
+            "is" => Some(TokenType::Is),
+            "loop" => Some(TokenType::Loop),
+            "do" => Some(TokenType::Do),
+            "yield" => Some(TokenType::Yield),
             _ => None,
         }
     }
@@ -21,18 +21,39 @@ pub enum TokenType {
     // {}
     LeftBrace,
     RightBrace,
-    // , .
+    // , . .. ..=
     Comma,
     Dot,
+    DotDot,
+    DotDotEqual,
+    DotDotDot,
     // -  +   ;  / *
     Minus,
     Plus,
     Semicolon,
     Slash,
     Star,
-    // = ==  ! !=
+    // % ** ~/ & ^ ~ << >>
+    Percent,
+    StarStar,
+    TildeSlash,
+    Amper,
+    Caret,
+    Tilde,
+    LessLess,
+    GreaterGreater,
+    // += -= *= /= %= ++ --
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
+    PercentEqual,
+    PlusPlus,
+    MinusMinus,
+    // = == =>  ! !=
     Equal,
     EqualEqual,
+    FatArrow,
     Bang,
     BangEqual,
     // > >=
@@ -41,15 +62,23 @@ pub enum TokenType {
     // < <=
     Less,
     LessEqual,
-    // :  ? |  ->
+    // :  ? ?. ?? |  ->
     Colon,
     Question,
+    QuestionDot,
+    QuestionQuestion,
     Pipeline,
     Arrow,
-    // id string I32 F64
+    // string template: "a${x}b"
+    Template,
+    DollarLeftBrace,
+    TemplateEnd,
+    // id string I32 I64 U8 F64
     Identifier,
     Str,
     I32,
+    I64,
+    U8,
     F64,
     // Keyword
     And,
@@ -72,6 +101,24 @@ pub enum TokenType {
     Continue,
     Match,
     Struct,
+    Enum,
+    Throw,
+    Try,
+    Catch,
+    Finally,
+    Import,
+    Export,
+    As,
+    Mut,
+    Const,
+    Static,
+    Interface,
+    Impl,
+    Type,
+    Is,
+    Loop,
+    Do,
+    Yield,
     Eof,
 }
 impl Display for TokenType {
@@ -85,32 +132,61 @@ impl Display for TokenType {
             TokenType::RightBrace => write!(f, "}}"),
             TokenType::Comma => write!(f, ","),
             TokenType::Dot => write!(f, "."),
+            TokenType::DotDot => write!(f, ".."),
+            TokenType::DotDotEqual => write!(f, "..="),
+            TokenType::DotDotDot => write!(f, "..."),
             // -  +   ;  / *
             TokenType::Minus => write!(f, "-"),
             TokenType::Plus => write!(f, "+"),
             TokenType::Semicolon => write!(f, ";"),
             TokenType::Slash => write!(f, "/"),
             TokenType::Star => write!(f, "*"),
+            // % ** ~/ & ^ ~ << >>
+            TokenType::Percent => write!(f, "%"),
+            TokenType::StarStar => write!(f, "**"),
+            TokenType::TildeSlash => write!(f, "~/"),
+            TokenType::Amper => write!(f, "&"),
+            TokenType::Caret => write!(f, "^"),
+            TokenType::Tilde => write!(f, "~"),
+            TokenType::LessLess => write!(f, "<<"),
+            TokenType::GreaterGreater => write!(f, ">>"),
+            // += -= *= /= %= ++ --
+            TokenType::PlusEqual => write!(f, "+="),
+            TokenType::MinusEqual => write!(f, "-="),
+            TokenType::StarEqual => write!(f, "*="),
+            TokenType::SlashEqual => write!(f, "/="),
+            TokenType::PercentEqual => write!(f, "%="),
+            TokenType::PlusPlus => write!(f, "++"),
+            TokenType::MinusMinus => write!(f, "--"),
             // ! != = ==
             TokenType::Bang => write!(f, "!"),
             TokenType::BangEqual => write!(f, "!="),
             TokenType::Equal => write!(f, "="),
             TokenType::EqualEqual => write!(f, "=="),
+            TokenType::FatArrow => write!(f, "=>"),
             // > >=
             TokenType::Greater => write!(f, ">"),
             TokenType::GreaterEqual => write!(f, ">="),
             // < <=
             TokenType::Less => write!(f, "<"),
             TokenType::LessEqual => write!(f, "<="),
-            // : ? | ->
+            // : ? ?. ?? | ->
             TokenType::Colon => write!(f, ":"),
             TokenType::Question => write!(f, "?"),
+            TokenType::QuestionDot => write!(f, "?."),
+            TokenType::QuestionQuestion => write!(f, "??"),
             TokenType::Pipeline => write!(f, "|"),
             TokenType::Arrow => write!(f, "->"),
+            // string template: "a${x}b"
+            TokenType::Template => write!(f, "Template"),
+            TokenType::DollarLeftBrace => write!(f, "${{"),
+            TokenType::TemplateEnd => write!(f, "TemplateEnd"),
             // id Str i32 f64
             TokenType::Identifier => write!(f, "Ident"),
             TokenType::Str => write!(f, "Str"),
             TokenType::I32 => write!(f, "I32"),
+            TokenType::I64 => write!(f, "I64"),
+            TokenType::U8 => write!(f, "U8"),
             TokenType::F64 => write!(f, "F64"),
             // Keyword
             TokenType::And => write!(f, "And"),
@@ -133,6 +209,24 @@ impl Display for TokenType {
             TokenType::Continue => write!(f, "Continue"),
             TokenType::Match => write!(f, "Match"),
             TokenType::Struct => write!(f, "Struct"),
+            TokenType::Enum => write!(f, "Enum"),
+            TokenType::Throw => write!(f, "Throw"),
+            TokenType::Try => write!(f, "Try"),
+            TokenType::Catch => write!(f, "Catch"),
+            TokenType::Finally => write!(f, "Finally"),
+            TokenType::Import => write!(f, "Import"),
+            TokenType::Export => write!(f, "Export"),
+            TokenType::As => write!(f, "As"),
+            TokenType::Mut => write!(f, "Mut"),
+            TokenType::Const => write!(f, "Const"),
+            TokenType::Static => write!(f, "Static"),
+            TokenType::Interface => write!(f, "Interface"),
+            TokenType::Impl => write!(f, "Impl"),
+            TokenType::Type => write!(f, "Type"),
+            TokenType::Is => write!(f, "Is"),
+            TokenType::Loop => write!(f, "Loop"),
+            TokenType::Do => write!(f, "Do"),
+            TokenType::Yield => write!(f, "Yield"),
 
             TokenType::Eof => write!(f, "Eof"),
         }
@@ -179,6 +273,55 @@ impl Token {
             line,
         )
     }
+    // compound assignment binary operator: `a += b` => a = a + b, `a++` => a = a + 1.
+    pub fn compound_operator(&self) -> Option<Token> {
+        let (ttype, lexeme) = match self.ttype {
+            TokenType::PlusEqual | TokenType::PlusPlus => (TokenType::Plus, "+"),
+            TokenType::MinusEqual | TokenType::MinusMinus => (TokenType::Minus, "-"),
+            TokenType::StarEqual => (TokenType::Star, "*"),
+            TokenType::SlashEqual => (TokenType::Slash, "/"),
+            TokenType::PercentEqual => (TokenType::Percent, "%"),
+            _ => return None,
+        };
+        Some(Token::new(
+            ttype,
+            String::from(lexeme),
+            literal_null(),
+            self.line,
+        ))
+    }
+    // operator overload class method name: `a + b` => a.add(b).
+    pub fn binary_method(&self) -> Option<&'static str> {
+        match self.ttype {
+            TokenType::Plus => Some("add"),
+            TokenType::Minus => Some("sub"),
+            TokenType::Star => Some("mul"),
+            TokenType::Slash => Some("div"),
+            TokenType::Percent => Some("rem"),
+            TokenType::StarStar => Some("pow"),
+            TokenType::TildeSlash => Some("floor_div"),
+            TokenType::Amper => Some("bitand"),
+            TokenType::Caret => Some("bitxor"),
+            TokenType::LessLess => Some("shl"),
+            TokenType::GreaterGreater => Some("shr"),
+            TokenType::EqualEqual => Some("eq"),
+            TokenType::BangEqual => Some("ne"),
+            TokenType::Less => Some("lt"),
+            TokenType::LessEqual => Some("le"),
+            TokenType::Greater => Some("gt"),
+            TokenType::GreaterEqual => Some("ge"),
+            _ => None,
+        }
+    }
+    // operator overload class method name: `-a` => a.neg().
+    pub fn unary_method(&self) -> Option<&'static str> {
+        match self.ttype {
+            TokenType::Minus => Some("neg"),
+            TokenType::Bang => Some("not"),
+            TokenType::Tilde => Some("bitnot"),
+            _ => None,
+        }
+    }
     pub fn is_this(&self) -> bool {
         self.lexeme.eq("this")
     }
@@ -193,6 +336,8 @@ impl Display for Token {
             TokenType::Identifier => write!(f, "{}", self.lexeme),
             TokenType::Str => write!(f, "{}", self.lexeme),
             TokenType::I32 => write!(f, "{}", self.lexeme),
+            TokenType::I64 => write!(f, "{}", self.lexeme),
+            TokenType::U8 => write!(f, "{}", self.lexeme),
             TokenType::F64 => write!(f, "{}", self.lexeme),
             _ => Display::fmt(&self.ttype, f),
         }
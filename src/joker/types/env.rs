@@ -1,4 +1,4 @@
-use std::collections::{hash_map::Entry, HashMap};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 
 use crate::joker::{
     callable::StructError,
@@ -12,24 +12,67 @@ use super::Type;
 
 pub struct TypeEnv {
     pub symbol: Vec<HashMap<String, Type>>,
+    narrowed: Vec<HashSet<String>>, // names of scope type is narrow, only read see it.
 }
 
 impl TypeEnv {
     pub fn new() -> TypeEnv {
-        TypeEnv { symbol: Vec::new() }
+        TypeEnv {
+            symbol: Vec::new(),
+            narrowed: Vec::new(),
+        }
     }
     pub fn new_global() -> TypeEnv {
         TypeEnv {
             symbol: vec![HashMap::new()],
+            narrowed: vec![HashSet::new()],
         }
     }
     pub fn begin_scope(&mut self) {
-        self.symbol.push(HashMap::new())
+        self.symbol.push(HashMap::new());
+        self.narrowed.push(HashSet::new());
     }
     pub fn end_scope(&mut self) {
         self.symbol.pop();
+        self.narrowed.pop();
+    }
+    // narrow type of declared variable in current scope: `x != null`, `x is T`.
+    pub fn narrow_type(&mut self, name: &Token, ty: Type) -> Result<(), JokerError> {
+        self.declare_type(name, ty)?;
+        if let Some(narrowed) = self.narrowed.last_mut() {
+            narrowed.insert(name.lexeme.clone());
+        }
+        Ok(())
+    }
+    // drop narrow type of name until its declaration, variable maybe assigned.
+    pub fn drop_narrow(&mut self, name: &Token) {
+        for (scope, narrowed) in self.symbol.iter_mut().zip(&mut self.narrowed).rev() {
+            if narrowed.remove(&name.lexeme) {
+                scope.remove(&name.lexeme);
+            } else if scope.contains_key(&name.lexeme) {
+                return;
+            }
+        }
+    }
+    // declared type, skip narrow type: assign value check by it.
+    pub fn declared_type(&self, name: &Token) -> Result<Type, JokerError> {
+        for (scope, narrowed) in self.symbol.iter().zip(&self.narrowed).rev() {
+            if narrowed.contains(&name.lexeme) {
+                continue;
+            }
+            if let Some(type_) = scope.get(&name.lexeme) {
+                return Ok(type_.clone());
+            }
+        }
+        Err(JokerError::Resolver(Env(EnvError::report_error(
+            name,
+            String::from("Expected find type, but not find type."),
+        ))))
     }
     pub fn declare_type(&mut self, name: &Token, ty: Type) -> Result<(), JokerError> {
+        if let Some(narrowed) = self.narrowed.last_mut() {
+            narrowed.remove(&name.lexeme);
+        }
         if let Some(current_scope) = self.symbol.last_mut() {
             current_scope.insert(name.lexeme.clone(), ty);
             Ok(())
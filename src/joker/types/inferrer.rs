@@ -3,12 +3,13 @@
 //!
 //!
 
-use std::collections::HashMap;
+use std::{collections::HashMap, slice};
 
 use crate::joker::{
     ast::{
-        Assign, Binary, Call, ClassStmt, Expr, Getter, Grouping, Lambda, Literal, Logical, Stmt,
-        Super, This, Trinomial, Unary, Variable,
+        Assign, Binary, Call, Cast, ClassStmt, Expr, Getter, Grouping, Index, IndexSet, Is, Lambda,
+        List, Literal, Logical, Map, Match, Pattern, Range, SafeGetter, Stmt, StructInit, Super,
+        Template, This, Trinomial, Tuple, Unary, Variable,
     },
     callable::StructError,
     error::JokerError,
@@ -31,20 +32,99 @@ impl TypeInferrer {
             ParamPair::Normal { param: _, type_ } => type_.clone(),
             ParamPair::Label { type_ } => type_.clone(),
             ParamPair::This { param: _, type_ } => type_.clone(),
+            ParamPair::Default { type_, .. } => type_.clone(),
+            ParamPair::Rest { param: _, type_ } => type_.clone(),
         }
     }
     // parse time:
     pub fn parse_type(parser: &mut Parser) -> Result<Type, JokerError> {
+        let type_: Type = TypeInferrer::parse_nullable_type(parser)?;
+        // union type: T | U, lambda params '|' is close.
+        if parser.is_pipe_params() || !parser.is_match(&[TokenType::Pipeline]) {
+            return Ok(type_);
+        }
+        let mut types: Vec<Type> = vec![type_, TypeInferrer::parse_nullable_type(parser)?];
+        while parser.is_match(&[TokenType::Pipeline]) {
+            types.push(TypeInferrer::parse_nullable_type(parser)?);
+        }
+        Ok(Type::union(types))
+    }
+    fn parse_nullable_type(parser: &mut Parser) -> Result<Type, JokerError> {
+        let type_: Type = TypeInferrer::parse_base_type(parser)?;
+        // nullable type: T?
+        if parser.is_match(&[TokenType::Question]) {
+            Ok(type_.nullable())
+        } else {
+            Ok(type_)
+        }
+    }
+    fn parse_base_type(parser: &mut Parser) -> Result<Type, JokerError> {
+        // list type: [T]
+        if parser.is_match(&[TokenType::LeftBracket]) {
+            let element_type: Type = TypeInferrer::parse_type(parser)?;
+            parser.consume(
+                &[TokenType::RightBracket],
+                String::from("[TypeInferrer::parse_type] Expect ']' after list element type."),
+            )?;
+            return Ok(Type::List(Box::new(element_type)));
+        }
+        // map type: {K: V}
+        if parser.is_match(&[TokenType::LeftBrace]) {
+            let key_type: Type = TypeInferrer::parse_type(parser)?;
+            parser.consume(
+                &[TokenType::Colon],
+                String::from("[TypeInferrer::parse_type] Expect ':' after map key type."),
+            )?;
+            let value_type: Type = TypeInferrer::parse_type(parser)?;
+            parser.consume(
+                &[TokenType::RightBrace],
+                String::from("[TypeInferrer::parse_type] Expect '}' after map value type."),
+            )?;
+            return Ok(Type::Map(Box::new(key_type), Box::new(value_type)));
+        }
+        // tuple type: (T, U), one type in paren is grouping: (i32 | str)?
+        if parser.is_match(&[TokenType::LeftParen]) {
+            let mut types: Vec<Type> = vec![TypeInferrer::parse_type(parser)?];
+            while parser.is_match(&[TokenType::Comma]) {
+                types.push(TypeInferrer::parse_type(parser)?);
+            }
+            parser.consume(
+                &[TokenType::RightParen],
+                String::from("[TypeInferrer::parse_type] Expect ')' after tuple element types."),
+            )?;
+            return Ok(if types.len() == 1 {
+                types.remove(0)
+            } else {
+                Type::Tuple(types)
+            });
+        }
         let type_name: Token = parser.consume(
             &[TokenType::Identifier, TokenType::Null],
             String::from("[TypeInferrer::parse_type] Expect type. but this not is."),
         )?;
+        if let Some(type_) = Type::builtin(&type_name.lexeme) {
+            return Ok(type_);
+        }
+        // imported type: alias.Type
+        if parser.is_match(&[TokenType::Dot]) {
+            let name: Token = parser.consume(
+                &[TokenType::Identifier],
+                String::from("[TypeInferrer::parse_type] Expect type name after '.'."),
+            )?;
+            return Ok(Type::UserDefined(Token::new(
+                TokenType::Identifier,
+                format!("{}.{}", type_name.lexeme, name.lexeme),
+                literal_null(),
+                type_name.line,
+            )));
+        }
+        // type alias declared before, type parameter shadow it.
+        if !parser.is_type_param(&type_name) {
+            if let Some(alias) = parser.alias_type(&type_name) {
+                return Ok(alias);
+            }
+        }
         match type_name.lexeme.as_str() {
-            "i32" => Ok(Type::I32),
-            "f64" => Ok(Type::F64),
-            "str" => Ok(Type::Str),
-            "bool" => Ok(Type::Bool),
-            "null" => Ok(Type::Null),
             "Fn" => {
                 let params: Option<Vec<ParamPair>> = if parser.is_match(&[TokenType::LeftParen]) {
                     let mut params: Vec<ParamPair> = vec![ParamPair::label_with_parse(parser)?];
@@ -71,6 +151,26 @@ impl TypeInferrer {
                     return_type,
                 })
             }
+            // generator type: Gen<T>
+            "Gen" if parser.is_match(&[TokenType::Less]) => {
+                let yield_type: Type = TypeInferrer::parse_type(parser)?;
+                parser.consume_type_args_end()?;
+                Ok(Type::Generator(Box::new(yield_type)))
+            }
+            // type parameter of generic fn or class.
+            _ if parser.is_type_param(&type_name) => Ok(Type::Param(type_name)),
+            // generic class type arguments: Box<i32>
+            _ if parser.is_match(&[TokenType::Less]) => {
+                let mut args: Vec<Type> = vec![TypeInferrer::parse_type(parser)?];
+                while parser.is_match(&[TokenType::Comma]) {
+                    args.push(TypeInferrer::parse_type(parser)?);
+                }
+                parser.consume_type_args_end()?;
+                Ok(Type::Generic {
+                    name: type_name,
+                    args,
+                })
+            }
             // parse don't parse, move to static resolve parse.
             _ => Ok(Type::UserDefined(type_name)),
         }
@@ -80,6 +180,8 @@ impl TypeInferrer {
         match expr {
             Expr::Literal(Literal { value }) => match value {
                 OEnum::Literal(ObL::I32(_)) => Ok(Type::I32),
+                OEnum::Literal(ObL::I64(_)) => Ok(Type::I64),
+                OEnum::Literal(ObL::U8(_)) => Ok(Type::U8),
                 OEnum::Literal(ObL::F64(_)) => Ok(Type::F64),
                 OEnum::Literal(ObL::Bool(_)) => Ok(Type::Bool),
                 OEnum::Literal(ObL::Str(_)) => Ok(Type::Str),
@@ -179,12 +281,32 @@ impl TypeInferrer {
                             None
                         };
 
+                    let statics: Option<HashMap<String, Type>> =
+                        if let Some(statics) = class.statics.as_ref() {
+                            let mut statics_type: HashMap<String, Type> = HashMap::new();
+                            for (name, value) in statics {
+                                let value_type: Type = TypeInferrer::infer_type(
+                                    resolver,
+                                    &Expr::Literal(Literal {
+                                        value: value.get().clone(),
+                                    }),
+                                )?;
+                                statics_type.insert(name.clone(), value_type);
+                            }
+                            Some(statics_type)
+                        } else {
+                            None
+                        };
+
                     Ok(Type::Class {
                         name,
                         super_class,
                         fields,
                         methods,
                         functions,
+                        statics,
+                        // interface is resolver only, runtime class don't keep it.
+                        interfaces: Vec::new(),
                     })
                 }
                 OEnum::Instance(instance) => {
@@ -231,10 +353,77 @@ impl TypeInferrer {
                         fields,
                     })
                 }
+                OEnum::List(list) => match list.elements.borrow().first() {
+                    Some(element) => Ok(Type::List(Box::new(TypeInferrer::infer_type(
+                        resolver,
+                        &Expr::Literal(Literal {
+                            value: element.get().clone(),
+                        }),
+                    )?))),
+                    None => Err(JokerError::Resolver(Struct(StructError::report_error(
+                        &Token::eof(0),
+                        String::from(
+                            "[TypeInferrer::infer_type] Empty list don't inferrer element type.",
+                        ),
+                    )))),
+                },
+                OEnum::Map(map) => match map.order.borrow().first() {
+                    Some(key) => Ok(Type::Map(
+                        Box::new(TypeInferrer::infer_type(
+                            resolver,
+                            &Expr::Literal(Literal {
+                                value: OEnum::Literal(key.clone()),
+                            }),
+                        )?),
+                        Box::new(TypeInferrer::infer_type(
+                            resolver,
+                            &Expr::Literal(Literal {
+                                value: map.table.borrow()[key].get().clone(),
+                            }),
+                        )?),
+                    )),
+                    None => Err(JokerError::Resolver(Struct(StructError::report_error(
+                        &Token::eof(0),
+                        String::from("[TypeInferrer::infer_type] Empty map don't inferrer key and value type."),
+                    )))),
+                },
+                OEnum::StructDecl(struct_decl) => resolver.get_type(&struct_decl.name),
+                OEnum::Struct(struct_) => resolver.get_type(&struct_.name),
+                OEnum::EnumDecl(enum_decl) => resolver.get_type(&enum_decl.name),
+                OEnum::Enum(enum_) => resolver.get_type(&enum_.name),
+                OEnum::Module(module) => resolver.get_type(&module.name),
+                OEnum::Tuple(tuple) => Ok(Type::Tuple(
+                    tuple
+                        .elements
+                        .iter()
+                        .map(|element| {
+                            TypeInferrer::infer_type(
+                                resolver,
+                                &Expr::Literal(Literal {
+                                    value: element.get().clone(),
+                                }),
+                            )
+                        })
+                        .collect::<Result<Vec<Type>, JokerError>>()?,
+                )),
+                OEnum::Range(_) => Ok(Type::Range),
+                OEnum::Generator(generator) => Ok(generator.type_()),
             },
             Expr::Unary(Unary { l_opera, r_expr }) => {
                 let right_type: Type = TypeInferrer::infer_type(resolver, r_expr)?;
-                if matches!(right_type, Type::I32 | Type::F64) {
+                if let Some(method) = l_opera.unary_method() {
+                    if let Some(type_) =
+                        TypeInferrer::operator_type(resolver, l_opera, method, &right_type, &[])?
+                    {
+                        return Ok(type_);
+                    }
+                }
+                if matches!(
+                    (&l_opera.ttype, &right_type),
+                    (TokenType::Minus, Type::I32 | Type::I64 | Type::F64)
+                        | (TokenType::Bang, Type::Bool)
+                        | (TokenType::Tilde, Type::I32 | Type::I64 | Type::U8)
+                ) {
                     Ok(right_type)
                 } else {
                     Err(JokerError::Resolver(Struct(
@@ -255,8 +444,60 @@ impl TypeInferrer {
             }) => {
                 let left_type: Type = TypeInferrer::infer_type(resolver, l_expr)?;
                 let right_type: Type = TypeInferrer::infer_type(resolver, r_expr)?;
+                // null check: nullable or any type compare with null, before operator overload.
+                if matches!(m_opera.ttype, TokenType::EqualEqual | TokenType::BangEqual)
+                    && (left_type == Type::Null
+                        || right_type == Type::Null
+                        || ((left_type.is_nullable() || right_type.is_nullable())
+                            && left_type.non_null().eq_type(right_type.non_null())))
+                {
+                    return Ok(Type::Bool);
+                }
+                // operator overload: left instance class method type.
+                if let Some(method) = m_opera.binary_method() {
+                    if let Some(type_) = TypeInferrer::operator_type(
+                        resolver,
+                        m_opera,
+                        method,
+                        &left_type,
+                        slice::from_ref(&right_type),
+                    )? {
+                        return Ok(type_);
+                    }
+                }
+                // numeric operator: % ** ~/ need number, & ^ need integer or bool, << >> need integer.
+                let is_operand: bool = match m_opera.ttype {
+                    TokenType::Percent | TokenType::StarStar | TokenType::TildeSlash => {
+                        left_type.is_number()
+                    }
+                    TokenType::Amper | TokenType::Caret => {
+                        left_type.is_integer() || left_type == Type::Bool
+                    }
+                    TokenType::LessLess | TokenType::GreaterGreater => left_type.is_integer(),
+                    // type parameter T only know equal compare.
+                    TokenType::EqualEqual | TokenType::BangEqual => true,
+                    _ => !matches!(left_type, Type::Param(_)),
+                };
+                if !is_operand {
+                    return Err(JokerError::Resolver(Struct(StructError::report_error(
+                        m_opera,
+                        format!(
+                            "[TypeInferrer::infer_type] Type mismatch in binary expression, '{}' don't impl operator '{}'.",
+                            left_type, m_opera.lexeme
+                        ),
+                    ))));
+                }
                 if left_type.eq_type(&right_type) {
-                    Ok(left_type)
+                    // compare operator result is bool.
+                    match m_opera.ttype {
+                        TokenType::EqualEqual
+                        | TokenType::BangEqual
+                        | TokenType::Greater
+                        | TokenType::GreaterEqual
+                        | TokenType::Less
+                        | TokenType::LessEqual => Ok(Type::Bool),
+                        _ => Ok(left_type),
+                    }
                 } else {
                     Err(JokerError::Resolver(Struct(
                         StructError::report_error(
@@ -283,6 +524,30 @@ impl TypeInferrer {
             }) => {
                 let left_type: Type = TypeInferrer::infer_type(resolver, l_expr)?;
                 let right_type: Type = TypeInferrer::infer_type(resolver, r_expr)?;
+                // a ?? b: b type need a non null type, result is non null unless b is nullable.
+                if m_opera.ttype == TokenType::QuestionQuestion {
+                    return if left_type == Type::Null {
+                        Ok(right_type)
+                    } else if right_type == Type::Null
+                        || left_type.non_null().is_assignable(right_type.non_null())
+                    {
+                        if right_type.is_nullable() || right_type == Type::Null {
+                            Ok(left_type.nullable())
+                        } else {
+                            Ok(left_type.non_null().clone())
+                        }
+                    } else {
+                        Err(JokerError::Resolver(Struct(
+                            StructError::report_error(
+                                m_opera,
+                                format!("[TypeInferrer::infer_type] Type mismatch in '??' expression, left type '{}' and right type '{}'",
+                                    left_type,
+                                    right_type
+                                ),
+                            ),
+                        )))
+                    };
+                }
                 if left_type.eq_type(&right_type) {
                     Ok(left_type)
                 } else {
@@ -303,7 +568,7 @@ impl TypeInferrer {
                 r_expr,
             }) => {
                 let condition: Type = TypeInferrer::infer_type(resolver, condition)?;
-                if condition.eq_type(&Type::Bool) {
+                if Type::Bool.eq_type(&condition) {
                     let left_type: Type = TypeInferrer::infer_type(resolver, l_expr)?;
                     let right_type: Type = TypeInferrer::infer_type(resolver, r_expr)?;
                     if left_type.eq_type(&right_type) {
@@ -333,15 +598,34 @@ impl TypeInferrer {
                 params,
                 return_type,
                 body: _,
+                destructs: _,
             }) => Ok(Type::Fn {
                 params: params.clone(),
                 return_type: return_type.clone(),
             }),
             Expr::Call(Call {
                 callee,
-                paren: _,
-                arguments: _,
-            }) => TypeInferrer::infer_type(resolver, callee),
+                paren,
+                arguments,
+                named: _,
+            }) => {
+                // function call type is return type, class call type is instance.
+                let callee_type: Type = TypeInferrer::infer_type(resolver, callee)?;
+                // a?.m(): method of nullable caller, return type is nullable.
+                if let Type::Nullable(callee_type) = callee_type {
+                    return Ok(TypeInferrer::call_type(*callee_type, paren)?.nullable());
+                }
+                if let Some(type_) = TypeInferrer::generic_call_type(
+                    resolver,
+                    &callee_type,
+                    arguments,
+                    paren,
+                    None,
+                )? {
+                    return Ok(type_);
+                }
+                TypeInferrer::call_type(callee_type, paren)
+            }
             Expr::Variable(Variable { name }) => resolver.get_type(name),
             Expr::Getter(Getter { expr, name }) => {
                 // var instance: class = class();
@@ -350,59 +634,24 @@ impl TypeInferrer {
                 // through Getter expr type, judgement who handle getter name type, and return type.
                 // inner type need translate: UserDefined to base type.
                 let caller_type: Type = TypeInferrer::infer_type(resolver, expr)?;
-                let caller_type: Type = if let Type::UserDefined(token) = caller_type {
-                    TypeInferrer::infer_type(resolver, &Expr::Variable(Variable { name: token }))?
-                } else {
-                    caller_type
-                };
-
-                match caller_type {
-                    Type::Class {
-                        name: _,
-                        super_class: _,
-                        fields: _,
-                        methods: _,
-                        functions: _,
-                    } => {
-                        if let Some(sub_type) = caller_type.get_type(name)? {
-                            Ok(sub_type.clone())
-                        } else {
-                            Err(JokerError::Resolver(Struct(
-                                StructError::report_error(
-                                    name,
-                                    format!(
-                                        "[TypeInferrer::infer_type] Expected getter class type, found get '{}'.",
-                                        name.lexeme
-                                    ),
-                                ),
-                            )))
-                        }
-                    }
-                    Type::Instance {
-                        class: _,
-                        methods: _,
-                        fields: _,
-                    } => {
-                        if let Some(sub_type) = caller_type.get_type(name)? {
-                            Ok(sub_type.clone())
-                        } else {
-                            Err(JokerError::Resolver(Struct(
-                                StructError::report_error(
-                                    name,
-                                    format!(
-                                        "[TypeInferrer::infer_type] Expected getter instance type, But don't found '{}' type.",
-                                        name.lexeme
-                                    ),
-                                ),
-                            )))
-                        }
-                    }
-                    _ => Err(JokerError::Resolver(Struct(
-                        StructError::report_error(
-                            name,
-                            String::from("[TypeInferrer::infer_type] Expected getter need left variable is impl getter."),
+                if caller_type.is_nullable() {
+                    return Err(JokerError::Resolver(Struct(StructError::report_error(
+                        name,
+                        format!(
+                            "[TypeInferrer::infer_type] Can't get '{}' on nullable type '{}', check null first or use '?.'.",
+                            name.lexeme, caller_type
                         ),
-                    ))),
+                    ))));
+                }
+                TypeInferrer::getter_type(resolver, caller_type, name)
+            }
+            Expr::SafeGetter(SafeGetter { expr, name }) => {
+                // a?.b: nullable caller get inner type, result is nullable.
+                let caller_type: Type = TypeInferrer::infer_type(resolver, expr)?;
+                if let Type::Nullable(caller_type) = caller_type {
+                    Ok(TypeInferrer::getter_type(resolver, *caller_type, name)?.nullable())
+                } else {
+                    TypeInferrer::getter_type(resolver, caller_type, name)
                 }
             }
             Expr::Super(Super { keyword, method }) => {
@@ -431,6 +680,234 @@ impl TypeInferrer {
                 }
             }
             Expr::This(This { keyword }) => resolver.get_type(keyword),
+            Expr::List(List { bracket, elements }) => {
+                let mut elements_iter = elements.iter();
+                let element_type: Type = match elements_iter.next() {
+                    Some(element) => TypeInferrer::infer_type(resolver, element)?,
+                    None => {
+                        return Err(JokerError::Resolver(Struct(StructError::report_error(
+                            bracket,
+                            String::from("[TypeInferrer::infer_type] Empty list don't inferrer element type, need declare type."),
+                        ))))
+                    }
+                };
+                for element in elements_iter {
+                    let other_type: Type = TypeInferrer::infer_type(resolver, element)?;
+                    if !element_type.eq_type(&other_type) {
+                        return Err(JokerError::Resolver(Struct(StructError::report_error(
+                            bracket,
+                            format!(
+                                "[TypeInferrer::infer_type] Type mismatch in list elements, first type '{}' but found '{}'.",
+                                element_type, other_type
+                            ),
+                        ))));
+                    }
+                }
+                Ok(Type::List(Box::new(element_type)))
+            }
+            Expr::Match(Match {
+                keyword,
+                value: _,
+                arms,
+            }) => {
+                let mut match_type: Option<Type> = None;
+                for arm in arms {
+                    let arm_type: Type = TypeInferrer::infer_arm_type(resolver, &arm.pattern, &arm.body)?;
+                    match &match_type {
+                        Some(match_type) if !match_type.eq_type(&arm_type) => {
+                            return Err(JokerError::Resolver(Struct(StructError::report_error(
+                                arm.pattern.token(),
+                                format!(
+                                    "[TypeInferrer::infer_type] Type mismatch in match arms, first type '{}' but found '{}'.",
+                                    match_type, arm_type
+                                ),
+                            ))));
+                        }
+                        Some(_) => {}
+                        None => match_type = Some(arm_type),
+                    }
+                }
+                match match_type {
+                    Some(match_type) => Ok(match_type),
+                    None => Err(JokerError::Resolver(Struct(StructError::report_error(
+                        keyword,
+                        String::from("[TypeInferrer::infer_type] Match don't have arm, can't inferrer type."),
+                    )))),
+                }
+            }
+            Expr::Map(Map { brace, pairs }) => {
+                let mut pairs_iter = pairs.iter();
+                let (key_type, value_type): (Type, Type) = match pairs_iter.next() {
+                    Some((key, value)) => (
+                        TypeInferrer::infer_type(resolver, key)?,
+                        TypeInferrer::infer_type(resolver, value)?,
+                    ),
+                    None => {
+                        return Err(JokerError::Resolver(Struct(StructError::report_error(
+                            brace,
+                            String::from("[TypeInferrer::infer_type] Empty map don't inferrer key and value type, need declare type."),
+                        ))))
+                    }
+                };
+                if !key_type.is_map_key() {
+                    return Err(JokerError::Resolver(Struct(StructError::report_error(
+                        brace,
+                        format!(
+                            "[TypeInferrer::infer_type] Map key type need i32, f64, str or bool, found '{}'.",
+                            key_type
+                        ),
+                    ))));
+                }
+                for (key, value) in pairs_iter {
+                    let other_key: Type = TypeInferrer::infer_type(resolver, key)?;
+                    let other_value: Type = TypeInferrer::infer_type(resolver, value)?;
+                    if !key_type.eq_type(&other_key) || !value_type.eq_type(&other_value) {
+                        return Err(JokerError::Resolver(Struct(StructError::report_error(
+                            brace,
+                            format!(
+                                "[TypeInferrer::infer_type] Type mismatch in map pairs, first type '{}: {}' but found '{}: {}'.",
+                                key_type, value_type, other_key, other_value
+                            ),
+                        ))));
+                    }
+                }
+                Ok(Type::Map(Box::new(key_type), Box::new(value_type)))
+            }
+            Expr::Index(Index {
+                l_expr,
+                bracket,
+                index,
+            }) => {
+                let index_type: Type = TypeInferrer::infer_type(resolver, index)?;
+                let object_type: Type = TypeInferrer::infer_type(resolver, l_expr)?;
+                if let Some(type_) = TypeInferrer::operator_type(
+                    resolver,
+                    bracket,
+                    "index",
+                    &object_type,
+                    slice::from_ref(&index_type),
+                )? {
+                    return Ok(type_);
+                }
+                // tuple index is i32 literal, element type by position.
+                if let Type::Tuple(types) = &object_type {
+                    return match index.as_ref() {
+                        Expr::Literal(Literal {
+                            value: OEnum::Literal(ObL::I32(i32_)),
+                        }) if *i32_ >= 0 && (*i32_ as usize) < types.len() => {
+                            Ok(types[*i32_ as usize].clone())
+                        }
+                        _ => Err(JokerError::Resolver(Struct(StructError::report_error(
+                            bracket,
+                            format!(
+                                "[TypeInferrer::infer_type] Tuple '{}' index need i32 literal in 0..{}.",
+                                object_type,
+                                types.len()
+                            ),
+                        )))),
+                    };
+                }
+                let (key_type, value_type): (Type, Type) =
+                    match object_type {
+                        Type::List(element_type) => (Type::I32, *element_type),
+                        Type::Map(key_type, value_type) => (*key_type, *value_type),
+                        other => {
+                            return Err(JokerError::Resolver(Struct(StructError::report_error(
+                                bracket,
+                                format!(
+                                    "[TypeInferrer::infer_type] Expected index list or map type, found '{}'.",
+                                    other
+                                ),
+                            ))))
+                        }
+                    };
+                if !index_type.eq_type(&key_type) {
+                    return Err(JokerError::Resolver(Struct(StructError::report_error(
+                        bracket,
+                        format!(
+                            "[TypeInferrer::infer_type] Expected index type '{}', found '{}'.",
+                            key_type, index_type
+                        ),
+                    ))));
+                }
+                Ok(value_type)
+            }
+            Expr::StructInit(StructInit { name, fields: _ }) => {
+                let struct_type: Type = resolver.get_type(name)?;
+                if struct_type.is_struct() {
+                    Ok(struct_type)
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        name,
+                        format!(
+                            "[TypeInferrer::infer_type] Expected struct type, found '{}'.",
+                            struct_type
+                        ),
+                    ))))
+                }
+            }
+            Expr::Template(Template { token: _, parts }) => {
+                for part in parts {
+                    TypeInferrer::infer_type(resolver, part)?;
+                }
+                Ok(Type::Str)
+            }
+            Expr::Cast(Cast {
+                expr,
+                keyword,
+                target,
+            }) => {
+                let source: Type = TypeInferrer::infer_type(resolver, expr)?;
+                if source.can_cast(target)
+                    || TypeInferrer::is_class_cast(resolver, &source, target)?
+                {
+                    Ok(*target.clone())
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        keyword,
+                        format!(
+                            "[TypeInferrer::infer_type] Can't cast type '{}' to '{}'.",
+                            source, target
+                        ),
+                    ))))
+                }
+            }
+            // x is T: runtime type test.
+            Expr::Is(Is {
+                expr,
+                keyword: _,
+                target: _,
+            }) => {
+                TypeInferrer::infer_type(resolver, expr)?;
+                Ok(Type::Bool)
+            }
+            Expr::Tuple(Tuple { paren: _, elements }) => Ok(Type::Tuple(
+                elements
+                    .iter()
+                    .map(|element| TypeInferrer::infer_type(resolver, element))
+                    .collect::<Result<Vec<Type>, JokerError>>()?,
+            )),
+            Expr::Range(Range { start, opera, end }) => {
+                for bound in [start, end] {
+                    let bound_type: Type = TypeInferrer::infer_type(resolver, bound)?;
+                    if bound_type != Type::I32 {
+                        return Err(JokerError::Resolver(Struct(StructError::report_error(
+                            opera,
+                            format!(
+                                "[TypeInferrer::infer_type] Range bound need i32, found '{}'.",
+                                bound_type
+                            ),
+                        ))));
+                    }
+                }
+                Ok(Type::Range)
+            }
+            Expr::IndexSet(IndexSet { bracket, .. }) => {
+                Err(JokerError::Resolver(Struct(StructError::report_error(
+                    bracket,
+                    String::from("[TypeInferrer::infer_type] IndexSet don't inferrer type."),
+                ))))
+            }
             _ => Err(JokerError::Resolver(Struct(StructError::report_error(
                 &Token::eof(0),
                 format!(
@@ -440,6 +917,849 @@ impl TypeInferrer {
             )))),
         }
     }
+    // callee type to call result type.
+    // class instance cast: self or super class is up cast, sub class is down cast checked at runtime.
+    fn is_class_cast(
+        resolver: &Resolver,
+        source: &Type,
+        target: &Type,
+    ) -> Result<bool, JokerError> {
+        let (Type::Instance { class, .. }, Type::UserDefined(name)) = (source, target) else {
+            return Ok(false);
+        };
+        let unwrap_this = |type_: Type| match type_ {
+            Type::This(class) => *class,
+            type_ => type_,
+        };
+        let source_class: Type = unwrap_this(*class.clone());
+        let target_class: Type = unwrap_this(resolver.get_type(name)?);
+        if !source_class.is_class() || !target_class.is_class() {
+            return Ok(false);
+        }
+        Ok(source_class.eq_type(&target_class)
+            || IsInstance::is_inherit(&source_class, &target_class)?
+            || IsInstance::is_inherit(&target_class, &source_class)?)
+    }
+    fn call_type(callee_type: Type, paren: &Token) -> Result<Type, JokerError> {
+        match callee_type {
+            Type::Fn {
+                params: _,
+                return_type: Some(return_type),
+            } => Ok(*return_type),
+            Type::Fn {
+                params: _,
+                return_type: None,
+            } => Err(JokerError::Resolver(Struct(StructError::report_error(
+                paren,
+                String::from("[TypeInferrer::infer_type] Call function don't have return type."),
+            )))),
+            Type::Class {
+                name: _,
+                super_class: _,
+                fields: _,
+                methods: _,
+                functions: _,
+                statics: _,
+                interfaces: _,
+            } => Ok(Type::Instance {
+                class: Box::new(callee_type),
+                methods: None,
+                fields: None,
+            }),
+            // class name call inside self class body.
+            Type::This(class) => Ok(Type::Instance {
+                class,
+                methods: None,
+                fields: None,
+            }),
+            _ => Ok(callee_type),
+        }
+    }
+    // call arguments bind to parameters ('this' skipped): positional by order, named by
+    // parameter name, extra positional to rest element, missing parameter need default.
+    pub fn bind_arguments<'a>(
+        params: &[ParamPair],
+        call: &'a Call,
+    ) -> Result<Vec<(&'a Expr, Type)>, JokerError> {
+        let error = |msg: String| -> Result<Vec<(&'a Expr, Type)>, JokerError> {
+            Err(JokerError::Resolver(Struct(StructError::report_error(
+                &call.paren,
+                msg,
+            ))))
+        };
+        let (fixed, rest): (&[ParamPair], Option<&ParamPair>) = match params {
+            [fixed @ .., rest] if rest.is_rest() => (fixed, Some(rest)),
+            fixed => (fixed, None),
+        };
+        let is_fixed: bool = rest.is_none() && !fixed.iter().any(|param| param.is_default());
+        if is_fixed && call.named.is_empty() && call.arguments.len() != fixed.len() {
+            return error(format!(
+                "Expected {} arguments but got {}.",
+                fixed.len(),
+                call.arguments.len()
+            ));
+        }
+        if rest.is_none() && call.arguments.len() > fixed.len() {
+            return error(format!(
+                "Expected at most {} arguments but got {}.",
+                fixed.len(),
+                call.arguments.len()
+            ));
+        }
+        let mut bound: Vec<(&'a Expr, Type)> = Vec::new();
+        let mut given: Vec<bool> = vec![false; fixed.len()];
+        for (index, arg) in call.arguments.iter().enumerate() {
+            match (fixed.get(index), rest) {
+                (Some(param), _) => {
+                    given[index] = true;
+                    bound.push((arg, param.get_type().clone()));
+                }
+                (
+                    None,
+                    Some(ParamPair::Rest {
+                        param: _,
+                        type_: Type::List(element),
+                    }),
+                ) => bound.push((arg, *element.clone())),
+                (None, _) => unreachable!("[TypeInferrer::bind_arguments] checked extra argument."),
+            }
+        }
+        for (name, arg) in &call.named {
+            let index: Option<usize> = fixed.iter().position(|param| {
+                param
+                    .get_param()
+                    .is_some_and(|param| param.lexeme.eq(&name.lexeme))
+            });
+            match index {
+                Some(index) if given[index] => {
+                    return error(format!(
+                        "Argument '{}' is given more than once.",
+                        name.lexeme
+                    ))
+                }
+                Some(index) => {
+                    given[index] = true;
+                    bound.push((arg, fixed[index].get_type().clone()));
+                }
+                None => {
+                    return error(format!(
+                        "Function has no parameter named '{}'.",
+                        name.lexeme
+                    ))
+                }
+            }
+        }
+        if let Some((param, _)) = fixed
+            .iter()
+            .zip(&given)
+            .find(|(param, given)| !**given && !param.is_default())
+        {
+            return error(format!(
+                "Missing argument for parameter '{}'.",
+                param
+                    .get_param()
+                    .map_or(String::from("_"), |param| param.lexeme.clone())
+            ));
+        }
+        Ok(bound)
+    }
+    // bind type parameters of generic params by arguments type.
+    pub fn instantiate(
+        resolver: &Resolver,
+        params: &[ParamPair],
+        arguments: &[Expr],
+        paren: &Token,
+    ) -> Result<HashMap<String, Type>, JokerError> {
+        let mut bounds: HashMap<String, Type> = HashMap::new();
+        TypeInferrer::unify_arguments(resolver, params, arguments, paren, &mut bounds)?;
+        Ok(bounds)
+    }
+    fn unify_arguments(
+        resolver: &Resolver,
+        params: &[ParamPair],
+        arguments: &[Expr],
+        paren: &Token,
+        bounds: &mut HashMap<String, Type>,
+    ) -> Result<(), JokerError> {
+        for (param, arg) in params.iter().zip(arguments) {
+            let param_type: &Type = param.get_type();
+            if !param_type.contains_param() {
+                continue;
+            }
+            let arg_type: Type = TypeInferrer::infer_type(resolver, arg)?;
+            if let Err(msg) = param_type.unify(&arg_type, bounds) {
+                return Err(JokerError::Resolver(Struct(StructError::report_error(
+                    paren,
+                    format!(
+                        "[TypeInferrer::instantiate] Expected argument of type '{}' but got '{}', {}.",
+                        param_type, arg_type, msg
+                    ),
+                ))));
+            }
+        }
+        Ok(())
+    }
+    // generic fn call type: return type bind type arguments.
+    // generic class call type: Box<T> bind by init arguments or expected declared type.
+    pub fn generic_call_type(
+        resolver: &Resolver,
+        callee_type: &Type,
+        arguments: &[Expr],
+        paren: &Token,
+        expected: Option<&Type>,
+    ) -> Result<Option<Type>, JokerError> {
+        match callee_type {
+            Type::Fn {
+                params: Some(params),
+                return_type: _,
+            } if callee_type.contains_param() => {
+                let params: &[ParamPair] = match params.first() {
+                    Some(param) if param.is_this() => &params[1..],
+                    _ => params,
+                };
+                let bounds: HashMap<String, Type> =
+                    TypeInferrer::instantiate(resolver, params, arguments, paren)?;
+                Ok(Some(TypeInferrer::call_type(
+                    callee_type.substitute(&bounds),
+                    paren,
+                )?))
+            }
+            Type::Class { name, .. } => {
+                let Some(generics) = resolver.type_params(name) else {
+                    return Ok(None);
+                };
+                let mut bounds: HashMap<String, Type> = match expected {
+                    Some(Type::Generic {
+                        name: expected_name,
+                        args,
+                    }) if expected_name.lexeme == name.lexeme => {
+                        resolver.type_arguments(expected_name, args)?
+                    }
+                    _ => HashMap::new(),
+                };
+                if let Some(Type::Fn {
+                    params: Some(params),
+                    return_type: _,
+                }) = callee_type.get_method("init")
+                {
+                    TypeInferrer::unify_arguments(
+                        resolver,
+                        &params[1..],
+                        arguments,
+                        paren,
+                        &mut bounds,
+                    )?;
+                }
+                let args: Vec<Type> = generics
+                    .iter()
+                    .map(|generic| {
+                        bounds.get(&generic.lexeme).cloned().ok_or_else(|| {
+                            JokerError::Resolver(Struct(StructError::report_error(
+                                paren,
+                                format!(
+                                    "[TypeInferrer::generic_call_type] Can't infer type parameter '{}' of class '{}', declare type like '{}<..>'.",
+                                    generic.lexeme, name.lexeme, name.lexeme
+                                ),
+                            )))
+                        })
+                    })
+                    .collect::<Result<Vec<Type>, JokerError>>()?;
+                Ok(Some(Type::Generic {
+                    name: name.clone(),
+                    args,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+    // getter name type of caller type: class, instance, struct, enum, module, interface and builtin.
+    fn getter_type(
+        resolver: &Resolver,
+        caller_type: Type,
+        name: &Token,
+    ) -> Result<Type, JokerError> {
+        let caller_type: Type = match caller_type {
+            // generic class instance: member type bind type arguments.
+            Type::Generic { name: class, args } => {
+                let bounds: HashMap<String, Type> = resolver.type_arguments(&class, &args)?;
+                let member_type: Type = TypeInferrer::getter_type(
+                    resolver,
+                    Type::Instance {
+                        class: Box::new(resolver.get_type(&class)?),
+                        methods: None,
+                        fields: None,
+                    },
+                    name,
+                )?;
+                return Ok(member_type.substitute(&bounds));
+            }
+            Type::UserDefined(token) => {
+                TypeInferrer::infer_type(resolver, &Expr::Variable(Variable { name: token }))?
+            }
+            // class name inside self class body.
+            Type::This(class) => *class,
+            caller_type => caller_type,
+        };
+
+        match caller_type {
+            Type::Class {
+                name: _,
+                super_class: _,
+                fields: _,
+                methods: _,
+                functions: _,
+                statics: _,
+                interfaces: _,
+            } => {
+                if let Some(sub_type) = caller_type.get_type(name)? {
+                    Ok(sub_type.clone())
+                } else {
+                    Err(JokerError::Resolver(Struct(
+                            StructError::report_error(
+                                name,
+                                format!(
+                                    "[TypeInferrer::infer_type] Expected getter class type, found get '{}'.",
+                                    name.lexeme
+                                ),
+                            ),
+                        )))
+                }
+            }
+            Type::Instance {
+                class: _,
+                methods: _,
+                fields: _,
+            } => {
+                if let Some(sub_type) = caller_type.get_type(name)? {
+                    Ok(sub_type.clone())
+                } else {
+                    Err(JokerError::Resolver(Struct(
+                            StructError::report_error(
+                                name,
+                                format!(
+                                    "[TypeInferrer::infer_type] Expected getter instance type, But don't found '{}' type.",
+                                    name.lexeme
+                                ),
+                            ),
+                        )))
+                }
+            }
+            Type::Struct {
+                name: ref struct_name,
+                fields: _,
+                module: _,
+            } => {
+                if let Some(field_type) = caller_type.get_type(name)? {
+                    Ok(field_type.clone())
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        name,
+                        format!(
+                            "[TypeInferrer::infer_type] Struct '{}' don't have field '{}'.",
+                            struct_name.lexeme, name.lexeme
+                        ),
+                    ))))
+                }
+            }
+            Type::Enum {
+                name: _,
+                variants: _,
+                module: _,
+            } => TypeInferrer::enum_variant_type(name, &caller_type),
+            Type::Module {
+                name: ref module_name,
+                exports: _,
+                module: _,
+            } => {
+                if let Some(export_type) = caller_type.get_type(name)? {
+                    Ok(export_type.clone())
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        name,
+                        format!(
+                            "[TypeInferrer::infer_type] Module '{}' don't export '{}'.",
+                            module_name.lexeme, name.lexeme
+                        ),
+                    ))))
+                }
+            }
+            Type::Interface {
+                name: ref interface_name,
+                methods: _,
+                module: _,
+            } => {
+                if let Some(method_type) = caller_type.get_type(name)? {
+                    Ok(method_type.clone())
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        name,
+                        format!(
+                            "[TypeInferrer::infer_type] Interface '{}' don't have method '{}'.",
+                            interface_name.lexeme, name.lexeme
+                        ),
+                    ))))
+                }
+            }
+            Type::List(element_type) => TypeInferrer::list_method_type(name, &element_type),
+            Type::I32 | Type::I64 | Type::U8 | Type::F64 => TypeInferrer::number_method_type(name),
+            Type::Map(key_type, value_type) => {
+                TypeInferrer::map_method_type(name, &key_type, &value_type)
+            }
+            Type::Range => TypeInferrer::range_method_type(name),
+            Type::Generator(yield_type) => TypeInferrer::generator_method_type(name, &yield_type),
+            _ => Err(JokerError::Resolver(Struct(StructError::report_error(
+                name,
+                String::from(
+                    "[TypeInferrer::infer_type] Expected getter need left variable is impl getter.",
+                ),
+            )))),
+        }
+    }
+    // arm body type, class pattern binding type declare in arm scope.
+    fn infer_arm_type(
+        resolver: &Resolver,
+        pattern: &Pattern,
+        body: &Expr,
+    ) -> Result<Type, JokerError> {
+        if let Pattern::Class {
+            name: _,
+            binding: Some(binding),
+        } = pattern
+        {
+            let binding_type: Type = TypeInferrer::infer_pattern_type(resolver, pattern)?
+                .unwrap_or_else(|| {
+                    unreachable!("[TypeInferrer::infer_arm_type] class pattern have type.")
+                });
+            resolver.type_env.borrow_mut().begin_scope();
+            let arm_type: Result<Type, JokerError> = resolver
+                .declare_type(binding, binding_type)
+                .and_then(|_| TypeInferrer::infer_type(resolver, body));
+            resolver.type_env.borrow_mut().end_scope();
+            arm_type
+        } else if let Pattern::Variant { bindings, .. } = pattern {
+            let payload: Vec<Type> = TypeInferrer::infer_variant_payload(resolver, pattern)?;
+            resolver.type_env.borrow_mut().begin_scope();
+            let arm_type: Result<Type, JokerError> = bindings
+                .iter()
+                .zip(payload)
+                .filter(|(binding, _)| !binding.lexeme.eq("_"))
+                .try_for_each(|(binding, binding_type)| {
+                    resolver.declare_type(binding, binding_type)
+                })
+                .and_then(|_| TypeInferrer::infer_type(resolver, body));
+            resolver.type_env.borrow_mut().end_scope();
+            arm_type
+        } else {
+            TypeInferrer::infer_type(resolver, body)
+        }
+    }
+    // pattern test value type: literal type, range bound type, class instance type, wildcard None.
+    pub fn infer_pattern_type(
+        resolver: &Resolver,
+        pattern: &Pattern,
+    ) -> Result<Option<Type>, JokerError> {
+        match pattern {
+            Pattern::Literal { token: _, value } => Ok(Some(TypeInferrer::infer_type(
+                resolver,
+                &Expr::Literal(Literal {
+                    value: value.clone(),
+                }),
+            )?)),
+            Pattern::Range {
+                token,
+                start,
+                end,
+                inclusive: _,
+            } => {
+                let start_type: Type = TypeInferrer::infer_type(
+                    resolver,
+                    &Expr::Literal(Literal {
+                        value: start.clone(),
+                    }),
+                )?;
+                let end_type: Type = TypeInferrer::infer_type(
+                    resolver,
+                    &Expr::Literal(Literal { value: end.clone() }),
+                )?;
+                if matches!(start_type, Type::I32 | Type::F64) && start_type.eq_type(&end_type) {
+                    Ok(Some(start_type))
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        token,
+                        format!(
+                            "[TypeInferrer::infer_pattern_type] Range pattern need same i32 or f64 bound, found '{}' and '{}'.",
+                            start_type, end_type
+                        ),
+                    ))))
+                }
+            }
+            Pattern::Class { name, binding: _ } => {
+                // builtin type pattern: union member i32(n).
+                if let Some(type_) = Type::builtin(&name.lexeme) {
+                    return Ok(Some(type_));
+                }
+                let class_type: Type = resolver.get_type(name)?;
+                if class_type.is_class() {
+                    Ok(Some(Type::Instance {
+                        class: Box::new(class_type),
+                        methods: None,
+                        fields: None,
+                    }))
+                } else if class_type.is_struct() {
+                    Ok(Some(class_type))
+                } else {
+                    Err(JokerError::Resolver(Struct(StructError::report_error(
+                        name,
+                        format!(
+                            "[TypeInferrer::infer_pattern_type] Class pattern need class or struct, found '{}'.",
+                            class_type
+                        ),
+                    ))))
+                }
+            }
+            Pattern::Variant { name, .. } => {
+                TypeInferrer::infer_variant_payload(resolver, pattern)?;
+                Ok(Some(resolver.get_type(name)?))
+            }
+            Pattern::Wildcard { token: _ } => Ok(None),
+        }
+    }
+    // variant pattern payload types, check enum, variant and bindings count.
+    pub fn infer_variant_payload(
+        resolver: &Resolver,
+        pattern: &Pattern,
+    ) -> Result<Vec<Type>, JokerError> {
+        if let Pattern::Variant {
+            name,
+            variant,
+            bindings,
+        } = pattern
+        {
+            match resolver.get_type(name)? {
+                Type::Enum { name: _, variants, module: _ } => {
+                    match variants.into_iter().find(|(other, _)| other.eq(&variant.lexeme)) {
+                        Some((_, payload)) if payload.len() == bindings.len() => Ok(payload),
+                        Some((_, payload)) => Err(JokerError::Resolver(Struct(StructError::report_error(
+                            variant,
+                            format!(
+                                "[TypeInferrer::infer_variant_payload] Variant '{}.{}' have {} payload, but pattern bind {}.",
+                                name.lexeme, variant.lexeme, payload.len(), bindings.len()
+                            ),
+                        )))),
+                        None => Err(JokerError::Resolver(Struct(StructError::report_error(
+                            variant,
+                            format!(
+                                "[TypeInferrer::infer_variant_payload] Enum '{}' don't have variant '{}'.",
+                                name.lexeme, variant.lexeme
+                            ),
+                        )))),
+                    }
+                }
+                other => Err(JokerError::Resolver(Struct(StructError::report_error(
+                    name,
+                    format!(
+                        "[TypeInferrer::infer_variant_payload] Variant pattern need enum, found '{}'.",
+                        other
+                    ),
+                )))),
+            }
+        } else {
+            unreachable!("[TypeInferrer::infer_variant_payload] pattern need variant.")
+        }
+    }
+    // enum variant type: no payload variant is enum, other is constructor Fn(payload) -> enum.
+    pub fn enum_variant_type(name: &Token, enum_type: &Type) -> Result<Type, JokerError> {
+        if let Type::Enum {
+            name: enum_name,
+            variants,
+            module: _,
+        } = enum_type
+        {
+            match variants
+                .iter()
+                .find(|(variant, _)| variant.eq(&name.lexeme))
+            {
+                Some((_, payload)) if payload.is_empty() => Ok(enum_type.clone()),
+                Some((_, payload)) => Ok(Type::Fn {
+                    params: Some(
+                        payload
+                            .iter()
+                            .enumerate()
+                            .map(|(i, type_)| {
+                                ParamPair::normal(
+                                    Token::new(
+                                        TokenType::Identifier,
+                                        format!("_{}", i),
+                                        literal_null(),
+                                        name.line,
+                                    ),
+                                    type_.clone(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                    return_type: Some(Box::new(enum_type.clone())),
+                }),
+                None => Err(JokerError::Resolver(Struct(StructError::report_error(
+                    name,
+                    format!(
+                        "[TypeInferrer::enum_variant_type] Enum '{}' don't have variant '{}'.",
+                        enum_name.lexeme, name.lexeme
+                    ),
+                )))),
+            }
+        } else {
+            unreachable!("[TypeInferrer::enum_variant_type] type need enum.")
+        }
+    }
+    // list bound method type: len: Fn() -> i32, push: Fn(T), pop: Fn() -> T
+    // operator overload: instance class method `name(this, args...)` type is return type, not instance is None.
+    pub fn operator_type(
+        resolver: &Resolver,
+        opera: &Token,
+        name: &str,
+        operand: &Type,
+        args: &[Type],
+    ) -> Result<Option<Type>, JokerError> {
+        let instance_type: Type = match operand {
+            Type::Instance {
+                class: _,
+                fields: _,
+                methods: _,
+            } => operand.clone(),
+            // method declared return type is class name.
+            Type::UserDefined(token) => match resolver.get_type(token)? {
+                class @ (Type::Class { .. } | Type::This(_)) => Type::Instance {
+                    class: Box::new(class),
+                    fields: None,
+                    methods: None,
+                },
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let (params, return_type) = match instance_type.get_method(name) {
+            Some(Type::Fn {
+                params,
+                return_type,
+            }) => (params.clone().unwrap_or_default(), return_type.clone()),
+            _ if name.eq("ne") => {
+                // a != b => !a.eq(b)
+                return match TypeInferrer::operator_type(resolver, opera, "eq", operand, args)? {
+                    Some(_) => Ok(Some(Type::Bool)),
+                    None => Ok(None),
+                };
+            }
+            _ => {
+                return Err(JokerError::Resolver(Struct(StructError::report_error(
+                    opera,
+                    format!(
+                        "[TypeInferrer::operator_type] Type '{}' don't impl operator '{}', need method '{}'.",
+                        operand, opera.lexeme, name
+                    ),
+                ))))
+            }
+        };
+        if params.len() != args.len() + 1 || !params[0].is_this() {
+            return Err(JokerError::Resolver(Struct(StructError::report_error(
+                opera,
+                format!(
+                    "[TypeInferrer::operator_type] Operator method '{}' need 'this' and {} parameter.",
+                    name,
+                    args.len()
+                ),
+            ))));
+        }
+        for (param, arg) in params[1..].iter().zip(args) {
+            let param_type: &Type = param.get_type();
+            let is_match: bool = param_type.eq_type(arg)
+                || match param_type {
+                    Type::UserDefined(token) => {
+                        let param_type: Type = resolver.get_type(token)?;
+                        arg.is_instance()
+                            && (param_type.is_class() || param_type.is_interface())
+                            && IsInstance::is_instance(arg, &param_type)?
+                    }
+                    _ => false,
+                };
+            if !is_match {
+                return Err(JokerError::Resolver(Struct(StructError::report_error(
+                    opera,
+                    format!(
+                        "[TypeInferrer::operator_type] Operator method '{}' expected argument type '{}', found '{}'.",
+                        name, param_type, arg
+                    ),
+                ))));
+            }
+        }
+        // declared class name return type is instance.
+        Ok(Some(match return_type.map(|return_type| *return_type) {
+            Some(Type::UserDefined(token)) => match resolver.get_type(&token)? {
+                class @ (Type::Class { .. } | Type::This(_)) => Type::Instance {
+                    class: Box::new(class),
+                    fields: None,
+                    methods: None,
+                },
+                _ => Type::UserDefined(token),
+            },
+            Some(return_type) => return_type,
+            None => Type::Null,
+        }))
+    }
+    pub fn list_method_type(name: &Token, element_type: &Type) -> Result<Type, JokerError> {
+        match name.lexeme.as_str() {
+            "len" => Ok(Type::Fn {
+                params: None,
+                return_type: Some(Box::new(Type::I32)),
+            }),
+            "push" => Ok(Type::Fn {
+                params: Some(vec![ParamPair::normal(
+                    Token::new(
+                        TokenType::Identifier,
+                        String::from("value"),
+                        literal_null(),
+                        name.line,
+                    ),
+                    element_type.clone(),
+                )]),
+                return_type: None,
+            }),
+            "pop" => Ok(Type::Fn {
+                params: None,
+                return_type: Some(Box::new(element_type.clone())),
+            }),
+            _ => Err(JokerError::Resolver(Struct(StructError::report_error(
+                name,
+                format!(
+                    "[TypeInferrer::list_method_type] List don't have method '{}'.",
+                    name.lexeme
+                ),
+            )))),
+        }
+    }
+    // number bound method type: to_i32: Fn() -> i32, to_i64: Fn() -> i64, to_u8: Fn() -> u8,
+    // to_f64: Fn() -> f64
+    pub fn number_method_type(name: &Token) -> Result<Type, JokerError> {
+        let return_type: Type = match name.lexeme.as_str() {
+            "to_i32" => Type::I32,
+            "to_i64" => Type::I64,
+            "to_u8" => Type::U8,
+            "to_f64" => Type::F64,
+            _ => {
+                return Err(JokerError::Resolver(Struct(StructError::report_error(
+                    name,
+                    format!(
+                        "[TypeInferrer::number_method_type] Number don't have method '{}'.",
+                        name.lexeme
+                    ),
+                ))))
+            }
+        };
+        Ok(Type::Fn {
+            params: None,
+            return_type: Some(Box::new(return_type)),
+        })
+    }
+    // map bound method type: get: Fn(K, V) -> V, set: Fn(K, V), delete: Fn(K) -> bool,
+    // contains: Fn(K) -> bool, keys: Fn() -> [K], values: Fn() -> [V], len: Fn() -> i32
+    pub fn map_method_type(
+        name: &Token,
+        key_type: &Type,
+        value_type: &Type,
+    ) -> Result<Type, JokerError> {
+        let param = |lexeme: &str, type_: &Type| -> ParamPair {
+            ParamPair::normal(
+                Token::new(
+                    TokenType::Identifier,
+                    String::from(lexeme),
+                    literal_null(),
+                    name.line,
+                ),
+                type_.clone(),
+            )
+        };
+        match name.lexeme.as_str() {
+            "get" => Ok(Type::Fn {
+                params: Some(vec![param("key", key_type), param("default", value_type)]),
+                return_type: Some(Box::new(value_type.clone())),
+            }),
+            "set" => Ok(Type::Fn {
+                params: Some(vec![param("key", key_type), param("value", value_type)]),
+                return_type: None,
+            }),
+            "delete" | "contains" => Ok(Type::Fn {
+                params: Some(vec![param("key", key_type)]),
+                return_type: Some(Box::new(Type::Bool)),
+            }),
+            "keys" => Ok(Type::Fn {
+                params: None,
+                return_type: Some(Box::new(Type::List(Box::new(key_type.clone())))),
+            }),
+            "values" => Ok(Type::Fn {
+                params: None,
+                return_type: Some(Box::new(Type::List(Box::new(value_type.clone())))),
+            }),
+            "len" => Ok(Type::Fn {
+                params: None,
+                return_type: Some(Box::new(Type::I32)),
+            }),
+            _ => Err(JokerError::Resolver(Struct(StructError::report_error(
+                name,
+                format!(
+                    "[TypeInferrer::map_method_type] Map don't have method '{}'.",
+                    name.lexeme
+                ),
+            )))),
+        }
+    }
+    // range bound method type: step: Fn(i32) -> range, contains: Fn(i32) -> bool, len: Fn() -> i32
+    // generator next() resume body, yield value or null when finish.
+    pub fn generator_method_type(name: &Token, yield_type: &Type) -> Result<Type, JokerError> {
+        match name.lexeme.as_str() {
+            "next" => Ok(Type::Fn {
+                params: None,
+                return_type: Some(Box::new(yield_type.clone().nullable())),
+            }),
+            _ => Err(JokerError::Resolver(Struct(StructError::report_error(
+                name,
+                format!(
+                    "[TypeInferrer::generator_method_type] Generator don't have method '{}'.",
+                    name.lexeme
+                ),
+            )))),
+        }
+    }
+    pub fn range_method_type(name: &Token) -> Result<Type, JokerError> {
+        let param = |lexeme: &str| -> Option<Vec<ParamPair>> {
+            Some(vec![ParamPair::normal(
+                Token::new(
+                    TokenType::Identifier,
+                    String::from(lexeme),
+                    literal_null(),
+                    name.line,
+                ),
+                Type::I32,
+            )])
+        };
+        let (params, return_type): (Option<Vec<ParamPair>>, Type) = match name.lexeme.as_str() {
+            "step" => (param("step"), Type::Range),
+            "contains" => (param("value"), Type::Bool),
+            "len" => (None, Type::I32),
+            _ => {
+                return Err(JokerError::Resolver(Struct(StructError::report_error(
+                    name,
+                    format!(
+                        "[TypeInferrer::range_method_type] Range don't have method '{}'.",
+                        name.lexeme
+                    ),
+                ))))
+            }
+        };
+        Ok(Type::Fn {
+            params,
+            return_type: Some(Box::new(return_type)),
+        })
+    }
     pub fn infer_class_stmt(resolver: &Resolver, stmt: &ClassStmt) -> Result<Type, JokerError> {
         let name: Token = stmt.name.clone();
         let super_class: Option<Box<Type>> = if let Some(super_class) = stmt.super_class.as_ref() {
@@ -452,19 +1772,25 @@ impl TypeInferrer {
             let mut fields_type: HashMap<String, Type> = HashMap::new();
             for stmt in fields {
                 if let Stmt::VarStmt(var_stmt) = stmt {
-                    let value_type: Type = if let Some(value) = var_stmt.value.as_ref() {
-                        TypeInferrer::infer_type(resolver, value)?
-                    } else if let Some(declared_type) = var_stmt.type_.as_ref() {
-                        declared_type.clone()
-                    } else {
-                        return Err(JokerError::Resolver(Struct(StructError::report_error(
-                            &var_stmt.name,
-                            format!(
-                                "class fields variable need some type, but this '{}' not.",
-                                var_stmt.name.lexeme
-                            ),
-                        ))));
-                    };
+                    let is_empty: bool = matches!(var_stmt.value.as_ref(), Some(Expr::List(List { bracket: _, elements })) if elements.is_empty())
+                        || matches!(var_stmt.value.as_ref(), Some(Expr::Map(Map { brace: _, pairs })) if pairs.is_empty());
+                    let value_type: Type =
+                        if let (true, Some(declared_type)) = (is_empty, var_stmt.type_.as_ref()) {
+                            // empty list or map field: element type from declared type.
+                            declared_type.clone()
+                        } else if let Some(value) = var_stmt.value.as_ref() {
+                            TypeInferrer::infer_type(resolver, value)?
+                        } else if let Some(declared_type) = var_stmt.type_.as_ref() {
+                            declared_type.clone()
+                        } else {
+                            return Err(JokerError::Resolver(Struct(StructError::report_error(
+                                &var_stmt.name,
+                                format!(
+                                    "class fields variable need some type, but this '{}' not.",
+                                    var_stmt.name.lexeme
+                                ),
+                            ))));
+                        };
                     fields_type.insert(var_stmt.name.lexeme.clone(), value_type);
                 } else {
                     unreachable!("[TypeInferrer::infer_class_stmt]: unreachable this arm.")
@@ -480,6 +1806,25 @@ impl TypeInferrer {
             let mut methods_type: HashMap<String, Type> = HashMap::new();
             for stmt in methods {
                 if let Stmt::FnStmt(fn_stmt) = stmt {
+                    // print and string template use `str(this) -> str`, compare operator need bool.
+                    let operator_return: Option<Type> = match fn_stmt.name.lexeme.as_str() {
+                        "str" => Some(Type::Str),
+                        "eq" | "lt" | "le" | "gt" | "ge" => Some(Type::Bool),
+                        _ => None,
+                    };
+                    if let Some(operator_return) = operator_return {
+                        if fn_stmt.return_type.as_deref() != Some(&operator_return) {
+                            return Err(JokerError::Resolver(Struct(StructError::report_error(
+                                &fn_stmt.name,
+                                format!(
+                                    "[TypeInferrer::infer_class_stmt] Operator method '{}' need return type '{}', found '{}'.",
+                                    fn_stmt.name.lexeme,
+                                    operator_return,
+                                    fn_stmt.return_type.as_deref().map_or(String::from("None"), Type::to_string)
+                                ),
+                            ))));
+                        }
+                    }
                     let value_type: Type = Type::Fn {
                         params: fn_stmt.params.clone(),
                         return_type: fn_stmt.return_type.clone(),
@@ -513,12 +1858,57 @@ impl TypeInferrer {
                 None
             };
 
+        // static var: declared type and value type need same.
+        let statics: Option<HashMap<String, Type>> = if let Some(statics) = stmt.statics.as_ref() {
+            let mut statics_type: HashMap<String, Type> = HashMap::new();
+            for stmt in statics {
+                if let Stmt::VarStmt(var_stmt) = stmt {
+                    let value_type: Option<Type> = match var_stmt.value.as_ref() {
+                        Some(value) => Some(TypeInferrer::infer_type(resolver, value)?),
+                        None => None,
+                    };
+                    let static_type: Type = match (var_stmt.type_.as_ref(), value_type) {
+                        (Some(declared_type), Some(value_type)) => {
+                            if !declared_type.eq_type(&value_type) {
+                                return Err(JokerError::Resolver(Struct(StructError::report_error(
+                                    &var_stmt.name,
+                                    format!(
+                                        "class static '{}' type mismatch: Expected type '{}', Found type '{}'.",
+                                        var_stmt.name.lexeme, declared_type, value_type
+                                    ),
+                                ))));
+                            }
+                            value_type
+                        }
+                        (None, Some(value_type)) => value_type,
+                        _ => {
+                            return Err(JokerError::Resolver(Struct(StructError::report_error(
+                                &var_stmt.name,
+                                format!(
+                                    "class static '{}' need initial value.",
+                                    var_stmt.name.lexeme
+                                ),
+                            ))));
+                        }
+                    };
+                    statics_type.insert(var_stmt.name.lexeme.clone(), static_type);
+                } else {
+                    unreachable!("[TypeInferrer::infer_class_stmt]: unreachable this arm.")
+                }
+            }
+            Some(statics_type)
+        } else {
+            None
+        };
+
         Ok(Type::Class {
             name,
             super_class,
             fields,
             methods,
             functions,
+            statics,
+            interfaces: stmt.interfaces.clone(),
         })
     }
 }
@@ -52,6 +52,15 @@ impl Object {
     pub fn get_mut(&self) -> RefMut<OEnum> {
         self.inner.borrow_mut()
     }
+    // struct is value semantics: copy on bind, other object share reference.
+    pub fn value_copy(&self) -> Self {
+        match &*self.get() {
+            OEnum::Struct(struct_) => {
+                Object::new(OEnum::Struct(Box::new(struct_.as_ref().deep_clone())))
+            }
+            _ => self.clone(),
+        }
+    }
     pub fn parse<F: FromObject>(&self) -> Result<F, F::Err> {
         FromObject::from_object(self)
     }
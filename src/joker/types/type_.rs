@@ -9,8 +9,10 @@
 use std::{collections::HashMap, fmt::Display, hash::Hash};
 
 use crate::joker::{
+    ast::Expr,
     callable::StructError,
     error::JokerError,
+    object::error_token,
     parse::Parser,
     resolver::Error::Struct,
     token::{Token, TokenType},
@@ -30,6 +32,8 @@ pub trait IsInstance {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Type {
     I32,
+    I64,
+    U8,
     F64,
     Str,
     Bool,
@@ -44,6 +48,8 @@ pub enum Type {
         fields: Option<HashMap<String, Type>>,
         methods: Option<HashMap<String, Type>>,
         functions: Option<HashMap<String, Type>>,
+        statics: Option<HashMap<String, Type>>,
+        interfaces: Vec<Token>,
     },
     Instance {
         class: Box<Type>,
@@ -52,6 +58,43 @@ pub enum Type {
     },
     This(Box<Type>),
     UserDefined(Token),
+    List(Box<Type>),
+    Map(Box<Type>, Box<Type>),
+    // nominal type identity: name and declaring module id, main module is 0.
+    Struct {
+        name: Token,
+        fields: Vec<(String, Type)>,
+        module: usize,
+    },
+    Enum {
+        name: Token,
+        variants: Vec<(String, Vec<Type>)>,
+        module: usize,
+    },
+    Module {
+        name: Token,
+        exports: Vec<(String, Type)>,
+        module: usize,
+    },
+    Interface {
+        name: Token,
+        methods: Vec<(String, Type)>,
+        module: usize,
+    },
+    Nullable(Box<Type>), // T? is T or null.
+    Param(Token),        // type parameter T of generic fn or class.
+    Generic {
+        name: Token,
+        args: Vec<Type>,
+    }, // generic class instance: Box<i32>.
+    Union(Vec<Type>),    // i32 | str is i32 or str.
+    Alias {
+        name: Token,
+        target: Box<Type>,
+    }, // type Id = i32 | str;
+    Tuple(Vec<Type>),    // (i32, str) fixed size values.
+    Range,               // i32 range: a..b, a..=b.
+    Generator(Box<Type>), // Gen<T>: fn* call result, next() resume and yield T.
 }
 
 impl Type {
@@ -75,7 +118,9 @@ impl Type {
                 super_class: _,
                 fields: _,
                 methods: _,
-                functions: _
+                functions: _,
+                statics: _,
+                interfaces: _
             }
         )
     }
@@ -89,6 +134,476 @@ impl Type {
             }
         )
     }
+    pub fn is_struct(&self) -> bool {
+        matches!(
+            self,
+            Type::Struct {
+                name: _,
+                fields: _,
+                module: _
+            }
+        )
+    }
+    pub fn is_enum(&self) -> bool {
+        matches!(
+            self,
+            Type::Enum {
+                name: _,
+                variants: _,
+                module: _,
+            }
+        )
+    }
+    pub fn is_module(&self) -> bool {
+        matches!(
+            self,
+            Type::Module {
+                name: _,
+                exports: _,
+                module: _,
+            }
+        )
+    }
+    pub fn is_interface(&self) -> bool {
+        matches!(
+            self,
+            Type::Interface {
+                name: _,
+                methods: _,
+                module: _,
+            }
+        )
+    }
+    // builtin error struct type: Error { message: str, line: i32, kind: str }
+    pub fn error_struct() -> Type {
+        Type::Struct {
+            name: error_token(),
+            fields: vec![
+                (String::from("message"), Type::Str),
+                (String::from("line"), Type::I32),
+                (String::from("kind"), Type::Str),
+            ],
+            module: 0,
+        }
+    }
+    // class name of class type, class inside self body is this class.
+    pub fn class_name(&self) -> Option<&String> {
+        match self {
+            Type::Class { name, .. } => Some(&name.lexeme),
+            Type::This(class) => class.class_name(),
+            _ => None,
+        }
+    }
+    // class static type and declared class name, self class first then super class.
+    pub fn get_static(&self, name: &str) -> Option<(&Token, &Type)> {
+        match self {
+            Type::Class {
+                name: class_name,
+                super_class,
+                fields: _,
+                methods: _,
+                functions: _,
+                statics,
+                interfaces: _,
+            } => match statics.as_ref().and_then(|statics| statics.get(name)) {
+                Some(type_) => Some((class_name, type_)),
+                None => super_class
+                    .as_ref()
+                    .and_then(|super_class| super_class.get_static(name)),
+            },
+            Type::Instance {
+                class,
+                fields: _,
+                methods: _,
+            } => class.get_static(name),
+            Type::This(class) => class.get_static(name),
+            _ => None,
+        }
+    }
+    // class declared field type and declared class name, self class first then super class.
+    pub fn get_field(&self, name: &str) -> Option<(&Token, &Type)> {
+        match self {
+            Type::Class {
+                name: class_name,
+                super_class,
+                fields,
+                methods: _,
+                functions: _,
+                statics: _,
+                interfaces: _,
+            } => match fields.as_ref().and_then(|fields| fields.get(name)) {
+                Some(type_) => Some((class_name, type_)),
+                None => super_class
+                    .as_ref()
+                    .and_then(|super_class| super_class.get_field(name)),
+            },
+            Type::Instance {
+                class,
+                fields: _,
+                methods: _,
+            } => class.get_field(name),
+            Type::This(class) => class.get_field(name),
+            _ => None,
+        }
+    }
+    // class, this or instance implement interface name, self class first then super class.
+    pub fn implements(&self, interface: &str) -> bool {
+        match self {
+            Type::Class {
+                name: _,
+                super_class,
+                fields: _,
+                methods: _,
+                functions: _,
+                statics: _,
+                interfaces,
+            } => {
+                interfaces.iter().any(|name| name.lexeme.eq(interface))
+                    || super_class
+                        .as_ref()
+                        .is_some_and(|super_class| super_class.implements(interface))
+            }
+            Type::Instance {
+                class,
+                fields: _,
+                methods: _,
+            } => class.implements(interface),
+            Type::This(class) => class.implements(interface),
+            _ => false,
+        }
+    }
+    // class method type, self class first then super class.
+    pub fn get_method(&self, name: &str) -> Option<&Type> {
+        match self {
+            Type::Class {
+                name: _,
+                super_class,
+                fields: _,
+                methods,
+                functions: _,
+                statics: _,
+                interfaces: _,
+            } => match methods.as_ref().and_then(|methods| methods.get(name)) {
+                Some(type_) => Some(type_),
+                None => super_class
+                    .as_ref()
+                    .and_then(|super_class| super_class.get_method(name)),
+            },
+            Type::Instance {
+                class,
+                fields: _,
+                methods: _,
+            } => class.get_method(name),
+            Type::This(class) => class.get_method(name),
+            _ => None,
+        }
+    }
+    // class method implement interface method: same params and return type, 'this' type is self.
+    pub fn is_impl_method(&self, interface_method: &Self) -> bool {
+        match (self, interface_method) {
+            (
+                Type::Fn {
+                    params: Some(p1),
+                    return_type: r1,
+                },
+                Type::Fn {
+                    params: Some(p2),
+                    return_type: r2,
+                },
+            ) => {
+                p1.len() == p2.len()
+                    && p1[0].is_this()
+                    && p2[0].is_this()
+                    && p1[1..]
+                        .iter()
+                        .zip(p2[1..].iter())
+                        .all(|(p1, p2)| p1.get_type().eq_type(p2.get_type()))
+                    && match (r1, r2) {
+                        (None, None) => true,
+                        (Some(r1), Some(r2)) => r1.eq_type(r2),
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Type::I32 | Type::I64 | Type::U8)
+    }
+    pub fn is_number(&self) -> bool {
+        self.is_integer() || matches!(self, Type::F64)
+    }
+    // value have native method: number, list, map, range and generator.
+    pub fn is_native(&self) -> bool {
+        self.is_number()
+            || matches!(
+                self,
+                Type::List(_) | Type::Map(..) | Type::Range | Type::Generator(_)
+            )
+    }
+    // cast table: number to number, bool to integer, number and bool to str, str to number and bool.
+    pub fn can_cast(&self, target: &Type) -> bool {
+        if self.eq_type(target) {
+            return true;
+        }
+        match (self, target) {
+            (source, target) if source.is_number() && target.is_number() => true,
+            (Type::Bool, target) if target.is_integer() => true,
+            (source, Type::Str) => source.is_number() || source == &Type::Bool,
+            (Type::Str, target) => target.is_number() || target == &Type::Bool,
+            _ => false,
+        }
+    }
+    // builtin type name: i32, i64, u8, f64, str, bool and null.
+    pub fn builtin(name: &str) -> Option<Type> {
+        match name {
+            "i32" => Some(Type::I32),
+            "i64" => Some(Type::I64),
+            "u8" => Some(Type::U8),
+            "f64" => Some(Type::F64),
+            "str" => Some(Type::Str),
+            "bool" => Some(Type::Bool),
+            "null" => Some(Type::Null),
+            "range" => Some(Type::Range),
+            _ => None,
+        }
+    }
+    // union of types: nested union flatten, same member once, one member is itself.
+    pub fn union(types: Vec<Type>) -> Type {
+        let mut members: Vec<Type> = Vec::new();
+        for type_ in types {
+            let flatten: Vec<Type> = match type_ {
+                Type::Union(types) => types,
+                type_ => vec![type_],
+            };
+            for type_ in flatten {
+                if !members.iter().any(|member| member.eq_type(&type_)) {
+                    members.push(type_);
+                }
+            }
+        }
+        if members.len() == 1 {
+            members.remove(0)
+        } else {
+            Type::Union(members)
+        }
+    }
+    // alias target type, other is self.
+    pub fn unalias(&self) -> &Type {
+        match self {
+            Type::Alias { name: _, target } => target.unalias(),
+            type_ => type_,
+        }
+    }
+    pub fn is_union(&self) -> bool {
+        matches!(self.unalias(), Type::Union(_))
+    }
+    // union members, other is self only.
+    pub fn members(&self) -> Vec<Type> {
+        match self.unalias() {
+            Type::Union(types) => types.clone(),
+            type_ => vec![type_.clone()],
+        }
+    }
+    // union without members of other, `x is T` else branch type.
+    pub fn exclude(&self, other: &Type) -> Type {
+        Type::union(
+            self.members()
+                .into_iter()
+                .filter(|member| !other.is_assignable(member))
+                .collect(),
+        )
+    }
+    // T to T?, null and T? keep self.
+    pub fn nullable(self) -> Type {
+        match self {
+            Type::Null | Type::Nullable(_) => self,
+            type_ => Type::Nullable(Box::new(type_)),
+        }
+    }
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, Type::Nullable(_))
+    }
+    // T? inner type T, other is self.
+    pub fn non_null(&self) -> &Type {
+        match self {
+            Type::Nullable(type_) => type_,
+            type_ => type_,
+        }
+    }
+    // declared type accept value type: same type, T? accept T, T? and null.
+    pub fn is_assignable(&self, value: &Type) -> bool {
+        match (self, value) {
+            (Type::Alias { name: _, target }, value) => target.is_assignable(value),
+            (_, Type::Alias { name: _, target }) => self.is_assignable(target),
+            // union accept every member of value type.
+            (Type::Union(_), Type::Union(values)) => {
+                values.iter().all(|value| self.is_assignable(value))
+            }
+            (Type::Union(_), Type::Nullable(value)) => {
+                self.is_assignable(&Type::Null) && self.is_assignable(value)
+            }
+            (Type::Union(types), value) => types.iter().any(|type_| type_.is_assignable(value)),
+            (Type::Nullable(_), Type::Null) => true,
+            (Type::Nullable(type_), Type::Nullable(value)) => type_.is_assignable(value),
+            (Type::Nullable(type_), value) => type_.is_assignable(value),
+            (Type::Tuple(types), Type::Tuple(values)) => {
+                types.len() == values.len()
+                    && types
+                        .iter()
+                        .zip(values)
+                        .all(|(type_, value)| type_.is_assignable(value))
+            }
+            _ => self.eq_type(value),
+        }
+    }
+    // type have type parameter T, generic fn signature need instantiate.
+    pub fn contains_param(&self) -> bool {
+        match self {
+            Type::Param(_) => true,
+            Type::List(element) | Type::Nullable(element) | Type::Generator(element) => {
+                element.contains_param()
+            }
+            Type::Map(key, value) => key.contains_param() || value.contains_param(),
+            Type::Fn {
+                params,
+                return_type,
+            } => {
+                params
+                    .iter()
+                    .flatten()
+                    .any(|param| param.get_type().contains_param())
+                    || return_type
+                        .as_ref()
+                        .is_some_and(|return_type| return_type.contains_param())
+            }
+            Type::Generic { name: _, args } | Type::Tuple(args) => {
+                args.iter().any(|arg| arg.contains_param())
+            }
+            _ => false,
+        }
+    }
+    // replace type parameter T with bound type, unbound T keep.
+    pub fn substitute(&self, bounds: &HashMap<String, Type>) -> Type {
+        match self {
+            Type::Param(name) => bounds
+                .get(&name.lexeme)
+                .cloned()
+                .unwrap_or_else(|| self.clone()),
+            Type::List(element) => Type::List(Box::new(element.substitute(bounds))),
+            Type::Generator(element) => Type::Generator(Box::new(element.substitute(bounds))),
+            Type::Nullable(type_) => type_.substitute(bounds).nullable(),
+            Type::Map(key, value) => Type::Map(
+                Box::new(key.substitute(bounds)),
+                Box::new(value.substitute(bounds)),
+            ),
+            Type::Fn {
+                params,
+                return_type,
+            } => Type::Fn {
+                params: params.as_ref().map(|params| {
+                    params
+                        .iter()
+                        .map(|param| {
+                            let mut param: ParamPair = param.clone();
+                            param.set_type(param.get_type().substitute(bounds));
+                            param
+                        })
+                        .collect()
+                }),
+                return_type: return_type
+                    .as_ref()
+                    .map(|return_type| Box::new(return_type.substitute(bounds))),
+            },
+            Type::Generic { name, args } => Type::Generic {
+                name: name.clone(),
+                args: args.iter().map(|arg| arg.substitute(bounds)).collect(),
+            },
+            Type::Tuple(types) => {
+                Type::Tuple(types.iter().map(|type_| type_.substitute(bounds)).collect())
+            }
+            _ => self.clone(),
+        }
+    }
+    // unify parameter type self with argument type, type parameter T bind to argument type.
+    // error is mismatch message: expected T found argument type.
+    pub fn unify(&self, arg: &Type, bounds: &mut HashMap<String, Type>) -> Result<(), String> {
+        let mismatch = || format!("expected '{}', found '{}'", self, arg);
+        match (self, arg) {
+            (Type::Param(name), _) => match bounds.get(&name.lexeme) {
+                Some(bound) if bound.is_assignable(arg) => Ok(()),
+                Some(bound) => Err(format!(
+                    "type parameter '{}' is '{}', found '{}'",
+                    name.lexeme, bound, arg
+                )),
+                None => {
+                    bounds.insert(name.lexeme.clone(), arg.clone());
+                    Ok(())
+                }
+            },
+            (Type::List(param), Type::List(arg))
+            | (Type::Generator(param), Type::Generator(arg)) => param.unify(arg, bounds),
+            (Type::Map(param_key, param_value), Type::Map(arg_key, arg_value)) => {
+                param_key.unify(arg_key, bounds)?;
+                param_value.unify(arg_value, bounds)
+            }
+            (Type::Nullable(_), Type::Null) => Ok(()),
+            (Type::Nullable(param), Type::Nullable(arg)) => param.unify(arg, bounds),
+            (Type::Nullable(param), arg) => param.unify(arg, bounds),
+            (
+                Type::Fn {
+                    params: params1,
+                    return_type: return1,
+                },
+                Type::Fn {
+                    params: params2,
+                    return_type: return2,
+                },
+            ) => {
+                let (params1, params2) = (
+                    params1.as_deref().unwrap_or_default(),
+                    params2.as_deref().unwrap_or_default(),
+                );
+                if params1.len() != params2.len() {
+                    return Err(mismatch());
+                }
+                for (param1, param2) in params1.iter().zip(params2) {
+                    param1.get_type().unify(param2.get_type(), bounds)?;
+                }
+                match (return1, return2) {
+                    (Some(return1), Some(return2)) => return1.unify(return2, bounds),
+                    (None, None) => Ok(()),
+                    _ => Err(mismatch()),
+                }
+            }
+            (
+                Type::Generic {
+                    name: name1,
+                    args: args1,
+                },
+                Type::Generic {
+                    name: name2,
+                    args: args2,
+                },
+            ) if name1.lexeme == name2.lexeme && args1.len() == args2.len() => args1
+                .iter()
+                .zip(args2)
+                .try_for_each(|(arg1, arg2)| arg1.unify(arg2, bounds)),
+            (Type::Tuple(params), Type::Tuple(args)) if params.len() == args.len() => params
+                .iter()
+                .zip(args)
+                .try_for_each(|(param, arg)| param.unify(arg, bounds)),
+            _ if self.is_assignable(arg) => Ok(()),
+            _ => Err(mismatch()),
+        }
+    }
+    // map key need hashable literal type.
+    pub fn is_map_key(&self) -> bool {
+        matches!(
+            self,
+            Type::I32 | Type::I64 | Type::U8 | Type::F64 | Type::Str | Type::Bool
+        )
+    }
     pub fn is_class_param(&self, other: &Self) -> bool {
         match (self, other) {
             (
@@ -99,6 +614,8 @@ impl Type {
                     fields: _,
                     methods: _,
                     functions: _,
+                    statics: _,
+                    interfaces: _,
                 },
             ) => other.eq(class),
             _ => self.eq_type(other),
@@ -106,11 +623,21 @@ impl Type {
     }
     pub fn eq_type(&self, other: &Self) -> bool {
         match (self, other) {
+            (Type::Alias { name: _, target }, other) => target.eq_type(other),
+            (_, Type::Alias { name: _, target }) => self.eq_type(target),
+            // union subtyping: member or sub union satisfy union.
+            (Type::Union(types), Type::Union(others)) => others
+                .iter()
+                .all(|other| types.iter().any(|type_| type_.eq_type(other))),
+            (Type::Union(types), other) => types.iter().any(|type_| type_.eq_type(other)),
             (Type::I32, Type::I32) => true,
+            (Type::I64, Type::I64) => true,
+            (Type::U8, Type::U8) => true,
             (Type::F64, Type::F64) => true,
             (Type::Str, Type::Str) => true,
             (Type::Bool, Type::Bool) => true,
             (Type::Null, Type::Null) => true,
+            (Type::Range, Type::Range) => true,
             (
                 Type::Fn {
                     params: p1,
@@ -162,6 +689,8 @@ impl Type {
                     fields: f1,
                     methods: m1,
                     functions: fn1,
+                    statics: s1,
+                    interfaces: i1,
                 },
                 Type::Class {
                     name: n2,
@@ -169,6 +698,8 @@ impl Type {
                     fields: f2,
                     methods: m2,
                     functions: fn2,
+                    statics: s2,
+                    interfaces: i2,
                 },
             ) => {
                 if n1 != n2 {
@@ -215,6 +746,18 @@ impl Type {
                     return false;
                 }
 
+                if s1 != s2 {
+                    return false;
+                }
+
+                if !i1
+                    .iter()
+                    .map(|i| &i.lexeme)
+                    .eq(i2.iter().map(|i| &i.lexeme))
+                {
+                    return false;
+                }
+
                 true
             }
             (
@@ -229,7 +772,155 @@ impl Type {
                     fields: _,
                 },
             ) => c1.eq_type(c2),
-            (Type::UserDefined(name1), Type::UserDefined(name2)) => name1 == name2,
+            (Type::UserDefined(name1), Type::UserDefined(name2)) => name1.lexeme == name2.lexeme,
+            (Type::Nullable(type1), Type::Nullable(type2)) => type1.eq_type(type2),
+            (Type::Param(name1), Type::Param(name2)) => name1.lexeme == name2.lexeme,
+            (
+                Type::Generic {
+                    name: name1,
+                    args: args1,
+                },
+                Type::Generic {
+                    name: name2,
+                    args: args2,
+                },
+            ) => {
+                name1.lexeme == name2.lexeme
+                    && args1.len() == args2.len()
+                    && args1
+                        .iter()
+                        .zip(args2)
+                        .all(|(arg1, arg2)| arg1.eq_type(arg2))
+            }
+            (Type::List(element1), Type::List(element2))
+            | (Type::Generator(element1), Type::Generator(element2)) => element1.eq_type(element2),
+            (Type::Tuple(types1), Type::Tuple(types2)) => {
+                types1.len() == types2.len()
+                    && types1
+                        .iter()
+                        .zip(types2)
+                        .all(|(type1, type2)| type1.eq_type(type2))
+            }
+            (Type::Map(key1, value1), Type::Map(key2, value2)) => {
+                key1.eq_type(key2) && value1.eq_type(value2)
+            }
+            (
+                Type::Struct {
+                    name: n1,
+                    fields: _,
+                    module: m1,
+                },
+                Type::Struct {
+                    name: n2,
+                    fields: _,
+                    module: m2,
+                },
+            ) => n1.lexeme == n2.lexeme && m1 == m2,
+            (
+                Type::Enum {
+                    name: n1,
+                    variants: _,
+                    module: m1,
+                },
+                Type::Enum {
+                    name: n2,
+                    variants: _,
+                    module: m2,
+                },
+            ) => n1.lexeme == n2.lexeme && m1 == m2,
+            (
+                Type::Module {
+                    name: n1,
+                    exports: _,
+                    module: m1,
+                },
+                Type::Module {
+                    name: n2,
+                    exports: _,
+                    module: m2,
+                },
+            ) => n1.lexeme == n2.lexeme && m1 == m2,
+            (
+                Type::Interface {
+                    name: n1,
+                    methods: _,
+                    module: m1,
+                },
+                Type::Interface {
+                    name: n2,
+                    methods: _,
+                    module: m2,
+                },
+            ) => n1.lexeme == n2.lexeme && m1 == m2,
+            // fn param and return declared struct or enum type is user defined name.
+            (
+                Type::Struct {
+                    name,
+                    fields: _,
+                    module: _,
+                },
+                Type::UserDefined(user_defined),
+            )
+            | (
+                Type::UserDefined(user_defined),
+                Type::Struct {
+                    name,
+                    fields: _,
+                    module: _,
+                },
+            )
+            | (
+                Type::Enum {
+                    name,
+                    variants: _,
+                    module: _,
+                },
+                Type::UserDefined(user_defined),
+            )
+            | (
+                Type::UserDefined(user_defined),
+                Type::Enum {
+                    name,
+                    variants: _,
+                    module: _,
+                },
+            )
+            | (
+                Type::Interface {
+                    name,
+                    methods: _,
+                    module: _,
+                },
+                Type::UserDefined(user_defined),
+            )
+            | (
+                Type::UserDefined(user_defined),
+                Type::Interface {
+                    name,
+                    methods: _,
+                    module: _,
+                },
+            ) => name.lexeme == user_defined.lexeme,
+            // declared class type is user defined name, value is class instance.
+            (
+                Type::Instance {
+                    class,
+                    fields: _,
+                    methods: _,
+                },
+                Type::UserDefined(user_defined),
+            )
+            | (
+                Type::UserDefined(user_defined),
+                Type::Instance {
+                    class,
+                    fields: _,
+                    methods: _,
+                },
+            ) => {
+                class.class_name() == Some(&user_defined.lexeme)
+                    || class.implements(&user_defined.lexeme)
+            }
             _ => false,
         }
     }
@@ -253,10 +944,12 @@ impl IsInstance for Type {
                 } else {
                     IsInstance::is_inherit(class.as_ref(), parent)
                 }
+            } else if parent.is_interface() {
+                IsInstance::is_inherit(class.as_ref(), parent)
             } else {
                 Err(JokerError::Resolver(Struct(StructError::report_error(
                     &Token::eof(0),
-                    format!("[IsInstance::is_instance] Type mismatch: instance '{}', parent '{}'.\n\t\tCurrent type not's Class.",
+                    format!("[IsInstance::is_instance] Type mismatch: instance '{}', parent '{}'.\n\t\tCurrent type not's Class or Interface.",
                     self, parent,
                     )
                 ))))
@@ -272,6 +965,15 @@ impl IsInstance for Type {
     }
     // TODO: INHERIT STORE? cache inherit info.
     fn is_inherit(&self, parent: &Self) -> Result<bool, JokerError> {
+        if let Type::Interface {
+            name,
+            methods: _,
+            module: _,
+        } = parent
+        {
+            // interface implement by self class or super class.
+            return Ok(self.implements(&name.lexeme));
+        }
         if parent.is_class() {
             let mut current: &Type = self;
             while let Type::Class {
@@ -280,6 +982,8 @@ impl IsInstance for Type {
                 fields: _,
                 methods: _,
                 functions: _,
+                statics: _,
+                interfaces: _,
             } = current
             {
                 if let Some(super_class) = super_class {
@@ -301,7 +1005,7 @@ impl IsInstance for Type {
         } else {
             Err(JokerError::Resolver(Struct(StructError::report_error(
                 &Token::eof(0),
-                format!("[IsInstance::is_inherit] Type mismatch: Expected right Class type, found '{}'.",
+                format!("[IsInstance::is_inherit] Type mismatch: Expected right Class or Interface type, found '{}'.",
                 parent,
                 )
             ))))
@@ -315,7 +1019,15 @@ impl IsInstance for Type {
                 fields,
                 methods,
                 functions,
+                statics,
+                interfaces: _,
             } => {
+                if let Some(statics) = statics {
+                    if statics.contains_key(&name.lexeme) {
+                        return Ok(true);
+                    }
+                }
+
                 if let Some(fields) = fields {
                     if fields.contains_key(&name.lexeme) {
                         return Ok(true);
@@ -356,6 +1068,21 @@ impl IsInstance for Type {
                 }
                 Ok(false)
             }
+            Type::Struct {
+                name: _,
+                fields,
+                module: _,
+            } => Ok(fields.iter().any(|(field, _)| field.eq(&name.lexeme))),
+            Type::Module {
+                name: _,
+                exports,
+                module: _,
+            } => Ok(exports.iter().any(|(export, _)| export.eq(&name.lexeme))),
+            Type::Interface {
+                name: _,
+                methods,
+                module: _,
+            } => Ok(methods.iter().any(|(method, _)| method.eq(&name.lexeme))),
             _ => Err(JokerError::Resolver(Struct(StructError::report_error(
                 name,
                 format!(
@@ -377,7 +1104,15 @@ impl IsInstance for Type {
                 fields,
                 methods,
                 functions,
+                statics,
+                interfaces: _,
             } => {
+                if let Some(statics) = statics {
+                    if let Some(type_) = statics.get(&name.lexeme) {
+                        return Ok(Some(type_));
+                    }
+                }
+
                 if let Some(fields) = fields {
                     if let Some(type_) = fields.get(&name.lexeme) {
                         return Ok(Some(type_))
@@ -421,6 +1156,18 @@ impl IsInstance for Type {
                 }
                 Ok(None)
             }
+            Type::Struct { name: _, fields, module: _ } => Ok(fields
+                .iter()
+                .find(|(field, _)| field.eq(&name.lexeme))
+                .map(|(_, type_)| type_)),
+            Type::Module { name: _, exports, module: _ } => Ok(exports
+                .iter()
+                .find(|(export, _)| export.eq(&name.lexeme))
+                .map(|(_, type_)| type_)),
+            Type::Interface { name: _, methods, module: _ } => Ok(methods
+                .iter()
+                .find(|(method, _)| method.eq(&name.lexeme))
+                .map(|(_, type_)| type_)),
             _ => Err(JokerError::Resolver(Struct(
                 StructError::report_error(
                     name,
@@ -461,6 +1208,8 @@ impl Hash for Type {
                 fields,
                 methods,
                 functions,
+                statics,
+                interfaces,
             } => {
                 6.hash(state);
                 name.hash(state);
@@ -485,6 +1234,13 @@ impl Hash for Type {
                         value.hash(state);
                     }
                 }
+                if let Some(statics) = statics {
+                    for (key, value) in statics {
+                        key.hash(state);
+                        value.hash(state);
+                    }
+                }
+                interfaces.hash(state);
             }
             Type::Instance {
                 class,
@@ -514,6 +1270,88 @@ impl Hash for Type {
                 9.hash(state);
                 token.hash(state);
             }
+            Type::List(element) => {
+                10.hash(state);
+                element.hash(state);
+            }
+            Type::Map(key, value) => {
+                11.hash(state);
+                key.hash(state);
+                value.hash(state);
+            }
+            Type::Struct {
+                name,
+                fields,
+                module,
+            } => {
+                12.hash(state);
+                name.hash(state);
+                fields.hash(state);
+                module.hash(state);
+            }
+            Type::Enum {
+                name,
+                variants,
+                module,
+            } => {
+                13.hash(state);
+                name.hash(state);
+                variants.hash(state);
+                module.hash(state);
+            }
+            Type::Module {
+                name,
+                exports,
+                module,
+            } => {
+                14.hash(state);
+                name.hash(state);
+                exports.hash(state);
+                module.hash(state);
+            }
+            Type::Interface {
+                name,
+                methods,
+                module,
+            } => {
+                15.hash(state);
+                name.hash(state);
+                methods.hash(state);
+                module.hash(state);
+            }
+            Type::I64 => 16.hash(state),
+            Type::U8 => 17.hash(state),
+            Type::Nullable(type_) => {
+                18.hash(state);
+                type_.hash(state);
+            }
+            Type::Param(name) => {
+                19.hash(state);
+                name.hash(state);
+            }
+            Type::Generic { name, args } => {
+                20.hash(state);
+                name.hash(state);
+                args.hash(state);
+            }
+            Type::Union(types) => {
+                21.hash(state);
+                types.hash(state);
+            }
+            Type::Alias { name, target } => {
+                22.hash(state);
+                name.hash(state);
+                target.hash(state);
+            }
+            Type::Tuple(types) => {
+                23.hash(state);
+                types.hash(state);
+            }
+            Type::Range => 24.hash(state),
+            Type::Generator(element) => {
+                25.hash(state);
+                element.hash(state);
+            }
         }
     }
 }
@@ -522,10 +1360,14 @@ impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Type::I32 => write!(f, "i32"),
+            Type::I64 => write!(f, "i64"),
+            Type::U8 => write!(f, "u8"),
             Type::F64 => write!(f, "f64"),
             Type::Str => write!(f, "str"),
             Type::Bool => write!(f, "bool"),
             Type::Null => write!(f, "null"),
+            Type::Range => write!(f, "range"),
+            Type::Generator(element) => write!(f, "Gen<{}>", element),
             Type::Fn {
                 params,
                 return_type,
@@ -533,7 +1375,11 @@ impl Display for Type {
                 let params = match params {
                     Some(params) => params
                         .iter()
-                        .map(|p| TypeInferrer::param_pair_type(p).to_string())
+                        .map(|p| match p {
+                            ParamPair::Default { .. } => format!("{} = ..", p.get_type()),
+                            ParamPair::Rest { .. } => format!("...{}", p.get_type()),
+                            p => TypeInferrer::param_pair_type(p).to_string(),
+                        })
                         .collect::<Vec<String>>()
                         .join(", "),
                     None => String::new(),
@@ -549,6 +1395,8 @@ impl Display for Type {
                 fields: _,
                 methods: _,
                 functions: _,
+                statics: _,
+                interfaces: _,
             } => write!(f, "class({})", name.lexeme),
             Type::Instance {
                 class,
@@ -557,6 +1405,73 @@ impl Display for Type {
             } => write!(f, "instance({})", class),
             Type::This(class) => write!(f, "{}", class),
             Type::UserDefined(name) => write!(f, "{}", name.lexeme),
+            Type::List(element) => write!(f, "[{}]", element),
+            Type::Map(key, value) => write!(f, "{{{}: {}}}", key, value),
+            Type::Struct {
+                name,
+                fields: _,
+                module: 0,
+            } => write!(f, "struct({})", name.lexeme),
+            Type::Struct {
+                name,
+                fields: _,
+                module,
+            } => write!(f, "struct({} of module {})", name.lexeme, module),
+            Type::Enum {
+                name,
+                variants: _,
+                module: 0,
+            } => write!(f, "enum({})", name.lexeme),
+            Type::Enum {
+                name,
+                variants: _,
+                module,
+            } => write!(f, "enum({} of module {})", name.lexeme, module),
+            Type::Module {
+                name,
+                exports: _,
+                module: _,
+            } => write!(f, "module({})", name.lexeme),
+            Type::Interface {
+                name,
+                methods: _,
+                module: 0,
+            } => write!(f, "interface({})", name.lexeme),
+            Type::Interface {
+                name,
+                methods: _,
+                module,
+            } => write!(f, "interface({} of module {})", name.lexeme, module),
+            Type::Nullable(type_) => write!(f, "{}?", type_),
+            Type::Param(name) => write!(f, "{}", name.lexeme),
+            Type::Generic { name, args } => write!(
+                f,
+                "{}<{}>",
+                name.lexeme,
+                args.iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Type::Union(types) => write!(
+                f,
+                "{}",
+                types
+                    .iter()
+                    .map(|type_| type_.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            ),
+            Type::Alias { name, target: _ } => write!(f, "{}", name.lexeme),
+            Type::Tuple(types) => write!(
+                f,
+                "({})",
+                types
+                    .iter()
+                    .map(|type_| type_.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -573,9 +1488,26 @@ impl FromParamPair for Type {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ParamPair {
-    This { param: Token, type_: Type },
-    Normal { param: Token, type_: Type },
-    Label { type_: Type },
+    This {
+        param: Token,
+        type_: Type,
+    },
+    Normal {
+        param: Token,
+        type_: Type,
+    },
+    Label {
+        type_: Type,
+    },
+    Default {
+        param: Token,
+        type_: Type,
+        value: Expr,
+    }, // `port: i32 = 80`
+    Rest {
+        param: Token,
+        type_: Type,
+    }, // `...opts: [str]`
 }
 
 impl ParamPair {
@@ -588,6 +1520,16 @@ impl ParamPair {
     pub fn label(type_: Type) -> ParamPair {
         ParamPair::Label { type_ }
     }
+    pub fn default(param: Token, type_: Type, value: Expr) -> ParamPair {
+        ParamPair::Default {
+            param,
+            type_,
+            value,
+        }
+    }
+    pub fn rest(param: Token, type_: Type) -> ParamPair {
+        ParamPair::Rest { param, type_ }
+    }
     pub fn is_this(&self) -> bool {
         matches!(self, ParamPair::This { param: _, type_: _ })
     }
@@ -597,10 +1539,18 @@ impl ParamPair {
     pub fn is_label(&self) -> bool {
         matches!(self, ParamPair::Label { type_: _ })
     }
+    pub fn is_default(&self) -> bool {
+        matches!(self, ParamPair::Default { .. })
+    }
+    pub fn is_rest(&self) -> bool {
+        matches!(self, ParamPair::Rest { .. })
+    }
     pub fn get_param(&self) -> Option<&Token> {
         match self {
             ParamPair::This { param, type_: _ } => Some(param),
             ParamPair::Normal { param, type_: _ } => Some(param),
+            ParamPair::Default { param, .. } => Some(param),
+            ParamPair::Rest { param, type_: _ } => Some(param),
             _ => None,
         }
     }
@@ -609,6 +1559,8 @@ impl ParamPair {
             ParamPair::Normal { param: _, type_ } => type_,
             ParamPair::Label { type_ } => type_,
             ParamPair::This { param: _, type_ } => type_,
+            ParamPair::Default { type_, .. } => type_,
+            ParamPair::Rest { param: _, type_ } => type_,
         }
     }
     pub fn set_type(&mut self, new_type: Type) {
@@ -616,6 +1568,8 @@ impl ParamPair {
             ParamPair::Normal { param: _, type_ } => *type_ = new_type,
             ParamPair::Label { type_ } => *type_ = new_type,
             ParamPair::This { param: _, type_ } => *type_ = new_type,
+            ParamPair::Default { type_, .. } => *type_ = new_type,
+            ParamPair::Rest { param: _, type_ } => *type_ = new_type,
         }
     }
     pub fn parse<F: FromParamPair>(&self) -> Result<F, F::Err> {